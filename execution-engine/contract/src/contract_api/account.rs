@@ -43,6 +43,22 @@ pub fn set_action_threshold(
     }
 }
 
+/// Returns the action thresholds of the account which made the deploy, as a pair of its deployment
+/// threshold and its key management threshold.
+pub fn get_action_thresholds() -> (Weight, Weight) {
+    let mut bytes = [0u8; 2];
+    unsafe { ext_ffi::get_action_thresholds(bytes.as_mut_ptr()) };
+    (Weight::new(bytes[0]), Weight::new(bytes[1]))
+}
+
+/// Returns the total [`Weight`] of the keys which signed the deploy.  Keys which aren't associated
+/// with the deploying account don't contribute to the total.
+pub fn get_total_weight() -> Weight {
+    let mut weight = 0u8;
+    unsafe { ext_ffi::get_total_weight(&mut weight) };
+    Weight::new(weight)
+}
+
 /// Adds the given [`AccountHash`] with associated [`Weight`] to the account's associated keys.
 pub fn add_associated_key(account_hash: AccountHash, weight: Weight) -> Result<(), AddKeyFailure> {
     let (account_hash_ptr, account_hash_size, _bytes) = to_ptr(account_hash);
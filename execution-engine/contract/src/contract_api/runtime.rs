@@ -3,16 +3,17 @@
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
 use alloc::vec;
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use core::mem::MaybeUninit;
 
 use casperlabs_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes},
+    bytesrepr::{self, FromBytes, U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH},
     contracts::{ContractVersion, NamedKeys},
-    ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, FromRuntimeArgs, Key,
+    Phase, RuntimeArgs, RuntimeArgsError, URef, BLOCKTIME_SERIALIZED_LENGTH,
+    PHASE_SERIALIZED_LENGTH,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -39,11 +40,45 @@ pub fn revert<T: Into<ApiError>>(error: T) -> ! {
     }
 }
 
+/// Returns the value held by `result` if it is `Ok`, otherwise stops execution of a contract and
+/// reverts execution effects with the error's code.
+///
+/// The code is converted as by `ApiError::from(u32)`.  For errors which convert to [`ApiError`]
+/// directly, use [`UnwrapOrRevert`](crate::unwrap_or_revert::UnwrapOrRevert) instead.
+pub fn revert_on_err<T, E: Into<u32>>(result: Result<T, E>) -> T {
+    result.unwrap_or_else(|error| revert(ApiError::from(error.into())))
+}
+
+/// Stops execution of a contract successfully, keeping the effects of the execution so far.
+///
+/// Only the gas used up to this point is charged, so this allows e.g. returning early when a
+/// contract detects there is nothing to do.  When called from a contract invoked via
+/// [`call_contract`] or [`call_versioned_contract`], only that contract stops, returning `()` to
+/// its caller.
+pub fn finish_early() -> ! {
+    unsafe { ext_ffi::finish_early() }
+}
+
+/// Stops execution of a contract and reverts execution effects with the given error code,
+/// recording `msg` in the execution result of the deploy.
+///
+/// The message is intended for debugging only; callers should continue to rely on `code`.
+pub fn revert_msg(code: u32, msg: &str) -> ! {
+    let (msg_ptr, msg_size, _bytes) = contract_api::to_ptr(msg);
+    unsafe {
+        ext_ffi::revert_with_message(code, msg_ptr, msg_size);
+    }
+}
+
 /// Calls the given stored contract, passing the given arguments to it.
 ///
 /// If the stored contract calls [`ret`], then that value is returned from `call_contract`.  If the
 /// stored contract calls [`revert`], then execution stops and `call_contract` doesn't return.
 /// Otherwise `call_contract` returns `()`.
+///
+/// Several values can be returned at once as a tuple, e.g. by calling
+/// `call_contract::<(U512, URef)>` on a contract which `ret`s a `CLValue` of `(U512, URef)`.  Any
+/// [`URef`]s held in a returned value or one- or two-element tuple become accessible to the caller.
 pub fn call_contract<T: CLTyped + FromBytes>(
     contract_hash: ContractHash,
     entry_point_name: &str,
@@ -80,6 +115,8 @@ pub fn call_contract<T: CLTyped + FromBytes>(
 /// If the stored contract calls [`ret`], then that value is returned from
 /// `call_versioned_contract`.  If the stored contract calls [`revert`], then execution stops and
 /// `call_versioned_contract` doesn't return. Otherwise `call_versioned_contract` returns `()`.
+///
+/// As with [`call_contract`], several values can be returned at once as a tuple.
 pub fn call_versioned_contract<T: CLTyped + FromBytes>(
     contract_package_hash: ContractPackageHash,
     contract_version: Option<ContractVersion>,
@@ -154,7 +191,38 @@ fn get_named_arg_size(name: &str) -> Option<usize> {
 /// Note that this is only relevant to contracts stored on-chain since a contract deployed directly
 /// is not invoked with any arguments.
 pub fn get_named_arg<T: FromBytes>(name: &str) -> T {
-    let arg_size = get_named_arg_size(name).unwrap_or_revert_with(ApiError::MissingArgument);
+    let arg_bytes = get_named_arg_bytes(name).unwrap_or_revert_with(ApiError::MissingArgument);
+    bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Returns all the named arguments required by `T`, decoded into a `T`.
+///
+/// If any argument is missing or can't be decoded, execution is reverted with
+/// [`ApiError::MissingArgument`] or [`ApiError::InvalidArgument`] respectively, recording the name
+/// of the offending argument in the execution result.
+///
+/// The host only provides the serialized bytes of each argument, so every argument is decoded as
+/// if it had the type `T` expects.  An argument passed with a different type is therefore not
+/// detected as mistyped unless its bytes fail to decode as the expected type.
+pub fn get_named_args<T: FromRuntimeArgs>() -> T {
+    let args: RuntimeArgs = T::named_arg_types()
+        .into_iter()
+        .filter_map(|(name, cl_type)| {
+            let arg_bytes = get_named_arg_bytes(&name)?;
+            Some((name, CLValue::from_components(cl_type, arg_bytes)))
+        })
+        .collect();
+    T::from_runtime_args(&args).unwrap_or_else(|error| {
+        let api_error = match error {
+            RuntimeArgsError::MissingArgument(_) => ApiError::MissingArgument,
+            _ => ApiError::InvalidArgument,
+        };
+        revert_msg(api_error.into(), &error.to_string())
+    })
+}
+
+fn get_named_arg_bytes(name: &str) -> Option<Vec<u8>> {
+    let arg_size = get_named_arg_size(name)?;
     let arg_bytes = if arg_size > 0 {
         let res = {
             let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
@@ -176,7 +244,58 @@ pub fn get_named_arg<T: FromBytes>(name: &str) -> T {
         // Avoids allocation with 0 bytes and a call to get_named_arg
         Vec::new()
     };
-    bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument)
+    Some(arg_bytes)
+}
+
+fn get_arg_size(index: u32) -> Option<usize> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe { ext_ffi::get_arg_size(index, &mut arg_size as *mut usize) };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => revert(e),
+    }
+}
+
+/// Returns every argument passed to the current module invocation, unchanged and in the order they
+/// were passed.
+///
+/// This allows a proxy contract to pass on all of its arguments to another contract without
+/// knowing their names or types.
+pub fn forward_all_args() -> RuntimeArgs {
+    let (total_args, result_size) = {
+        let mut total_args = MaybeUninit::uninit();
+        let mut result_size = 0;
+        let ret = unsafe {
+            ext_ffi::load_runtime_args(total_args.as_mut_ptr(), &mut result_size as *mut usize)
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        let total_args = unsafe { total_args.assume_init() };
+        (total_args, result_size)
+    };
+    if total_args == 0 {
+        return RuntimeArgs::new();
+    }
+    let bytes = read_host_buffer(result_size).unwrap_or_revert();
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// Returns the raw serialized bytes of the argument at `index` passed to the host for the current
+/// module invocation, or `None` if there is no such argument.
+///
+/// This allows forwarding an argument without knowing its type.
+pub fn get_arg_bytes(index: u32) -> Option<Vec<u8>> {
+    let arg_size = get_arg_size(index)?;
+    if arg_size == 0 {
+        // Avoids allocation with 0 bytes and a call to get_arg
+        return Some(Vec::new());
+    }
+    let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+    let ret = unsafe { ext_ffi::get_arg(index, data_non_null_ptr.as_ptr(), arg_size) };
+    let data = unsafe { Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size) };
+    // Assumed to be safe as `get_arg_size` checks the argument already
+    api_error::result_from(ret).unwrap_or_revert();
+    Some(data)
 }
 
 /// Returns the caller of the current context, i.e. the [`AccountHash`] of the account which made
@@ -192,6 +311,14 @@ pub fn get_caller() -> AccountHash {
     bytesrepr::deserialize(buf).unwrap_or_revert()
 }
 
+/// Returns the [`AccountHash`] of the account which originated the current deploy.
+///
+/// Unlike an immediate caller, the base account stays the same however deeply nested the stored
+/// contract calls are, so it can be used e.g. to attribute fees to the deploy's origin.
+pub fn get_base_account() -> AccountHash {
+    get_caller()
+}
+
 /// Returns the current [`BlockTime`].
 pub fn get_blocktime() -> BlockTime {
     let dest_non_null_ptr = contract_api::alloc_bytes(BLOCKTIME_SERIALIZED_LENGTH);
@@ -206,6 +333,57 @@ pub fn get_blocktime() -> BlockTime {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns the gas remaining for the current execution, saturating at `u64::MAX`.
+pub fn get_remaining_gas() -> u64 {
+    let dest_non_null_ptr = contract_api::alloc_bytes(U64_SERIALIZED_LENGTH);
+    let bytes = unsafe {
+        ext_ffi::get_remaining_gas(dest_non_null_ptr.as_ptr());
+        Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            U64_SERIALIZED_LENGTH,
+            U64_SERIALIZED_LENGTH,
+        )
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// Reverts with the [`ApiError`] represented by `error` if the gas remaining for the current
+/// execution is below `min`.
+///
+/// This allows e.g. ensuring enough gas is left to complete cleanup after a sub-call.
+pub fn require_gas_reserve(min: u64, error: u32) {
+    if get_remaining_gas() < min {
+        revert(ApiError::from(error))
+    }
+}
+
+/// Returns the time budget remaining for the current execution, measured in gas.
+///
+/// Execution is bounded by gas rather than by wall-clock time, as validators must all agree on the
+/// outcome of a deploy and can't agree on how long it took to run.  A long-running contract, such
+/// as one processing a batch, can compare this against the gas it expects the next unit of work
+/// to take and stop early rather than run out of gas.
+pub fn remaining_time() -> u64 {
+    get_remaining_gas()
+}
+
+/// Returns the number of approvals carried by the current deploy.
+///
+/// Each approval corresponds to one of the deploy's authorization keys, so a multisig-aware
+/// contract can e.g. require a minimum number of signers before proceeding.
+pub fn get_approval_count() -> u32 {
+    let dest_non_null_ptr = contract_api::alloc_bytes(U32_SERIALIZED_LENGTH);
+    let bytes = unsafe {
+        ext_ffi::get_approval_count(dest_non_null_ptr.as_ptr());
+        Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            U32_SERIALIZED_LENGTH,
+            U32_SERIALIZED_LENGTH,
+        )
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
 /// Returns the current [`Phase`].
 pub fn get_phase() -> Phase {
     let dest_non_null_ptr = contract_api::alloc_bytes(PHASE_SERIALIZED_LENGTH);
@@ -220,6 +398,16 @@ pub fn get_phase() -> Phase {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns a deterministic 32-byte seed for the current deploy.
+///
+/// The seed is derived from the deploy hash and the current execution context, so the same deploy
+/// always observes the same seed while different deploys observe different ones.
+pub fn get_seed() -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    unsafe { ext_ffi::get_seed(seed.as_mut_ptr()) };
+    seed
+}
+
 /// Returns the requested named [`Key`] from the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -257,6 +445,24 @@ pub fn has_key(name: &str) -> bool {
     result == 0
 }
 
+/// Tries to take the reentrancy lock `name`, returning `false` if it is already held.
+///
+/// Locks are held per stored contract (or per account for session code) and only for the duration
+/// of the current execution; they are never written to global state.  A contract which calls out
+/// to other contracts can take a lock on entry to reject calls back into itself until it releases
+/// the lock with [`release_lock`].
+pub fn acquire_lock(name: &str) -> bool {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let result = unsafe { ext_ffi::acquire_lock(name_ptr, name_size) };
+    result == 0
+}
+
+/// Releases the reentrancy lock `name` previously taken with [`acquire_lock`].
+pub fn release_lock(name: &str) {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    unsafe { ext_ffi::release_lock(name_ptr, name_size) };
+}
+
 /// Stores the given [`Key`] under `name` in the current context's named keys.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -298,6 +504,29 @@ pub fn list_named_keys() -> NamedKeys {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns every [`URef`] the current context can access, each with the union of its known access
+/// rights.
+///
+/// This includes URefs held in the context's named keys, URefs passed in as arguments or created
+/// during execution, and the account's main purse.
+pub fn list_urefs() -> Vec<URef> {
+    let (total_urefs, result_size) = {
+        let mut total_urefs = MaybeUninit::uninit();
+        let mut result_size = 0;
+        let ret = unsafe {
+            ext_ffi::list_urefs(total_urefs.as_mut_ptr(), &mut result_size as *mut usize)
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        let total_urefs = unsafe { total_urefs.assume_init() };
+        (total_urefs, result_size)
+    };
+    if total_urefs == 0 {
+        return Vec::new();
+    }
+    let bytes = read_host_buffer(result_size).unwrap_or_revert();
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
 /// Validates uref against named keys.
 pub fn is_valid_uref(uref: URef) -> bool {
     let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(uref);
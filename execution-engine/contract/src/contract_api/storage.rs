@@ -6,7 +6,7 @@ use core::{convert::From, mem::MaybeUninit};
 use casperlabs_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    contracts::{ContractVersion, EntryPoints, NamedKeys},
+    contracts::{ContractPackage, ContractVersion, EntryPoints, NamedKeys},
     AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, URef,
     UREF_SERIALIZED_LENGTH,
 };
@@ -43,6 +43,31 @@ pub fn read_or_revert<T: CLTyped + FromBytes>(uref: URef) -> T {
         .unwrap_or_revert_with(ApiError::ValueNotFound)
 }
 
+/// Reads the values under each of `keys` in the global state with a single host call.
+///
+/// The result holds the value under each key in the same order as `keys`, or `None` for a key
+/// with no value.  Execution fails if any key can't be read by the current context, or holds a
+/// value other than a `CLValue`, e.g. an account, and reverts if any value is not `T`.
+pub fn read_keys<T: CLTyped + FromBytes>(keys: &[Key]) -> Vec<Option<T>> {
+    let (keys_ptr, keys_size, _bytes) = contract_api::to_ptr(keys.to_vec());
+
+    let values_size = {
+        let mut values_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::read_values(keys_ptr, keys_size, values_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { values_size.assume_init() }
+    };
+
+    let values_bytes = runtime::read_host_buffer(values_size).unwrap_or_revert();
+    let values: Vec<Option<Vec<u8>>> = bytesrepr::deserialize(values_bytes).unwrap_or_revert();
+    values
+        .into_iter()
+        .map(|maybe_value| {
+            maybe_value.map(|bytes| bytesrepr::deserialize(bytes).unwrap_or_revert())
+        })
+        .collect()
+}
+
 /// Reads the value under `key` in the context-local partition of global state.
 pub fn read_local<K: ToBytes, V: CLTyped + FromBytes>(
     key: &K,
@@ -65,6 +90,58 @@ pub fn read_local<K: ToBytes, V: CLTyped + FromBytes>(
     Ok(Some(bytesrepr::deserialize(value_bytes)?))
 }
 
+/// Reads the value stored under the named key `name` of the contract under `contract`.
+///
+/// Returns `None` if either the contract or its named key doesn't exist, and reverts if the value
+/// is not a `T`.  If the named key is a [`URef`], execution fails unless the caller holds it with
+/// read access.
+pub fn read_contract_value<T: FromBytes>(contract: ContractHash, name: &str) -> Option<T> {
+    let (contract_ptr, contract_size, _bytes1) = contract_api::to_ptr(contract);
+    let (name_ptr, name_size, _bytes2) = contract_api::to_ptr(name);
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::read_contract_value(
+                contract_ptr,
+                contract_size,
+                name_ptr,
+                name_size,
+                value_size.as_mut_ptr(),
+            )
+        };
+        match api_error::result_from(ret) {
+            Ok(_) => unsafe { value_size.assume_init() },
+            Err(ApiError::ValueNotFound) => return None,
+            Err(e) => runtime::revert(e),
+        }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    Some(bytesrepr::deserialize(value_bytes).unwrap_or_revert())
+}
+
+/// Returns the contract package under `contract_package_hash`, including its enabled and disabled
+/// versions.
+///
+/// The URefs of the returned package carry no access rights.  Reverts if the package doesn't
+/// exist.
+pub fn get_contract_package(contract_package_hash: ContractPackageHash) -> ContractPackage {
+    let (package_ptr, package_size, _bytes) = contract_api::to_ptr(contract_package_hash);
+
+    let value_size = {
+        let mut value_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::get_contract_package(package_ptr, package_size, value_size.as_mut_ptr())
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { value_size.assume_init() }
+    };
+
+    let value_bytes = runtime::read_host_buffer(value_size).unwrap_or_revert();
+    bytesrepr::deserialize(value_bytes).unwrap_or_revert()
+}
+
 /// Writes `value` under `uref` in the global state.
 pub fn write<T: CLTyped + ToBytes>(uref: URef, value: T) {
     let key = Key::from(uref);
@@ -290,6 +367,53 @@ pub fn add_contract_version(
     contract_package_hash: ContractPackageHash,
     entry_points: EntryPoints,
     named_keys: NamedKeys,
+) -> (ContractHash, ContractVersion) {
+    call_versioning_ffi(
+        ext_ffi::add_contract_version,
+        contract_package_hash,
+        entry_points,
+        named_keys,
+    )
+}
+
+/// Upgrades the contract package stored at the given hash by adding a new version of the contract
+/// to it.
+///
+/// Unlike [`add_contract_version`], this reverts with [`ApiError::UpgradeNotAuthorized`] if the
+/// caller does not hold the package's access key.
+pub fn upgrade_contract(
+    contract_package_hash: ContractPackageHash,
+    entry_points: EntryPoints,
+    named_keys: NamedKeys,
+) -> (ContractHash, ContractVersion) {
+    call_versioning_ffi(
+        ext_ffi::upgrade_contract,
+        contract_package_hash,
+        entry_points,
+        named_keys,
+    )
+}
+
+/// The signature shared by `ext_ffi::add_contract_version` and `ext_ffi::upgrade_contract`.
+type VersioningFfi = unsafe extern "C" fn(
+    *const u8,
+    usize,
+    *const u32,
+    *const u8,
+    usize,
+    *const u8,
+    usize,
+    *mut u8,
+    usize,
+    *mut usize,
+) -> i32;
+
+// Helper function to avoid duplication between `add_contract_version` and `upgrade_contract`.
+fn call_versioning_ffi(
+    ffi: VersioningFfi,
+    contract_package_hash: ContractPackageHash,
+    entry_points: EntryPoints,
+    named_keys: NamedKeys,
 ) -> (ContractHash, ContractVersion) {
     let (contract_package_hash_ptr, contract_package_hash_size, _bytes1) =
         contract_api::to_ptr(contract_package_hash);
@@ -302,7 +426,7 @@ pub fn add_contract_version(
     let mut contract_version: ContractVersion = 0;
 
     let ret = unsafe {
-        ext_ffi::add_contract_version(
+        ffi(
             contract_package_hash_ptr,
             contract_package_hash_size,
             &mut contract_version as *mut ContractVersion,
@@ -1,19 +1,32 @@
 //! Functions for interacting with the system contracts.
 
-use alloc::vec::Vec;
+use alloc::{format, string::String, vec::Vec};
 use core::mem::MaybeUninit;
 
 use casperlabs_types::{
-    account::AccountHash, api_error, bytesrepr, ApiError, ContractHash, SystemContractType,
-    TransferResult, TransferredTo, URef, U512, UREF_SERIALIZED_LENGTH,
+    account::AccountHash,
+    api_error,
+    bytesrepr::{self, ToBytes},
+    runtime_args,
+    system_contract_errors::mint,
+    ApiError, CLTyped, ContractHash, Key, PurseTransferResult, PurseTransferred, RuntimeArgs,
+    SystemContractType, TransferResult, TransferredTo, URef, U512, UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
-    contract_api::{self, runtime},
+    contract_api::{self, runtime, storage},
     ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
 };
 
+/// Name of the Mint contract's entry point which sets a purse's balance cap.
+const MINT_METHOD_SET_BALANCE_CAP: &str = "set_balance_cap";
+const MINT_ARG_PURSE: &str = "purse";
+const MINT_ARG_CAP: &str = "cap";
+
+/// Prefix of the context-local keys under which [`transfer_once`] records its idempotency keys.
+const TRANSFER_ONCE_PREFIX: &str = "transfer_once";
+
 fn get_system_contract(system_contract: SystemContractType) -> ContractHash {
     let system_contract_index = system_contract.into();
     let contract_hash: ContractHash = {
@@ -76,6 +89,9 @@ pub fn create_purse() -> URef {
 }
 
 /// Returns the balance in motes of the given purse.
+///
+/// Returns `None` if the purse doesn't exist, or if the caller doesn't hold a [`URef`] to it with
+/// read access rights.
 pub fn get_balance(purse: URef) -> Option<U512> {
     let (purse_ptr, purse_size, _bytes) = contract_api::to_ptr(purse);
 
@@ -93,6 +109,79 @@ pub fn get_balance(purse: URef) -> Option<U512> {
     Some(value)
 }
 
+/// Reverts with the [`ApiError`] represented by `error` if the balance of `purse` is below `min`.
+///
+/// Reverts with [`ApiError::InvalidPurse`] if the purse doesn't exist, or if the caller doesn't
+/// hold a [`URef`] to it with read access rights.
+pub fn revert_if_balance_below(purse: URef, min: U512, error: u32) {
+    let balance = get_balance(purse).unwrap_or_revert_with(ApiError::InvalidPurse);
+    if balance < min {
+        runtime::revert(ApiError::from(error))
+    }
+}
+
+/// Returns the purses held in the named keys of the current context, along with their names.
+///
+/// Named keys which are not [`URef`]s, or which are `URef`s not referring to a purse, are omitted.
+pub fn list_purses() -> Vec<(String, URef)> {
+    runtime::list_named_keys()
+        .into_iter()
+        .filter_map(|(name, key)| {
+            let uref = key.into_uref()?;
+            get_balance(uref).map(|_| (name, uref))
+        })
+        .collect()
+}
+
+/// Reverts with the [`ApiError`] represented by `error` unless a [`URef`] with the same address as
+/// `purse` is held in the named keys of the current context.
+///
+/// Access rights are ignored when comparing, so e.g. a read-only copy of an owned purse passes.
+/// In session code the current context is the caller's account.
+pub fn assert_purse_owned(purse: URef, error: u32) {
+    let owned = runtime::list_named_keys()
+        .values()
+        .filter_map(|key| key.into_uref())
+        .any(|uref| uref.addr() == purse.addr());
+    if !owned {
+        runtime::revert(ApiError::from(error))
+    }
+}
+
+/// Returns the name under which a sub-purse belonging to `account` should be stored.
+///
+/// The name is `prefix`, an underscore, then the lowercase base16 encoding of `account`, so the
+/// same inputs always yield the same name and distinct accounts yield distinct names.  This lets a
+/// contract managing e.g. per-user escrow purses find each user's purse in its named keys.
+pub fn sub_purse_name(prefix: &str, account: &AccountHash) -> String {
+    format!("{}_{}", prefix, account)
+}
+
+/// Reverts with the [`ApiError`] represented by `error` unless the balance of `purse` is exactly
+/// `before + expected_delta`.
+///
+/// This allows e.g. checking that an operation deposited the expected amount into `purse`.
+pub fn assert_balance_delta(purse: URef, before: U512, expected_delta: U512, error: u32) {
+    match (get_balance(purse), before.checked_add(expected_delta)) {
+        (Some(balance), Some(expected_balance)) if balance == expected_balance => (),
+        _ => runtime::revert(ApiError::from(error)),
+    }
+}
+
+/// Sets the maximum balance of the given purse to `cap`.
+///
+/// Subsequent transfers into `purse` which would take its balance above `cap` fail.  The caller
+/// must hold `purse` with write access rights.
+pub fn set_balance_cap(purse: URef, cap: U512) -> Result<(), ApiError> {
+    let args = runtime_args! {
+        MINT_ARG_PURSE => purse,
+        MINT_ARG_CAP => cap,
+    };
+    let result: Result<(), mint::Error> =
+        runtime::call_contract(get_mint(), MINT_METHOD_SET_BALANCE_CAP, args);
+    result.map_err(ApiError::from)
+}
+
 /// Transfers `amount` of motes from the default purse of the account to `target`
 /// account.  If `target` does not exist it will be created.
 pub fn transfer_to_account(target: AccountHash, amount: U512) -> TransferResult {
@@ -126,8 +215,35 @@ pub fn transfer_from_purse_to_account(
     TransferredTo::result_from(return_code)
 }
 
+/// Transfers `amount` of motes from `source` purse to `target` account.  If `target` does not exist
+/// it will only be created if `create_if_missing` is `true`, otherwise the transfer fails with
+/// [`ApiError::TargetAccountMissing`].
+pub fn transfer_from_purse_to_account_with_policy(
+    source: URef,
+    target: AccountHash,
+    amount: U512,
+    create_if_missing: bool,
+) -> TransferResult {
+    let (source_ptr, source_size, _bytes1) = contract_api::to_ptr(source);
+    let (target_ptr, target_size, _bytes2) = contract_api::to_ptr(target);
+    let (amount_ptr, amount_size, _bytes3) = contract_api::to_ptr(amount);
+    let return_code = unsafe {
+        ext_ffi::transfer_from_purse_to_account_with_policy(
+            source_ptr,
+            source_size,
+            target_ptr,
+            target_size,
+            amount_ptr,
+            amount_size,
+            create_if_missing as u32,
+        )
+    };
+    TransferredTo::result_from(return_code)
+}
+
 /// Transfers `amount` of motes from `source` purse to `target` purse.  If `target` does not exist
-/// the transfer fails.
+/// the transfer fails.  If `target` lacks add access rights the transfer fails with
+/// [`ApiError::TargetNotWritable`].
 pub fn transfer_from_purse_to_purse(
     source: URef,
     target: URef,
@@ -146,9 +262,103 @@ pub fn transfer_from_purse_to_purse(
             amount_size,
         )
     };
-    if result == 0 {
-        Ok(())
-    } else {
-        Err(ApiError::Transfer)
+    api_error::result_from(result)
+}
+
+/// Transfers `amount` of motes from `source` purse to `target` purse and, only if the transfer
+/// succeeds, writes `value` under `marker`.
+///
+/// The transfer and the write are part of the same execution, so they are committed together; e.g.
+/// an invoice can't be marked paid without its payment, nor paid without being marked.  If the
+/// transfer fails, `marker` is left untouched and the error is returned.
+pub fn transfer_from_purse_to_purse_and_write<T: CLTyped + ToBytes>(
+    source: URef,
+    target: URef,
+    amount: U512,
+    marker: URef,
+    value: T,
+) -> Result<(), ApiError> {
+    transfer_from_purse_to_purse(source, target, amount)?;
+    storage::write(marker, value);
+    Ok(())
+}
+
+/// Transfers `amount` of motes from `source` purse to `target` purse and, only if the transfer
+/// succeeds, puts `key` under `name` in the current context's named keys.
+///
+/// As with [`transfer_from_purse_to_purse_and_write`], both changes are committed together or not
+/// at all, so e.g. an escrow can't record a receipt for a payment which never happened.
+pub fn transfer_and_put_key(
+    source: URef,
+    target: URef,
+    amount: U512,
+    name: &str,
+    key: Key,
+) -> PurseTransferResult {
+    transfer_from_purse_to_purse(source, target, amount)?;
+    runtime::put_key(name, key);
+    Ok(PurseTransferred::Completed)
+}
+
+/// Atomically swaps funds between two purses, moving `amount_a` from `a` to `b` and `amount_b`
+/// from `b` to `a`.
+///
+/// The caller must hold both purses with write access rights.  If either leg fails, any funds
+/// already moved are returned, so no partial swap is left behind.
+pub fn swap_purses(a: URef, b: URef, amount_a: U512, amount_b: U512) -> PurseTransferResult {
+    transfer_from_purse_to_purse(a, b, amount_a)?;
+    if let Err(error) = transfer_from_purse_to_purse(b, a, amount_b) {
+        // `b` has just received `amount_a`, so returning it can only fail if the host misbehaves.
+        transfer_from_purse_to_purse(b, a, amount_a).unwrap_or_revert();
+        return Err(error);
+    }
+    Ok(PurseTransferred::Completed)
+}
+
+/// Transfers `amount` of motes from `source` purse to `target` purse at most once per `key`.
+///
+/// On success, `key` is recorded in the context-local partition of global state.  If `key` has
+/// already been recorded by a previous successful call, no funds are moved and
+/// `Ok(PurseTransferred::Duplicate)` is returned.
+pub fn transfer_once(
+    source: URef,
+    target: URef,
+    amount: U512,
+    key: [u8; 32],
+) -> PurseTransferResult {
+    let local_key = (TRANSFER_ONCE_PREFIX, key);
+    let already_transferred: Option<bool> = storage::read_local(&local_key).unwrap_or_revert();
+    if already_transferred.is_some() {
+        return Ok(PurseTransferred::Duplicate);
+    }
+    transfer_from_purse_to_purse(source, target, amount)?;
+    storage::write_local(local_key, true);
+    Ok(PurseTransferred::Completed)
+}
+
+#[cfg(test)]
+mod tests {
+    use casperlabs_types::account::AccountHash;
+
+    const PREFIX: &str = "escrow";
+    const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+    const ACCOUNT_2_ADDR: AccountHash = AccountHash::new([2u8; 32]);
+
+    #[test]
+    fn should_derive_same_name_for_same_inputs() {
+        let name = super::sub_purse_name(PREFIX, &ACCOUNT_1_ADDR);
+        assert_eq!(name, super::sub_purse_name(PREFIX, &ACCOUNT_1_ADDR));
+        assert_eq!(
+            name,
+            "escrow_0101010101010101010101010101010101010101010101010101010101010101"
+        );
+    }
+
+    #[test]
+    fn should_derive_different_names_for_different_inputs() {
+        let name_1 = super::sub_purse_name(PREFIX, &ACCOUNT_1_ADDR);
+        let name_2 = super::sub_purse_name(PREFIX, &ACCOUNT_2_ADDR);
+        assert_ne!(name_1, name_2);
+        assert_ne!(name_1, super::sub_purse_name("other", &ACCOUNT_1_ADDR));
     }
 }
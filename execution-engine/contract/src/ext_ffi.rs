@@ -344,6 +344,8 @@ extern "C" {
     /// occurred, where 0 means a successful transfer, 1 means the transfer
     /// failed (this could be because the source purse had insufficient tokens or
     /// because there was not valid access to the source purse or target purse).
+    /// If the target purse lacks add access rights, the value of
+    /// [`casperlabs_types::ApiError::TargetNotWritable`] is returned instead.
     ///
     /// # Arguments
     ///
@@ -627,6 +629,237 @@ extern "C" {
         urefs_size: usize,
     ) -> i32;
 
+    /// This function writes a 32-byte seed to the given address in wasm memory.  The seed is
+    /// derived deterministically from the hash of the deploy being executed, the current phase
+    /// and the context in which the function is called, so that repeated executions of the same
+    /// deploy observe the same seed.  It is up to the caller to ensure there are 32 bytes
+    /// allocated at `dest_ptr`, otherwise data corruption in the wasm memory may occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn get_seed(dest_ptr: *mut u8);
+
+    /// Adds new contract version to a contract package, provided the caller is authorized to
+    /// upgrade it, i.e. holds the package's access key.  Returns
+    /// [`casperlabs_types::ApiError::UpgradeNotAuthorized`] otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `version_ptr` - output parameter where new version assigned by host is set
+    /// * `entry_points_ptr` - pointer to serialized [`casperlabs_types::EntryPoints`]
+    /// * `entry_points_size` - size of serialized [`casperlabs_types::EntryPoints`]
+    /// * `named_keys_ptr` - pointer to serialized [`casperlabs_types::contracts::NamedKeys`]
+    /// * `named_keys_size` - size of serialized [`casperlabs_types::contracts::NamedKeys`]
+    /// * `output_ptr` - pointer to a memory where host assigned contract hash is set to
+    /// * `output_size` - size of memory area that host can write to
+    /// * `bytes_written_ptr` - pointer to a value where host will set a number of bytes written to
+    ///   the `output_size` pointer
+    pub fn upgrade_contract(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        version_ptr: *const u32,
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        output_ptr: *mut u8,
+        output_size: usize,
+        bytes_written_ptr: *mut usize,
+    ) -> i32;
+
+    /// This function behaves like [`transfer_from_purse_to_account`], except that if the target
+    /// account does not exist it is only created when `create_if_missing` is non-zero.  Otherwise
+    /// no transfer takes place and the return value is 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_ptr` - pointer in wasm memory to bytes representing the source
+    ///   [`casperlabs_types::uref::URef`] to transfer from
+    /// * `source_size` - size of the source [`casperlabs_types::uref::URef`] (in bytes)
+    /// * `target_ptr` - pointer in wasm memory to bytes representing the target account to transfer
+    ///   to
+    /// * `target_size` - size of the target (in bytes)
+    /// * `amount_ptr` - pointer in wasm memory to bytes representing the amount to transfer to the
+    ///   target account
+    /// * `amount_size` - size of the amount (in bytes)
+    /// * `create_if_missing` - non-zero if a missing target account should be created
+    pub fn transfer_from_purse_to_account_with_policy(
+        source_ptr: *const u8,
+        source_size: usize,
+        target_ptr: *const u8,
+        target_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+        create_if_missing: u32,
+    ) -> i32;
+
+    /// Reads the value stored under the named key `name` of the contract under `contract_hash`.
+    /// If the value is found, it is serialized and buffered in the runtime, and can be obtained
+    /// via the [`read_host_buffer`] function.  Returns standard error code, where
+    /// `ApiError::ValueNotFound` means either the contract or its named key doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_hash_ptr` - pointer to serialized contract hash
+    /// * `contract_hash_size` - size of the serialized contract hash (in bytes)
+    /// * `name_ptr` - pointer to serialized name of the contract's named key
+    /// * `name_size` - size of the serialized name (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of bytes read
+    pub fn read_contract_value(
+        contract_hash_ptr: *const u8,
+        contract_hash_size: usize,
+        name_ptr: *const u8,
+        name_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+
+    /// This function gets the gas remaining for the current execution, and writes it to
+    /// `dest_ptr`.  The value is always a 64-bit unsigned integer, saturating at `u64::MAX`.  It is
+    /// up to the caller to ensure there are 8 bytes allocated at `dest_ptr`, otherwise data
+    /// corruption in the wasm memory may occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn get_remaining_gas(dest_ptr: *mut u8);
+
+    /// This function behaves like [`revert`], additionally recording the given message in the
+    /// execution result of the deploy to help explain the failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - error code of the revert
+    /// * `message_ptr` - pointer to serialized message
+    /// * `message_size` - size of serialized message
+    pub fn revert_with_message(status: u32, message_ptr: *const u8, message_size: usize) -> !;
+
+    /// This function causes a `Trap` which terminates the currently running module successfully.
+    /// Unlike [`revert`], the effects of the execution up to this point are kept, and only the gas
+    /// used so far is charged.
+    pub fn finish_early() -> !;
+
+    /// This function queries the host side for the argument at the given index and returns a size
+    /// in bytes of that argument. Returns zero for success or non-zero value for failure as
+    /// described in standard error codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - index of the argument, in the order the arguments were passed
+    /// * `dest_size` - pointer to the location where the argument size will be written
+    pub fn get_arg_size(index: u32, dest_size: *mut usize) -> i32;
+    /// This function copies the serialized bytes of the argument at the given index into the wasm
+    /// memory, beginning at the provided offset. The size of the data which will be written is
+    /// returned from the `get_arg_size` call.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - index of the argument, in the order the arguments were passed
+    /// * `dest_ptr` - pointer to the location where argument bytes will be copied from the host
+    ///   side
+    /// * `dest_size` - size of destination pointer
+    pub fn get_arg(index: u32, dest_ptr: *mut u8, dest_size: usize) -> i32;
+
+    /// Reads the contract package under `contract_package_hash`.  If it is found, it is serialized
+    /// and buffered in the runtime, and can be obtained via the [`read_host_buffer`] function.
+    /// Returns standard error code, where `ApiError::ValueNotFound` means the package doesn't
+    /// exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash
+    /// * `contract_package_hash_size` - size of the serialized contract package hash (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of bytes read
+    pub fn get_contract_package(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+
+    /// Writes the action thresholds of the account which made the deploy to `dest_ptr`, as two
+    /// bytes holding the deployment threshold followed by the key management threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer to position in wasm memory to write the result
+    pub fn get_action_thresholds(dest_ptr: *mut u8);
+    /// Writes the total weight of the keys which signed the deploy to `dest_ptr` as a single byte.
+    /// Only keys associated with the deploying account contribute to the total.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer to position in wasm memory to write the result
+    pub fn get_total_weight(dest_ptr: *mut u8);
+
+    /// Tries to take the reentrancy lock with the given name for the current context.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_ptr` - pointer to the lock name
+    /// * `name_size` - size of the lock name
+    ///
+    /// Returns `0` if the lock was acquired, or `1` if it was already held.
+    pub fn acquire_lock(name_ptr: *const u8, name_size: usize) -> i32;
+    /// Releases the reentrancy lock with the given name for the current context.
+    ///
+    /// # Arguments
+    ///
+    /// * `name_ptr` - pointer to the lock name
+    /// * `name_size` - size of the lock name
+    pub fn release_lock(name_ptr: *const u8, name_size: usize);
+
+    /// This function gets the number of approvals (authorization keys) the current deploy carries,
+    /// and writes it to `dest_ptr` as a 32-bit unsigned integer.  It is up to the caller to ensure
+    /// there are 4 bytes allocated at `dest_ptr`, otherwise data corruption in the wasm memory may
+    /// occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn get_approval_count(dest_ptr: *mut u8);
+
+    /// This function lists every [`casperlabs_types::uref::URef`] the current context can access,
+    /// writing their count to `total_urefs` and the size of their serialized form to
+    /// `result_size`.  The serialized `Vec<URef>` is left in the host buffer, to be retrieved via
+    /// [`read_host_buffer`].  If there are no such URefs, the host buffer is left empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_urefs` - pointer to the offset in wasm memory where the number of URefs will be
+    ///   written
+    /// * `result_size` - pointer to the offset in wasm memory where the size of the serialized
+    ///   URefs will be written
+    pub fn list_urefs(total_urefs: *mut usize, result_size: *mut usize) -> i32;
+
+    /// This function serializes every argument passed to the current module invocation as
+    /// [`casperlabs_types::RuntimeArgs`], writing the number of arguments to `total_args` and the
+    /// size of the serialized form to `result_size`.  The serialized `RuntimeArgs` are left in the
+    /// host buffer, to be retrieved via [`read_host_buffer`].  If there are no arguments, the host
+    /// buffer is left empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_args` - pointer to the offset in wasm memory where the number of arguments will be
+    ///   written
+    /// * `result_size` - pointer to the offset in wasm memory where the size of the serialized
+    ///   arguments will be written
+    pub fn load_runtime_args(total_args: *mut usize, result_size: *mut usize) -> i32;
+
+    /// This function reads the values stored under each of several keys in the global state, read
+    /// by de-serializing the bytes in wasm memory from offset `keys_ptr` to `keys_ptr + keys_size`
+    /// as a `Vec<Key>`.  The results are serialized as a `Vec<Option<Vec<u8>>>` holding the
+    /// serialized value under each key in order, or `None` for a key with no value, and left in
+    /// the host buffer, to be retrieved via [`read_host_buffer`].
+    ///
+    /// # Arguments
+    ///
+    /// * `keys_ptr` - pointer (offset in wasm linear memory) to serialized form of the keys to read
+    /// * `keys_size` - size of the serialized keys (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of the serialized results
+    pub fn read_values(keys_ptr: *const u8, keys_size: usize, output_size: *mut usize) -> i32;
+
     /// Prints data directly to stanadard output on the host.
     ///
     /// # Arguments
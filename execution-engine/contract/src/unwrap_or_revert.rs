@@ -1,6 +1,7 @@
-//! Home of [`UnwrapOrRevert`], a convenience trait for unwrapping values.
+//! Home of [`UnwrapOrRevert`], a convenience trait for unwrapping values, and
+//! [`CheckedSubOrRevert`], a convenience trait for subtracting balances.
 
-use casperlabs_types::ApiError;
+use casperlabs_types::{ApiError, U512};
 
 use crate::contract_api::runtime;
 
@@ -35,3 +36,19 @@ impl<T> UnwrapOrRevert<T> for Option<T> {
         self.unwrap_or_else(|| runtime::revert(error.into()))
     }
 }
+
+/// A trait which provides syntactic sugar for subtracting one value from another or calling
+/// [`runtime::revert`] if this would underflow.  It is implemented for `U512`.
+///
+/// Where clamping to zero is wanted instead, use `U512::saturating_sub`.
+pub trait CheckedSubOrRevert: Sized {
+    /// Returns `self - other`, or calls [`runtime::revert`] with the provided `error` if `other` is
+    /// greater than `self`.
+    fn checked_sub_or_revert<E: Into<ApiError>>(self, other: Self, error: E) -> Self;
+}
+
+impl CheckedSubOrRevert for U512 {
+    fn checked_sub_or_revert<E: Into<ApiError>>(self, other: Self, error: E) -> Self {
+        self.checked_sub(other).unwrap_or_revert_with(error)
+    }
+}
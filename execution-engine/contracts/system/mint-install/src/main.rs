@@ -30,6 +30,11 @@ pub extern "C" fn transfer() {
     mint_token::transfer();
 }
 
+#[no_mangle]
+pub extern "C" fn set_balance_cap() {
+    mint_token::set_balance_cap();
+}
+
 #[no_mangle]
 pub extern "C" fn install() {
     let entry_points = mint_token::get_entry_points();
@@ -20,3 +20,8 @@ pub extern "C" fn balance() {
 pub extern "C" fn transfer() {
     mint_token::transfer();
 }
+
+#[no_mangle]
+pub extern "C" fn set_balance_cap() {
+    mint_token::set_balance_cap();
+}
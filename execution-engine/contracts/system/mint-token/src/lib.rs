@@ -23,11 +23,13 @@ pub const METHOD_MINT: &str = "mint";
 pub const METHOD_CREATE: &str = "create";
 pub const METHOD_BALANCE: &str = "balance";
 pub const METHOD_TRANSFER: &str = "transfer";
+pub const METHOD_SET_BALANCE_CAP: &str = "set_balance_cap";
 
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_PURSE: &str = "purse";
 pub const ARG_SOURCE: &str = "source";
 pub const ARG_TARGET: &str = "target";
+pub const ARG_CAP: &str = "cap";
 
 pub struct MintContract;
 
@@ -39,6 +41,10 @@ impl RuntimeProvider for MintContract {
     fn put_key(&mut self, name: &str, key: Key) {
         runtime::put_key(name, key)
     }
+
+    fn has_key(&self, name: &str) -> bool {
+        runtime::has_key(name)
+    }
 }
 
 impl StorageProvider for MintContract {
@@ -107,6 +113,15 @@ pub fn transfer() {
     runtime::ret(ret);
 }
 
+pub fn set_balance_cap() {
+    let mut mint_contract = MintContract;
+    let purse: URef = runtime::get_named_arg(ARG_PURSE);
+    let cap: U512 = runtime::get_named_arg(ARG_CAP);
+    let result: Result<(), Error> = mint_contract.set_balance_cap(purse, cap);
+    let ret = CLValue::from_t(result).unwrap_or_revert();
+    runtime::ret(ret);
+}
+
 pub fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
 
@@ -156,5 +171,20 @@ pub fn get_entry_points() -> EntryPoints {
     );
     entry_points.add_entry_point(entry_point);
 
+    let entry_point = EntryPoint::new(
+        METHOD_SET_BALANCE_CAP,
+        vec![
+            Parameter::new(ARG_PURSE, CLType::URef),
+            Parameter::new(ARG_CAP, CLType::U512),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
     entry_points
 }
@@ -3,7 +3,7 @@
 
 extern crate alloc;
 
-use alloc::{collections::BTreeMap, string::String, vec};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
 
 use alloc::{boxed::Box, string::ToString};
 use contract::{
@@ -11,8 +11,9 @@ use contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use pos::{
-    ARG_ACCOUNT_KEY, ARG_AMOUNT, ARG_PURSE, METHOD_BOND, METHOD_FINALIZE_PAYMENT,
-    METHOD_GET_PAYMENT_PURSE, METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE, METHOD_UNBOND,
+    ARG_ACCOUNT_KEY, ARG_AMOUNT, ARG_AMOUNTS, ARG_PURSE, METHOD_BOND, METHOD_DISTRIBUTE,
+    METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE, METHOD_GET_REFUND_PURSE,
+    METHOD_GET_VALIDATOR_SET, METHOD_SET_REFUND_PURSE, METHOD_UNBOND,
 };
 use proof_of_stake::Stakes;
 use types::{
@@ -23,7 +24,7 @@ use types::{
     },
     runtime_args,
     system_contract_errors::mint,
-    CLType, CLValue, ContractPackageHash, Key, RuntimeArgs, URef, U512,
+    CLType, CLTyped, CLValue, ContractPackageHash, Key, RuntimeArgs, URef, U512,
 };
 
 const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
@@ -68,6 +69,16 @@ pub extern "C" fn finalize_payment() {
     pos::finalize_payment();
 }
 
+#[no_mangle]
+pub extern "C" fn get_validator_set() {
+    pos::get_validator_set();
+}
+
+#[no_mangle]
+pub extern "C" fn distribute() {
+    pos::distribute();
+}
+
 #[no_mangle]
 pub extern "C" fn install() {
     let mint_package_hash: ContractPackageHash = runtime::get_named_arg(ARG_MINT_PACKAGE_HASH);
@@ -160,6 +171,27 @@ pub extern "C" fn install() {
         );
         entry_points.add_entry_point(finalize_payment);
 
+        let get_validator_set = EntryPoint::new(
+            METHOD_GET_VALIDATOR_SET.to_string(),
+            vec![],
+            Vec::<(AccountHash, U512)>::cl_type(),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(get_validator_set);
+
+        let distribute = EntryPoint::new(
+            METHOD_DISTRIBUTE.to_string(),
+            vec![Parameter::new(
+                ARG_AMOUNTS,
+                Vec::<(AccountHash, U512)>::cl_type(),
+            )],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(distribute);
+
         entry_points
     };
 
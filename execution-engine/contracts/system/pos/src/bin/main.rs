@@ -30,3 +30,13 @@ pub extern "C" fn get_refund_purse() {
 pub extern "C" fn finalize_payment() {
     pos::finalize_payment();
 }
+
+#[no_mangle]
+pub extern "C" fn get_validator_set() {
+    pos::get_validator_set();
+}
+
+#[no_mangle]
+pub extern "C" fn distribute() {
+    pos::distribute();
+}
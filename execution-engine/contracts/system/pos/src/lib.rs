@@ -5,6 +5,7 @@ extern crate alloc;
 use alloc::{
     collections::{BTreeMap, BTreeSet},
     string::String,
+    vec::Vec,
 };
 
 use contract::{
@@ -25,6 +26,8 @@ pub const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
 pub const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
 pub const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
 pub const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+pub const METHOD_GET_VALIDATOR_SET: &str = "get_validator_set";
+pub const METHOD_DISTRIBUTE: &str = "distribute";
 
 const BONDING_KEY: u8 = 1;
 const UNBONDING_KEY: u8 = 2;
@@ -32,6 +35,7 @@ const UNBONDING_KEY: u8 = 2;
 pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_PURSE: &str = "purse";
 pub const ARG_ACCOUNT_KEY: &str = "account";
+pub const ARG_AMOUNTS: &str = "amounts";
 
 pub struct ProofOfStakeContract;
 
@@ -226,3 +230,18 @@ pub fn finalize_payment() {
         .finalize_payment(amount_spent, account)
         .unwrap_or_revert();
 }
+
+pub fn get_validator_set() {
+    let pos_contract = ProofOfStakeContract;
+    let validator_set: Vec<(AccountHash, U512)> =
+        pos_contract.get_validator_set().unwrap_or_revert();
+    let return_value = CLValue::from_t(validator_set).unwrap_or_revert();
+    runtime::ret(return_value);
+}
+
+pub fn distribute() {
+    let mut pos_contract = ProofOfStakeContract;
+
+    let amounts: Vec<(AccountHash, U512)> = runtime::get_named_arg(ARG_AMOUNTS);
+    pos_contract.distribute(amounts).unwrap_or_revert();
+}
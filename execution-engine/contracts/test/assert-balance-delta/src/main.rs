@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+const ARG_AMOUNT: &str = "amount";
+const ARG_EXPECTED_DELTA: &str = "expected_delta";
+const UNEXPECTED_BALANCE_DELTA: u16 = 100;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let expected_delta: U512 = runtime::get_named_arg(ARG_EXPECTED_DELTA);
+
+    let source = account::get_main_purse();
+    let target = system::create_purse();
+    let before = system::get_balance(target).unwrap_or_revert();
+
+    system::transfer_from_purse_to_purse(source, target, amount).unwrap_or_revert();
+
+    system::assert_balance_delta(
+        target,
+        before,
+        expected_delta,
+        ApiError::User(UNEXPECTED_BALANCE_DELTA).into(),
+    );
+}
@@ -0,0 +1,19 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, system};
+use types::ApiError;
+
+const ARG_STORE_PURSE: &str = "store_purse";
+const PURSE_NAME: &str = "purse";
+const NOT_OWNED: u16 = 100;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let store_purse: bool = runtime::get_named_arg(ARG_STORE_PURSE);
+    let purse = system::create_purse();
+    if store_purse {
+        runtime::put_key(PURSE_NAME, purse.into());
+    }
+    system::assert_purse_owned(purse, ApiError::User(NOT_OWNED).into());
+}
@@ -0,0 +1,27 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::contract_api::runtime;
+use types::{contracts::ContractVersion, ContractPackageHash, RuntimeArgs};
+
+const ARG_CONTRACT_PACKAGE: &str = "contract_package";
+const ARG_VERSION: &str = "version";
+const ARG_ENTRY_POINT: &str = "entry_point";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_package_hash: ContractPackageHash = runtime::get_named_arg(ARG_CONTRACT_PACKAGE);
+    let contract_version: Option<ContractVersion> = runtime::get_named_arg(ARG_VERSION);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+
+    runtime::call_versioned_contract::<()>(
+        contract_package_hash,
+        contract_version,
+        &entry_point,
+        RuntimeArgs::default(),
+    );
+}
@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{account, runtime, storage};
+use types::ApiError;
+
+const DEPLOYMENT_THRESHOLD: &str = "deployment_threshold";
+const KEY_MANAGEMENT_THRESHOLD: &str = "key_management_threshold";
+const TOTAL_WEIGHT: &str = "total_weight";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let (deployment_threshold, key_management_threshold) = account::get_action_thresholds();
+    let total_weight = account::get_total_weight();
+
+    // Mirrors a privileged operation which requires the deploy be signed with enough weight to
+    // manage the account's keys.
+    if total_weight < key_management_threshold {
+        runtime::revert(ApiError::PermissionDenied);
+    }
+
+    runtime::put_key(
+        DEPLOYMENT_THRESHOLD,
+        storage::new_uref(deployment_threshold).into(),
+    );
+    runtime::put_key(
+        KEY_MANAGEMENT_THRESHOLD,
+        storage::new_uref(key_management_threshold).into(),
+    );
+    runtime::put_key(TOTAL_WEIGHT, storage::new_uref(total_weight).into());
+}
@@ -0,0 +1,30 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::CheckedSubOrRevert,
+};
+use types::{ApiError, U512};
+
+const ARG_BALANCE: &str = "balance";
+const ARG_AMOUNT: &str = "amount";
+const REMAINING_BALANCE: &str = "remaining_balance";
+
+#[repr(u16)]
+enum Error {
+    InsufficientBalance = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let balance: U512 = runtime::get_named_arg(ARG_BALANCE);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let remaining_balance =
+        balance.checked_sub_or_revert(amount, ApiError::User(Error::InsufficientBalance as u16));
+    runtime::put_key(
+        REMAINING_BALANCE,
+        storage::new_uref(remaining_balance).into(),
+    );
+}
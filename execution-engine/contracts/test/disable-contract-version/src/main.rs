@@ -0,0 +1,19 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ContractHash, ContractPackageHash};
+
+const ARG_CONTRACT_PACKAGE: &str = "contract_package";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_package_hash: ContractPackageHash = runtime::get_named_arg(ARG_CONTRACT_PACKAGE);
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+
+    storage::disable_contract_version(contract_package_hash, contract_hash).unwrap_or_revert();
+}
@@ -0,0 +1,58 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use contract::contract_api::{runtime, storage};
+use types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    CLType, EntryPointAccess, EntryPointType, Key,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const ARG_CONFLICT: &str = "conflict";
+const SHARED_NAME: &str = "shared";
+const NEW_NAME: &str = "new";
+const CONTRACT_HASH_KEY_NAME: &str = "contract_hash";
+
+#[no_mangle]
+pub extern "C" fn delegate() {}
+
+fn entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    let entry_point = EntryPoint::new(
+        ENTRY_FUNCTION_NAME.to_string(),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+    entry_points
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let conflict: bool = runtime::get_named_arg(ARG_CONFLICT);
+
+    let (package_hash, _access_uref) = storage::create_contract_package_at_hash();
+
+    let mut first_named_keys = NamedKeys::new();
+    first_named_keys.insert(SHARED_NAME.to_string(), Key::Hash([1; 32]));
+    storage::add_contract_version(package_hash, entry_points(), first_named_keys);
+
+    let shared_key = if conflict {
+        Key::Hash([2; 32])
+    } else {
+        Key::Hash([1; 32])
+    };
+    let mut second_named_keys = NamedKeys::new();
+    second_named_keys.insert(SHARED_NAME.to_string(), shared_key);
+    second_named_keys.insert(NEW_NAME.to_string(), Key::Hash([3; 32]));
+    let (contract_hash, _contract_version) =
+        storage::add_contract_version(package_hash, entry_points(), second_named_keys);
+
+    runtime::put_key(CONTRACT_HASH_KEY_NAME, contract_hash.into());
+}
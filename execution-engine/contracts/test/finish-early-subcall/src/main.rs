@@ -0,0 +1,39 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use contract::contract_api::{runtime, storage};
+use types::{CLType, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, RuntimeArgs};
+
+const CALLEE_ENTRY_POINT_NAME: &str = "callee";
+const AFTER_CALL: &str = "after_call";
+
+#[no_mangle]
+pub extern "C" fn callee() {
+    runtime::finish_early();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        CALLEE_ENTRY_POINT_NAME.to_string(),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+
+    let () = runtime::call_contract(
+        contract_hash,
+        CALLEE_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+
+    // Only reached if `finish_early` in the callee returns control here.
+    runtime::put_key(AFTER_CALL, storage::new_uref(()).into());
+}
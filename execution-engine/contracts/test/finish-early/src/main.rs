@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_FINISH_EARLY: &str = "finish_early";
+const BEFORE: &str = "before";
+const AFTER: &str = "after";
+const ITERATIONS: u64 = 1_000;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let finish_early: bool = runtime::get_named_arg(ARG_FINISH_EARLY);
+
+    runtime::put_key(BEFORE, storage::new_uref(()).into());
+
+    if finish_early {
+        runtime::finish_early();
+    }
+
+    let counter = storage::new_uref(0u64);
+    for _ in 0..ITERATIONS {
+        storage::add(counter, 1u64);
+    }
+    runtime::put_key(AFTER, counter.into());
+}
@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::bytesrepr::ToBytes;
+
+const RECEIVED_ARGS_KEY: &str = "received_args";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let received_args = runtime::forward_all_args();
+    // Loading the args a second time fails if the first call left the host buffer occupied.
+    let received_again = runtime::forward_all_args();
+    assert_eq!(received_args, received_again);
+
+    let received_args_bytes = received_args.into_bytes().unwrap_or_revert();
+    runtime::put_key(
+        RECEIVED_ARGS_KEY,
+        storage::new_uref(received_args_bytes).into(),
+    );
+}
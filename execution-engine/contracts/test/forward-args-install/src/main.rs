@@ -0,0 +1,46 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    bytesrepr::ToBytes,
+    contracts::{EntryPoint, EntryPoints},
+    CLType, CLValue, EntryPointAccess, EntryPointType,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "record_args";
+const HASH_KEY_NAME: &str = "contract_hash";
+
+#[no_mangle]
+pub extern "C" fn record_args() {
+    let received_args = runtime::forward_all_args().into_bytes().unwrap_or_revert();
+    let result = CLValue::from_t(received_args).unwrap_or_revert();
+    runtime::ret(result)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::List(Box::new(CLType::U8)),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::{runtime, storage};
+use types::ContractHash;
+
+const ENTRY_FUNCTION_NAME: &str = "record_args";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const RECEIVED_ARGS_KEY: &str = "received_args";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+
+    let received_args: Vec<u8> = runtime::call_contract(
+        contract_hash,
+        ENTRY_FUNCTION_NAME,
+        runtime::forward_all_args(),
+    );
+
+    runtime::put_key(RECEIVED_ARGS_KEY, storage::new_uref(received_args).into());
+}
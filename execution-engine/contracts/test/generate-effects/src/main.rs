@@ -0,0 +1,16 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_COUNT: &str = "count";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let count: u32 = runtime::get_named_arg(ARG_COUNT);
+
+    // Each new uref is written to global state as a separate effect entry.
+    for value in 0..count {
+        storage::new_uref(value);
+    }
+}
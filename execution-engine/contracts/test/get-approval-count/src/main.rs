@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const APPROVAL_COUNT_KEY: &str = "approval_count";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let approval_count: u32 = runtime::get_approval_count();
+    let approval_count_uref = storage::new_uref(approval_count);
+    runtime::put_key(APPROVAL_COUNT_KEY, approval_count_uref.into());
+}
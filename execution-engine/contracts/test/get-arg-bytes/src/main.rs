@@ -0,0 +1,22 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::format;
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_COUNT: u32 = 3;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // Callers pass fewer than `ARG_COUNT` args, so lookups of absent args are recorded too.
+    for index in 0..ARG_COUNT {
+        let arg_bytes = runtime::get_arg_bytes(index);
+        runtime::put_key(
+            &format!("arg_{}", index),
+            storage::new_uref(arg_bytes).into(),
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use contract::{
+    contract_api::{runtime, storage},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::api_error;
+
+const ARG_VALUE: &str = "value";
+const BY_INDEX: &str = "by_index";
+const BY_NAME: &str = "by_name";
+/// Larger than the serialized `value` arg passed by the test.
+const BUFFER_SIZE: usize = 64;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut by_index = vec![0u8; BUFFER_SIZE];
+    let ret = unsafe { ext_ffi::get_arg(0, by_index.as_mut_ptr(), by_index.len()) };
+    api_error::result_from(ret).unwrap_or_revert();
+    runtime::put_key(BY_INDEX, storage::new_uref(by_index).into());
+
+    let mut by_name = vec![0u8; BUFFER_SIZE];
+    let ret = unsafe {
+        ext_ffi::get_named_arg(
+            ARG_VALUE.as_bytes().as_ptr(),
+            ARG_VALUE.len(),
+            by_name.as_mut_ptr(),
+            by_name.len(),
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+    runtime::put_key(BY_NAME, storage::new_uref(by_name).into());
+}
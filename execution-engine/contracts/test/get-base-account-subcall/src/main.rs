@@ -0,0 +1,84 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, string::ToString, vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    account::AccountHash, runtime_args, CLType, CLTyped, CLValue, ContractHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Parameter, RuntimeArgs,
+};
+
+const INNER_ENTRY_POINT_NAME: &str = "get_base_account_inner";
+const OUTER_ENTRY_POINT_NAME: &str = "get_base_account_outer";
+const ARG_ACCOUNT: &str = "account";
+const ARG_INNER_CONTRACT: &str = "inner_contract";
+
+#[no_mangle]
+pub extern "C" fn get_base_account_inner() {
+    let base_account: AccountHash = runtime::get_base_account();
+    runtime::ret(CLValue::from_t(base_account).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_base_account_outer() {
+    let inner_contract: ContractHash = runtime::get_named_arg(ARG_INNER_CONTRACT);
+    let base_account: AccountHash = runtime::get_base_account();
+    let inner_base_account: AccountHash = runtime::call_contract(
+        inner_contract,
+        INNER_ENTRY_POINT_NAME,
+        RuntimeArgs::default(),
+    );
+    assert_eq!(
+        inner_base_account, base_account,
+        "inner base account was not outer base account"
+    );
+    runtime::ret(CLValue::from_t(base_account).unwrap_or_revert());
+}
+
+fn new_contract(entry_point: EntryPoint) -> ContractHash {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_point);
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+    contract_hash
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let known_account_hash: AccountHash = runtime::get_named_arg(ARG_ACCOUNT);
+    assert_eq!(
+        runtime::get_base_account(),
+        known_account_hash,
+        "base account was not known account hash"
+    );
+
+    let inner_contract = new_contract(EntryPoint::new(
+        INNER_ENTRY_POINT_NAME.to_string(),
+        vec![],
+        CLType::FixedList(Box::new(CLType::U8), 32),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let outer_contract = new_contract(EntryPoint::new(
+        OUTER_ENTRY_POINT_NAME.to_string(),
+        vec![Parameter::new(ARG_INNER_CONTRACT, ContractHash::cl_type())],
+        CLType::FixedList(Box::new(CLType::U8), 32),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let subcall_base_account: AccountHash = runtime::call_contract(
+        outer_contract,
+        OUTER_ENTRY_POINT_NAME,
+        runtime_args! { ARG_INNER_CONTRACT => inner_contract },
+    );
+    assert_eq!(
+        subcall_base_account, known_account_hash,
+        "subcall base account was not known account hash"
+    );
+}
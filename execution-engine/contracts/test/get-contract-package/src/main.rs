@@ -0,0 +1,30 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ContractPackageHash, Key};
+
+const ARG_PACKAGE_HASH: &str = "package_hash";
+const VERSION_COUNT: &str = "version_count";
+const DISABLED_VERSION_COUNT: &str = "disabled_version_count";
+const CURRENT_CONTRACT_HASH: &str = "current_contract_hash";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let package_hash: ContractPackageHash = runtime::get_named_arg(ARG_PACKAGE_HASH);
+    let package = storage::get_contract_package(package_hash);
+
+    let version_count = package.versions().len() as u32;
+    let disabled_version_count = package.disabled_versions().len() as u32;
+    let current_contract_hash = package.current_contract_hash().unwrap_or_revert();
+
+    runtime::put_key(VERSION_COUNT, storage::new_uref(version_count).into());
+    runtime::put_key(
+        DISABLED_VERSION_COUNT,
+        storage::new_uref(disabled_version_count).into(),
+    );
+    runtime::put_key(CURRENT_CONTRACT_HASH, Key::from(current_contract_hash));
+}
@@ -0,0 +1,19 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage, system};
+use types::{AccessRights, URef, U512};
+
+const ARG_PURSE_ADDR: &str = "purse_addr";
+const BALANCE_RESULT: &str = "balance_result";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // Only the address is passed in, so the resulting `URef` is held by the caller only if it
+    // already had access to the purse.
+    let purse_addr: [u8; 32] = runtime::get_named_arg(ARG_PURSE_ADDR);
+    let purse = URef::new(purse_addr, AccessRights::READ);
+
+    let balance: Option<U512> = system::get_balance(purse);
+    runtime::put_key(BALANCE_RESULT, storage::new_uref(balance).into());
+}
@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+
+const SEED_KEY: &str = "seed";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let seed: [u8; 32] = runtime::get_seed();
+    let seed_uref = storage::new_uref(seed);
+    runtime::put_key(SEED_KEY, seed_uref.into());
+}
@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use contract::contract_api::{runtime, storage, system};
+
+const PURSE_A: &str = "purse:a";
+const PURSE_B: &str = "purse:b";
+const NOT_A_PURSE: &str = "not_a_purse";
+const PURSE_NAMES: &str = "purse_names";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    runtime::put_key(PURSE_A, system::create_purse().into());
+    runtime::put_key(PURSE_B, system::create_purse().into());
+    runtime::put_key(NOT_A_PURSE, storage::new_uref(42u64).into());
+
+    let purse_names: Vec<String> = system::list_purses()
+        .into_iter()
+        .map(|(name, _purse)| name)
+        .collect();
+    runtime::put_key(PURSE_NAMES, storage::new_uref(purse_names).into());
+}
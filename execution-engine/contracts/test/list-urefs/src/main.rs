@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{account, runtime, storage};
+use types::ApiError;
+
+const CREATED_UREF_KEY: &str = "created_uref";
+const ACCESSIBLE_UREFS_KEY: &str = "accessible_urefs";
+
+#[repr(u16)]
+enum Error {
+    CreatedURefNotListed = 0,
+    MainPurseNotListed = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let created_uref = storage::new_uref(());
+    runtime::put_key(CREATED_UREF_KEY, created_uref.into());
+
+    let urefs = runtime::list_urefs();
+    if !urefs.contains(&created_uref) {
+        runtime::revert(ApiError::User(Error::CreatedURefNotListed as u16));
+    }
+    if !urefs.contains(&account::get_main_purse()) {
+        runtime::revert(ApiError::User(Error::MainPurseNotListed as u16));
+    }
+
+    let accessible_urefs_uref = storage::new_uref(urefs);
+    runtime::put_key(ACCESSIBLE_UREFS_KEY, accessible_urefs_uref.into());
+}
@@ -39,6 +39,10 @@ impl RuntimeProvider for MintContract {
     fn put_key(&mut self, name: &str, key: Key) {
         runtime::put_key(name, key)
     }
+
+    fn has_key(&self, name: &str) -> bool {
+        runtime::has_key(name)
+    }
 }
 
 impl StorageProvider for MintContract {
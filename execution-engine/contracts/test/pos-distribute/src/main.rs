@@ -0,0 +1,22 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::{runtime, system};
+use types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const DISTRIBUTE: &str = "distribute";
+const ARG_AMOUNTS: &str = "amounts";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amounts: Vec<(AccountHash, U512)> = runtime::get_named_arg(ARG_AMOUNTS);
+    runtime::call_contract::<()>(
+        system::get_proof_of_stake(),
+        DISTRIBUTE,
+        runtime_args! { ARG_AMOUNTS => amounts },
+    );
+}
@@ -11,7 +11,6 @@ use types::{ApiError, RuntimeArgs, URef, U512};
 enum Error {
     TransferFromSourceToPayment = 0,
     TransferFromPaymentToSource,
-    GetBalance,
     CheckBalance,
 }
 
@@ -36,12 +35,12 @@ pub extern "C" fn call() {
     system::transfer_from_purse_to_purse(source_purse, payment_purse, payment_amount)
         .unwrap_or_revert_with(ApiError::User(Error::TransferFromSourceToPayment as u16));
 
-    let payment_balance = system::get_balance(payment_purse)
-        .unwrap_or_revert_with(ApiError::User(Error::GetBalance as u16));
-
-    if payment_balance.saturating_sub(payment_fund) != payment_amount {
-        runtime::revert(ApiError::User(Error::CheckBalance as u16))
-    }
+    system::assert_balance_delta(
+        payment_purse,
+        payment_fund,
+        payment_amount,
+        ApiError::User(Error::CheckBalance as u16).into(),
+    );
 
     // cannot withdraw
     if system::transfer_from_purse_to_purse(payment_purse, source_purse, payment_amount).is_ok() {
@@ -0,0 +1,22 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::{runtime, storage, system};
+use types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const GET_VALIDATOR_SET: &str = "get_validator_set";
+const VALIDATOR_SET: &str = "validator_set";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let validator_set: Vec<(AccountHash, U512)> = runtime::call_contract(
+        system::get_proof_of_stake(),
+        GET_VALIDATOR_SET,
+        runtime_args! {},
+    );
+    runtime::put_key(VALIDATOR_SET, storage::new_uref(validator_set).into());
+}
@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use contract::contract_api::{runtime, storage};
+use types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    CLType, EntryPointAccess, EntryPointType,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const HASH_KEY_NAME: &str = "contract_hash";
+const VALUE_NAME: &str = "value";
+const VALUE: u64 = 42;
+
+#[no_mangle]
+pub extern "C" fn delegate() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let value_key = storage::new_uref(VALUE).into();
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(VALUE_NAME.to_string(), value_key);
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+    // Keep the value's URef so that this account can read the value through the contract.
+    runtime::put_key(VALUE_NAME, value_key);
+}
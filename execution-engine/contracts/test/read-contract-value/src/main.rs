@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::contract_api::{runtime, storage};
+use types::ContractHash;
+
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_NAME: &str = "name";
+const RESULT_KEY: &str = "result";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let name: String = runtime::get_named_arg(ARG_NAME);
+
+    let result: Option<u64> = storage::read_contract_value(contract_hash, &name);
+
+    runtime::put_key(RESULT_KEY, storage::new_uref(result).into());
+}
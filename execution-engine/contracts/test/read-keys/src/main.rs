@@ -0,0 +1,25 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::{runtime, storage};
+use types::{Key, U512};
+
+const FIRST_VALUE: u64 = 10;
+const SECOND_VALUE: u64 = 20;
+const ABSENT_KEY: Key = Key::Hash([255; 32]);
+const READ_VALUES: &str = "read_values";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let first: Key = storage::new_uref(U512::from(FIRST_VALUE)).into();
+    let second: Key = storage::new_uref(U512::from(SECOND_VALUE)).into();
+
+    let values: Vec<Option<U512>> = storage::read_keys(&[first, ABSENT_KEY, second]);
+
+    let values_uref = storage::new_uref(values);
+    runtime::put_key(READ_VALUES, values_uref.into());
+}
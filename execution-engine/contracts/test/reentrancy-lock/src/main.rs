@@ -0,0 +1,78 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec};
+
+use contract::contract_api::{runtime, storage};
+use types::{
+    runtime_args, ApiError, CLType, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Parameter, RuntimeArgs,
+};
+
+const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+const WITHDRAW_LOCK_NAME: &str = "withdraw_lock";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_REENTER: &str = "reenter";
+
+#[repr(u16)]
+enum Error {
+    ReentrantCall = 0,
+}
+
+impl Into<ApiError> for Error {
+    fn into(self) -> ApiError {
+        ApiError::User(self as u16)
+    }
+}
+
+fn call_withdraw(contract_hash: ContractHash, reenter: bool) {
+    runtime::call_contract::<()>(
+        contract_hash,
+        WITHDRAW_ENTRY_POINT_NAME,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_REENTER => reenter,
+        },
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let reenter: bool = runtime::get_named_arg(ARG_REENTER);
+
+    if !runtime::acquire_lock(WITHDRAW_LOCK_NAME) {
+        runtime::revert(Error::ReentrantCall);
+    }
+
+    if reenter {
+        // Simulates a callee calling back into this contract before the withdrawal completes.
+        call_withdraw(contract_hash, false);
+    }
+
+    runtime::release_lock(WITHDRAW_LOCK_NAME);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let reenter: bool = runtime::get_named_arg(ARG_REENTER);
+
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        WITHDRAW_ENTRY_POINT_NAME.to_string(),
+        vec![
+            Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type()),
+            Parameter::new(ARG_REENTER, CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+
+    // The lock is released at the end of each call, so consecutive calls both succeed.
+    call_withdraw(contract_hash, reenter);
+    call_withdraw(contract_hash, reenter);
+}
@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+use types::ApiError;
+
+const ARG_ITERATIONS: &str = "iterations";
+const REMAINING_TIME_NOT_DECREASING: u16 = 0;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let iterations: u32 = runtime::get_named_arg(ARG_ITERATIONS);
+
+    let mut remaining_time = runtime::remaining_time();
+    for iteration in 0..iterations {
+        let _ = storage::new_uref(iteration);
+        let next_remaining_time = runtime::remaining_time();
+        if next_remaining_time >= remaining_time {
+            runtime::revert(ApiError::User(REMAINING_TIME_NOT_DECREASING));
+        }
+        remaining_time = next_remaining_time;
+    }
+}
@@ -0,0 +1,14 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::runtime;
+use types::ApiError;
+
+const ARG_MIN: &str = "min";
+const INSUFFICIENT_GAS_RESERVE: u16 = 100;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let min: u64 = runtime::get_named_arg(ARG_MIN);
+    runtime::require_gas_reserve(min, ApiError::User(INSUFFICIENT_GAS_RESERVE).into());
+}
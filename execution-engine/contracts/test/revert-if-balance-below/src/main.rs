@@ -0,0 +1,18 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{account, runtime, system};
+use types::{ApiError, U512};
+
+const ARG_MIN: &str = "min";
+const INSUFFICIENT_BALANCE: u16 = 100;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let min: U512 = runtime::get_named_arg(ARG_MIN);
+    system::revert_if_balance_below(
+        account::get_main_purse(),
+        min,
+        ApiError::User(INSUFFICIENT_BALANCE).into(),
+    );
+}
@@ -0,0 +1,37 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+use types::{ApiError, U512};
+
+const ARG_FAIL: &str = "fail";
+const RESULT_KEY: &str = "result";
+const OK_VALUE: u64 = 42;
+const FAILED: u16 = 100;
+
+enum Error {
+    Failed,
+}
+
+impl From<Error> for u32 {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Failed => ApiError::User(FAILED).into(),
+        }
+    }
+}
+
+fn check(fail: bool) -> Result<U512, Error> {
+    if fail {
+        Err(Error::Failed)
+    } else {
+        Ok(U512::from(OK_VALUE))
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let fail: bool = runtime::get_named_arg(ARG_FAIL);
+    let value = runtime::revert_on_err(check(fail));
+    runtime::put_key(RESULT_KEY, storage::new_uref(value).into());
+}
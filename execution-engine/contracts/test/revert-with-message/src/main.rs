@@ -0,0 +1,13 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::runtime;
+use types::ApiError;
+
+const REVERT_CODE: u16 = 100;
+const REVERT_MESSAGE: &str = "payment purse balance mismatch";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    runtime::revert_msg(ApiError::User(REVERT_CODE).into(), REVERT_MESSAGE);
+}
@@ -0,0 +1,35 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::format;
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{URef, U512};
+
+const CAPPED_PURSE: &str = "purse:capped";
+const PURSE_TRANSFER_RESULT: &str = "purse_transfer_result";
+
+const ARG_CAP: &str = "cap";
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let cap: U512 = runtime::get_named_arg(ARG_CAP);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let source: URef = account::get_main_purse();
+    let target: URef = system::create_purse();
+    runtime::put_key(CAPPED_PURSE, target.into());
+
+    system::set_balance_cap(target, cap).unwrap_or_revert();
+
+    let transfer_result = system::transfer_from_purse_to_purse(source, target, amount);
+
+    let result = format!("{:?}", transfer_result);
+    runtime::put_key(PURSE_TRANSFER_RESULT, storage::new_uref(result).into());
+}
@@ -0,0 +1,18 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use contract::contract_api::{runtime, storage};
+
+const ARG_NAME: &str = "name";
+const ARG_ARGS: &str = "args";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let name: String = runtime::get_named_arg(ARG_NAME);
+    let args: Vec<u8> = runtime::get_named_arg(ARG_ARGS);
+    runtime::put_key(&name, storage::new_uref(args).into());
+}
@@ -0,0 +1,42 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use contract::contract_api::{runtime, system};
+use types::{account::AccountHash, ApiError};
+
+const ARG_PREFIX: &str = "prefix";
+const ARG_ACCOUNTS: &str = "accounts";
+
+#[repr(u16)]
+enum Error {
+    UnstableName = 100,
+    DuplicateName = 101,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let prefix: String = runtime::get_named_arg(ARG_PREFIX);
+    let accounts: Vec<AccountHash> = runtime::get_named_arg(ARG_ACCOUNTS);
+
+    for account in accounts {
+        let name = system::sub_purse_name(&prefix, &account);
+        if name != system::sub_purse_name(&prefix, &account) {
+            runtime::revert(Error::UnstableName);
+        }
+        if runtime::has_key(&name) {
+            runtime::revert(Error::DuplicateName);
+        }
+        let purse = system::create_purse();
+        runtime::put_key(&name, purse.into());
+    }
+}
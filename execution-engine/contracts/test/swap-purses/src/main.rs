@@ -0,0 +1,42 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::format;
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::U512;
+
+const PURSE_A: &str = "purse:a";
+const PURSE_B: &str = "purse:b";
+const SWAP_RESULT: &str = "swap_result";
+
+const ARG_FUNDS_A: &str = "funds_a";
+const ARG_FUNDS_B: &str = "funds_b";
+const ARG_AMOUNT_A: &str = "amount_a";
+const ARG_AMOUNT_B: &str = "amount_b";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let funds_a: U512 = runtime::get_named_arg(ARG_FUNDS_A);
+    let funds_b: U512 = runtime::get_named_arg(ARG_FUNDS_B);
+    let amount_a: U512 = runtime::get_named_arg(ARG_AMOUNT_A);
+    let amount_b: U512 = runtime::get_named_arg(ARG_AMOUNT_B);
+
+    let main_purse = account::get_main_purse();
+    let purse_a = system::create_purse();
+    let purse_b = system::create_purse();
+    system::transfer_from_purse_to_purse(main_purse, purse_a, funds_a).unwrap_or_revert();
+    system::transfer_from_purse_to_purse(main_purse, purse_b, funds_b).unwrap_or_revert();
+    runtime::put_key(PURSE_A, purse_a.into());
+    runtime::put_key(PURSE_B, purse_b.into());
+
+    let swap_result = system::swap_purses(purse_a, purse_b, amount_a, amount_b);
+
+    let result = format!("{:?}", swap_result);
+    runtime::put_key(SWAP_RESULT, storage::new_uref(result).into());
+}
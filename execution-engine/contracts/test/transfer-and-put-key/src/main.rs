@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use contract::contract_api::{account, runtime, storage, system};
+use types::{URef, U512};
+
+const TARGET_PURSE: &str = "purse:target";
+const ESCROW_RECEIPT: &str = "escrow_receipt";
+const TRANSFER_RESULT: &str = "transfer_result";
+
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let source: URef = account::get_main_purse();
+    let target: URef = system::create_purse();
+    runtime::put_key(TARGET_PURSE, target.into());
+
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let receipt: URef = storage::new_uref(amount);
+
+    let transfer_result =
+        system::transfer_and_put_key(source, target, amount, ESCROW_RECEIPT, receipt.into());
+
+    let result: String = format!("{:?}", transfer_result);
+    runtime::put_key(TRANSFER_RESULT, storage::new_uref(result).into());
+}
@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::AccountHash, U512};
+
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_CREATE_IF_MISSING: &str = "create_if_missing";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let source = account::get_main_purse();
+    let target: AccountHash = runtime::get_named_arg(ARG_TARGET);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let create_if_missing: bool = runtime::get_named_arg(ARG_CREATE_IF_MISSING);
+
+    system::transfer_from_purse_to_account_with_policy(source, target, amount, create_if_missing)
+        .unwrap_or_revert();
+}
@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, URef, U512};
+
+const TRANSFER_RESULT: &str = "transfer_result";
+
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_TRANSFER_KEY: &str = "transfer_key";
+
+#[repr(u16)]
+enum CustomError {
+    UnexpectedTargetPurseKeyVariant = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let source: URef = account::get_main_purse();
+
+    let target_name: String = runtime::get_named_arg(ARG_TARGET);
+    let target: URef = match runtime::get_key(&target_name) {
+        Some(target_key) => *target_key.as_uref().unwrap_or_revert_with(ApiError::User(
+            CustomError::UnexpectedTargetPurseKeyVariant as u16,
+        )),
+        None => {
+            let purse = system::create_purse();
+            runtime::put_key(&target_name, purse.into());
+            purse
+        }
+    };
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let transfer_key: [u8; 32] = runtime::get_named_arg(ARG_TRANSFER_KEY);
+
+    let transfer_result = system::transfer_once(source, target, amount, transfer_key);
+
+    let result = format!("{:?}", transfer_result);
+    runtime::put_key(TRANSFER_RESULT, storage::new_uref(result).into());
+}
@@ -0,0 +1,28 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::format;
+
+use contract::contract_api::{account, runtime, storage, system};
+use types::{URef, U512};
+
+const TARGET_PURSE: &str = "purse:target";
+const PURSE_TRANSFER_RESULT: &str = "purse_transfer_result";
+
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let source: URef = account::get_main_purse();
+    let target: URef = system::create_purse();
+    runtime::put_key(TARGET_PURSE, target.into());
+
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let transfer_result = system::transfer_from_purse_to_purse(source, target.into_read(), amount);
+
+    let result = format!("{:?}", transfer_result);
+    runtime::put_key(PURSE_TRANSFER_RESULT, storage::new_uref(result).into());
+}
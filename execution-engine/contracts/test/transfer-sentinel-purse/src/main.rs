@@ -0,0 +1,26 @@
+#![no_std]
+#![no_main]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{URef, U512};
+
+const ARG_SENTINEL_AS_SOURCE: &str = "sentinel_as_source";
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let sentinel_as_source: bool = runtime::get_named_arg(ARG_SENTINEL_AS_SOURCE);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let main_purse = account::get_main_purse();
+    let (source, target) = if sentinel_as_source {
+        (URef::SENTINEL, main_purse)
+    } else {
+        (main_purse, URef::SENTINEL)
+    };
+
+    system::transfer_from_purse_to_purse(source, target, amount).unwrap_or_revert();
+}
@@ -0,0 +1,22 @@
+#![no_std]
+#![no_main]
+
+use contract::contract_api::{runtime, storage};
+use types::{ContractHash, RuntimeArgs, URef, U512};
+
+const ENTRY_FUNCTION_NAME: &str = "balance_and_purse";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const RETURNED_BALANCE_KEY: &str = "returned_balance";
+const RETURNED_PURSE_KEY: &str = "returned_purse";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+
+    let (balance, purse): (U512, URef) =
+        runtime::call_contract(contract_hash, ENTRY_FUNCTION_NAME, RuntimeArgs::default());
+
+    runtime::put_key(RETURNED_BALANCE_KEY, storage::new_uref(balance).into());
+    // Storing the purse requires the caller to have been granted access to it by the return.
+    runtime::put_key(RETURNED_PURSE_KEY, purse.into());
+}
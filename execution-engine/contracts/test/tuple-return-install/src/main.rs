@@ -0,0 +1,60 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    ApiError, CLType, CLValue, EntryPointAccess, EntryPointType, URef, U512,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "balance_and_purse";
+const HASH_KEY_NAME: &str = "contract_hash";
+const PURSE_NAME: &str = "purse";
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn balance_and_purse() {
+    let purse: URef = runtime::get_key(PURSE_NAME)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert_with(ApiError::MissingKey);
+    let balance: U512 = system::get_balance(purse).unwrap_or_revert();
+    let result = CLValue::from_t((balance, purse)).unwrap_or_revert();
+    runtime::ret(result)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::Tuple2([Box::new(CLType::U512), Box::new(CLType::URef)]),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount)
+        .unwrap_or_revert();
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(PURSE_NAME.to_string(), purse.into());
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,45 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use contract::contract_api::{runtime, storage};
+use types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys},
+    CLType, ContractPackageHash, EntryPointAccess, EntryPointType,
+};
+
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const ARG_PACKAGE_HASH: &str = "package_hash";
+const UPGRADED_CONTRACT_VERSION: &str = "upgraded_contract_version";
+
+#[no_mangle]
+pub extern "C" fn delegate() {}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let package_hash: ContractPackageHash = runtime::get_named_arg(ARG_PACKAGE_HASH);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        let entry_point = EntryPoint::new(
+            ENTRY_FUNCTION_NAME.to_string(),
+            Vec::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        entry_points.add_entry_point(entry_point);
+        entry_points
+    };
+
+    let (_contract_hash, contract_version) =
+        storage::upgrade_contract(package_hash, entry_points, NamedKeys::new());
+
+    runtime::put_key(
+        UPGRADED_CONTRACT_VERSION,
+        storage::new_uref(contract_version).into(),
+    );
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeSet;
+
+use types::{RedactedRuntimeArgs, RuntimeArgs};
+
+/// Controls which deploy arg values appear in log output.
+///
+/// By default only the names and types of args are logged.  Values are only logged once enabled
+/// via [`ArgRedaction::with_log_values`], and even then not those of args with redacted names.
+#[derive(Clone, Default, Debug)]
+pub struct ArgRedaction {
+    log_values: bool,
+    redacted_names: BTreeSet<String>,
+}
+
+impl ArgRedaction {
+    /// Creates an `ArgRedaction` redacting the values of args with the given names, even if arg
+    /// values are logged.
+    pub fn new<I: IntoIterator<Item = String>>(redacted_names: I) -> Self {
+        ArgRedaction {
+            log_values: false,
+            redacted_names: redacted_names.into_iter().collect(),
+        }
+    }
+
+    /// Returns `self` logging the values of args whose names aren't redacted if `log_values` is
+    /// `true`.
+    pub fn with_log_values(mut self, log_values: bool) -> Self {
+        self.log_values = log_values;
+        self
+    }
+
+    /// Returns `true` if the values of args whose names aren't redacted are logged.
+    pub fn log_values(&self) -> bool {
+        self.log_values
+    }
+
+    /// Returns `true` if the value of the arg called `name` is redacted.
+    pub fn is_redacted(&self, name: &str) -> bool {
+        !self.log_values || self.redacted_names.contains(name)
+    }
+
+    /// Returns a view of `args` which can be logged with the redacted values hidden.
+    pub fn args<'a>(&'a self, args: &'a RuntimeArgs) -> RedactedRuntimeArgs<'a> {
+        if self.log_values {
+            args.redacted(&self.redacted_names)
+        } else {
+            args.without_values()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{runtime_args, RuntimeArgs, U512};
+
+    use super::ArgRedaction;
+
+    const SECRET_AMOUNT: u64 = 0xdead_beef;
+
+    fn args() -> RuntimeArgs {
+        runtime_args! {
+            "amount" => U512::from(SECRET_AMOUNT),
+            "target" => 7u8,
+        }
+    }
+
+    #[test]
+    fn should_not_log_values_by_default() {
+        let redaction = ArgRedaction::default();
+        let logged = format!("Payment args: {}", redaction.args(&args()));
+
+        assert!(logged.contains("amount: U512"), "{}", logged);
+        assert!(logged.contains("target: U8"), "{}", logged);
+        assert!(!logged.contains("efbeadde"), "{}", logged);
+        assert!(!logged.contains("0x07"), "{}", logged);
+        assert!(redaction.is_redacted("target"));
+    }
+
+    #[test]
+    fn should_not_log_redacted_values() {
+        let redaction = ArgRedaction::new(vec!["amount".to_string()]).with_log_values(true);
+        let logged = format!("Payment args: {}", redaction.args(&args()));
+
+        assert!(logged.contains("amount: U512"), "{}", logged);
+        assert!(!logged.contains("deadbeef"), "{}", logged);
+        assert!(!logged.contains("efbeadde"), "{}", logged);
+        assert!(logged.contains("target: U8 = 0x07"), "{}", logged);
+        assert!(redaction.is_redacted("amount"));
+        assert!(!redaction.is_redacted("target"));
+    }
+
+    #[test]
+    fn should_log_all_values_when_enabled_without_redaction() {
+        let redaction = ArgRedaction::default().with_log_values(true);
+        assert_eq!(redaction.args(&args()).to_string(), args().to_string());
+    }
+}
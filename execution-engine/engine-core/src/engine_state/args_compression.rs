@@ -0,0 +1,231 @@
+//! An optional compressed encoding for serialized deploy item args.
+//!
+//! Compressed args are prefixed with [`COMPRESSED_ARGS_TAG`], which can't begin a serialized
+//! `RuntimeArgs` in practice since it would declare `u32::max_value()` args.  The remainder is a
+//! stream of LZ77-style tokens, each either a run of literal bytes or a back-reference into the
+//! already decompressed output.
+
+use types::bytesrepr;
+
+/// Prefix marking args as compressed.
+pub const COMPRESSED_ARGS_TAG: [u8; 4] = [0xff; 4];
+
+/// The maximum size of decompressed args.  Back-references let a short input expand greatly, so
+/// decompression stops with `DeserializationLimitExceeded` once the output would exceed this.
+pub const MAX_DECOMPRESSED_ARGS_LENGTH: usize = 8 * 1024 * 1024;
+
+const LITERAL_TOKEN: u8 = 0;
+const MATCH_TOKEN: u8 = 1;
+
+const MIN_MATCH_LENGTH: usize = 4;
+const MAX_MATCH_LENGTH: usize = u8::max_value() as usize;
+const MAX_LITERAL_LENGTH: usize = u8::max_value() as usize;
+const WINDOW_SIZE: usize = 4096;
+
+/// Returns `true` if `args` use the compressed encoding.
+pub fn is_compressed(args: &[u8]) -> bool {
+    args.starts_with(&COMPRESSED_ARGS_TAG)
+}
+
+/// Compresses serialized args, producing bytes accepted by [`decompress_args`].
+pub fn compress_args(args: &[u8]) -> Vec<u8> {
+    let mut output = COMPRESSED_ARGS_TAG.to_vec();
+    let mut literals_start = 0;
+    let mut position = 0;
+
+    while position < args.len() {
+        let (offset, length) = longest_match(args, position);
+        if length < MIN_MATCH_LENGTH {
+            position += 1;
+            continue;
+        }
+        push_literals(&mut output, &args[literals_start..position]);
+        output.push(MATCH_TOKEN);
+        output.extend_from_slice(&(offset as u16).to_le_bytes());
+        output.push(length as u8);
+        position += length;
+        literals_start = position;
+    }
+    push_literals(&mut output, &args[literals_start..]);
+
+    output
+}
+
+/// Decompresses `args` if they use the compressed encoding, otherwise returns them unchanged.
+pub fn decompress_args(args: Vec<u8>) -> Result<Vec<u8>, bytesrepr::Error> {
+    if !is_compressed(&args) {
+        return Ok(args);
+    }
+
+    let mut input = &args[COMPRESSED_ARGS_TAG.len()..];
+    let mut output = Vec::new();
+    while let Some((&token, rem)) = input.split_first() {
+        input = match token {
+            LITERAL_TOKEN => {
+                let (&length, rem) = rem
+                    .split_first()
+                    .ok_or(bytesrepr::Error::EarlyEndOfStream)?;
+                let length = length as usize;
+                if rem.len() < length {
+                    return Err(bytesrepr::Error::EarlyEndOfStream);
+                }
+                check_output_length(&output, length)?;
+                output.extend_from_slice(&rem[..length]);
+                &rem[length..]
+            }
+            MATCH_TOKEN => {
+                if rem.len() < 3 {
+                    return Err(bytesrepr::Error::EarlyEndOfStream);
+                }
+                let offset = u16::from_le_bytes([rem[0], rem[1]]) as usize;
+                let length = rem[2] as usize;
+                if offset == 0 || offset > output.len() {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                check_output_length(&output, length)?;
+                // Copy byte by byte, as the match may overlap the bytes it produces.
+                for _ in 0..length {
+                    let byte = output[output.len() - offset];
+                    output.push(byte);
+                }
+                &rem[3..]
+            }
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+    }
+
+    Ok(output)
+}
+
+/// Fails if growing `output` by `additional` bytes would exceed the decompressed size limit.
+fn check_output_length(output: &[u8], additional: usize) -> Result<(), bytesrepr::Error> {
+    if output.len() + additional > MAX_DECOMPRESSED_ARGS_LENGTH {
+        return Err(bytesrepr::Error::DeserializationLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Finds the longest match for the bytes at `position` within the preceding window, returning its
+/// offset back from `position` and its length.
+fn longest_match(bytes: &[u8], position: usize) -> (usize, usize) {
+    let window_start = position.saturating_sub(WINDOW_SIZE);
+    let max_length = MAX_MATCH_LENGTH.min(bytes.len() - position);
+
+    let mut best = (0, 0);
+    for start in window_start..position {
+        let length = (0..max_length)
+            .take_while(|&index| bytes[start + index] == bytes[position + index])
+            .count();
+        if length > best.1 {
+            best = (position - start, length);
+        }
+    }
+    best
+}
+
+fn push_literals(output: &mut Vec<u8>, literals: &[u8]) {
+    for chunk in literals.chunks(MAX_LITERAL_LENGTH) {
+        output.push(LITERAL_TOKEN);
+        output.push(chunk.len() as u8);
+        output.extend_from_slice(chunk);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{bytesrepr::ToBytes, runtime_args, RuntimeArgs, U512};
+
+    use super::*;
+
+    fn serialized_args() -> Vec<u8> {
+        let args = runtime_args! {
+            "amount" => U512::from(42),
+            "repetitive" => vec![7u8; 1000],
+            "text" => "abcabcabcabcabcabcabcabc",
+        };
+        args.to_bytes().expect("should serialize")
+    }
+
+    #[test]
+    fn should_round_trip_compressed_args() {
+        let args = serialized_args();
+        let compressed = compress_args(&args);
+        assert!(is_compressed(&compressed));
+        assert!(compressed.len() < args.len());
+        assert_eq!(
+            decompress_args(compressed).expect("should decompress"),
+            args
+        );
+    }
+
+    #[test]
+    fn should_pass_through_uncompressed_args() {
+        let args = serialized_args();
+        assert!(!is_compressed(&args));
+        assert_eq!(
+            decompress_args(args.clone()).expect("should pass through"),
+            args
+        );
+    }
+
+    #[test]
+    fn should_round_trip_incompressible_and_empty_args() {
+        let args: Vec<u8> = (0..=255).collect();
+        assert_eq!(
+            decompress_args(compress_args(&args)).expect("should decompress"),
+            args
+        );
+        assert_eq!(
+            decompress_args(compress_args(&[])).expect("should decompress"),
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_compressed_args() {
+        let with_tag = |bytes: &[u8]| {
+            let mut args = COMPRESSED_ARGS_TAG.to_vec();
+            args.extend_from_slice(bytes);
+            args
+        };
+
+        // Back-reference before any output.
+        assert!(decompress_args(with_tag(&[MATCH_TOKEN, 1, 0, 4])).is_err());
+        // Truncated literal run.
+        assert!(decompress_args(with_tag(&[LITERAL_TOKEN, 5, 1, 2])).is_err());
+        // Unknown token.
+        assert!(decompress_args(with_tag(&[2])).is_err());
+    }
+
+    #[test]
+    fn should_reject_args_decompressing_beyond_limit() {
+        // A single literal byte repeated by back-references expands by up to 255 bytes for every
+        // four bytes of input.
+        let mut args = COMPRESSED_ARGS_TAG.to_vec();
+        args.extend_from_slice(&[LITERAL_TOKEN, 1, 0]);
+        let match_count = MAX_DECOMPRESSED_ARGS_LENGTH / MAX_MATCH_LENGTH + 1;
+        for _ in 0..match_count {
+            args.extend_from_slice(&[MATCH_TOKEN, 1, 0, MAX_MATCH_LENGTH as u8]);
+        }
+        assert_eq!(
+            decompress_args(args),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_accept_args_decompressing_to_limit() {
+        let mut args = COMPRESSED_ARGS_TAG.to_vec();
+        args.extend_from_slice(&[LITERAL_TOKEN, 1, 7]);
+        let mut remaining = MAX_DECOMPRESSED_ARGS_LENGTH - 1;
+        while remaining > 0 {
+            let length = remaining.min(MAX_MATCH_LENGTH);
+            args.extend_from_slice(&[MATCH_TOKEN, 1, 0, length as u8]);
+            remaining -= length;
+        }
+        assert_eq!(
+            decompress_args(args).expect("should decompress"),
+            vec![7u8; MAX_DECOMPRESSED_ARGS_LENGTH]
+        );
+    }
+}
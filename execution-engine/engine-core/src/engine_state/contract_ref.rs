@@ -0,0 +1,89 @@
+//! A reference to a stored contract, either by its hash or by a name in an account's named keys.
+
+use engine_shared::account::Account;
+use types::ContractHash;
+
+use super::error;
+use crate::execution;
+
+/// A reference to a stored contract, as given by a deploy item or by a contract calling another.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ContractRef {
+    /// The hash under which the contract is stored.
+    Hash(ContractHash),
+    /// A name under which the contract's hash is stored in an account's named keys.
+    Name(String),
+}
+
+impl ContractRef {
+    /// Resolves `self` to a contract hash, looking names up in the named keys of `account`.
+    pub fn resolve(self, account: &Account) -> Result<ContractHash, error::Error> {
+        match self {
+            ContractRef::Hash(hash) => Ok(hash),
+            ContractRef::Name(name) => {
+                let key =
+                    account.named_keys().get(&name).cloned().ok_or_else(|| {
+                        error::Error::Exec(execution::Error::NamedKeyNotFound(name))
+                    })?;
+                key.into_hash()
+                    .ok_or_else(|| error::Error::InvalidKeyVariant(format!("{:?}", key)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use engine_shared::account::Account;
+    use types::{account::AccountHash, contracts::NamedKeys, AccessRights, Key, URef};
+
+    use super::ContractRef;
+    use crate::{engine_state::Error, execution};
+
+    const CONTRACT_NAME: &str = "contract";
+    const UREF_NAME: &str = "uref";
+    const CONTRACT_HASH: [u8; 32] = [3; 32];
+
+    fn account() -> Account {
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(CONTRACT_NAME.to_string(), Key::Hash(CONTRACT_HASH));
+        named_keys.insert(
+            UREF_NAME.to_string(),
+            Key::URef(URef::new([4; 32], AccessRights::READ)),
+        );
+        let main_purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        Account::create(AccountHash::new([1; 32]), named_keys, main_purse)
+    }
+
+    #[test]
+    fn should_resolve_hash_without_lookup() {
+        let contract_ref = ContractRef::Hash([5; 32]);
+        assert_eq!(contract_ref.resolve(&account()).unwrap(), [5; 32]);
+    }
+
+    #[test]
+    fn should_resolve_name_in_named_keys() {
+        let contract_ref = ContractRef::Name(CONTRACT_NAME.to_string());
+        assert_eq!(contract_ref.resolve(&account()).unwrap(), CONTRACT_HASH);
+    }
+
+    #[test]
+    fn should_fail_to_resolve_missing_name() {
+        let contract_ref = ContractRef::Name("missing".to_string());
+        assert_matches!(
+            contract_ref.resolve(&account()),
+            Err(Error::Exec(execution::Error::NamedKeyNotFound(name))) if name == "missing"
+        );
+    }
+
+    #[test]
+    fn should_fail_to_resolve_name_of_non_hash_key() {
+        let contract_ref = ContractRef::Name(UREF_NAME.to_string());
+        assert_matches!(
+            contract_ref.resolve(&account()),
+            Err(Error::InvalidKeyVariant(_))
+        );
+    }
+}
@@ -4,6 +4,10 @@ pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
     enable_bonding: bool,
+    max_effect_entries: Option<usize>,
+    enable_host_call_tracing: bool,
+    enable_replay_protection: bool,
+    enable_execution_result_cache: bool,
 }
 
 impl EngineConfig {
@@ -29,4 +33,49 @@ impl EngineConfig {
         self.enable_bonding = enable_bonding;
         self
     }
+
+    /// The maximum number of effect entries a single execution may produce, if limited.
+    pub fn max_effect_entries(self) -> Option<usize> {
+        self.max_effect_entries
+    }
+
+    pub fn with_max_effect_entries(mut self, max_effect_entries: Option<usize>) -> EngineConfig {
+        self.max_effect_entries = max_effect_entries;
+        self
+    }
+
+    /// Whether each host call made during execution is recorded in the execution effect.
+    pub fn enable_host_call_tracing(self) -> bool {
+        self.enable_host_call_tracing
+    }
+
+    pub fn with_enable_host_call_tracing(mut self, enable_host_call_tracing: bool) -> EngineConfig {
+        self.enable_host_call_tracing = enable_host_call_tracing;
+        self
+    }
+
+    /// Whether deploys which have already been executed are rejected rather than run again.
+    pub fn enable_replay_protection(self) -> bool {
+        self.enable_replay_protection
+    }
+
+    pub fn with_enable_replay_protection(mut self, enable_replay_protection: bool) -> EngineConfig {
+        self.enable_replay_protection = enable_replay_protection;
+        self
+    }
+
+    /// Whether successful execution results of dry-run requests are cached, so that dry-running the
+    /// same deploy against the same pre-state again returns the cached result instead of running
+    /// it.
+    pub fn enable_execution_result_cache(self) -> bool {
+        self.enable_execution_result_cache
+    }
+
+    pub fn with_enable_execution_result_cache(
+        mut self,
+        enable_execution_result_cache: bool,
+    ) -> EngineConfig {
+        self.enable_execution_result_cache = enable_execution_result_cache;
+        self
+    }
 }
@@ -1,7 +1,7 @@
 use failure::Fail;
 
 use engine_shared::newtypes::Blake2bHash;
-use types::{bytesrepr, system_contract_errors::mint};
+use types::{bytesrepr, system_contract_errors::mint, ContractHash, ContractPackageHash};
 
 use crate::execution;
 use types::ProtocolVersion;
@@ -47,6 +47,20 @@ pub enum Error {
     InvalidUpgradeResult,
     #[fail(display = "Unsupported deploy item variant: {}", _0)]
     InvalidDeployItemVariant(String),
+    #[fail(display = "Invalid module bytes chunks: {}", _0)]
+    InvalidModuleBytesChunks(String),
+    #[fail(display = "Inconsistent deploy item: {}", _0)]
+    InconsistentDeployItem(String),
+    #[fail(display = "No such contract: {:?}", _0)]
+    NoSuchContract(ContractHash),
+    #[fail(display = "No such contract package: {:?}", _0)]
+    NoSuchContractPackage(ContractPackageHash),
+    #[fail(display = "Deploy already executed: {}", _0)]
+    AlreadyExecuted(Blake2bHash),
+    #[fail(display = "Invalid transfer target: {}", _0)]
+    InvalidTransferTarget(String),
+    #[fail(display = "Invalid stored args: {}", _0)]
+    InvalidStoredArgs(String),
 }
 
 impl From<engine_wasm_prep::PreprocessingError> for Error {
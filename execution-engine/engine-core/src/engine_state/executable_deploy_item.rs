@@ -1,12 +1,44 @@
-use super::error;
+use std::collections::BTreeMap;
+
+use failure::Fail;
+use serde::Serialize;
+
+use super::{args_compression, contract_ref::ContractRef, error};
 use crate::execution;
 use engine_shared::account::Account;
 use types::{
-    bytesrepr,
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::{ContractVersion, DEFAULT_ENTRY_POINT_NAME},
-    ContractHash, ContractPackageHash, Key, RuntimeArgs,
+    CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, RuntimeArgs, URef,
 };
 
+/// Name of the arg holding the recipient of a `Transfer` item.
+const TRANSFER_ARG_TARGET: &str = "target";
+/// Maximum number of args an item's serialized args may declare.
+const MAX_RUNTIME_ARGS_COUNT: u32 = 1024;
+/// Maximum length in bytes of the name of an arg.
+const MAX_ARG_NAME_LENGTH: u32 = 1024;
+
+#[repr(u8)]
+enum Tag {
+    ModuleBytes = 0,
+    StoredContractByHash = 1,
+    StoredContractByName = 2,
+    StoredVersionedContractByName = 3,
+    StoredVersionedContractByHash = 4,
+    Transfer = 5,
+}
+
+/// Error returned when parsing an [`ExecutableDeployItem`] from a hex string fails.
+#[derive(Fail, Debug)]
+pub enum FromHexError {
+    #[fail(display = "Invalid hex string: {}", _0)]
+    Hex(base16::DecodeError),
+    #[fail(display = "Invalid deploy item bytes: {}", _0)]
+    BytesRepr(bytesrepr::Error),
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum ExecutableDeployItem {
     ModuleBytes {
@@ -41,7 +73,125 @@ pub enum ExecutableDeployItem {
     },
 }
 
+/// A single piece of a Wasm module which was split for transport.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ModuleBytesChunk {
+    /// Zero-based position of this chunk within the module.
+    pub index: u32,
+    /// Total number of chunks the module was split into.
+    pub total: u32,
+    /// The bytes of this chunk.
+    pub bytes: Vec<u8>,
+}
+
 impl ExecutableDeployItem {
+    /// Reassembles a `ModuleBytes` item from `chunks`.
+    ///
+    /// The chunks must be given in order, must all agree on the total number of chunks, and the
+    /// reassembled module must not exceed `max_module_size` bytes.
+    pub fn from_module_bytes_chunks(
+        chunks: Vec<ModuleBytesChunk>,
+        args: Vec<u8>,
+        max_module_size: usize,
+    ) -> Result<Self, error::Error> {
+        let invalid = |msg: String| Err(error::Error::InvalidModuleBytesChunks(msg));
+
+        if chunks.is_empty() {
+            return invalid("no chunks provided".to_string());
+        }
+
+        let mut total_size = 0usize;
+        for (expected_index, chunk) in chunks.iter().enumerate() {
+            if chunk.index as usize != expected_index {
+                return invalid(format!(
+                    "expected chunk {}, got chunk {}",
+                    expected_index, chunk.index
+                ));
+            }
+            if chunk.total as usize != chunks.len() {
+                return invalid(format!(
+                    "chunk {} expects {} chunks, got {}",
+                    chunk.index,
+                    chunk.total,
+                    chunks.len()
+                ));
+            }
+            total_size = total_size.saturating_add(chunk.bytes.len());
+            if total_size > max_module_size {
+                return invalid(format!(
+                    "module exceeds maximum size of {} bytes",
+                    max_module_size
+                ));
+            }
+        }
+
+        let mut module_bytes = Vec::with_capacity(total_size);
+        for chunk in chunks {
+            module_bytes.extend(chunk.bytes);
+        }
+        Ok(ExecutableDeployItem::ModuleBytes { module_bytes, args })
+    }
+
+    /// Checks that the entry point implied by this item is consistent with its variant.
+    ///
+    /// `ModuleBytes` and `Transfer` items always use the default entry point, while items
+    /// targeting stored contracts must name the entry point to be called.  The `target` arg of a
+    /// `Transfer` item, if given, must also be a well-formed account hash, account key or purse.
+    ///
+    /// The checks need no global state and are run before payment, so an item failing them is
+    /// rejected as an uncharged precondition failure, in the same way as e.g. session code naming
+    /// a nonexistent contract.  Without this check, a stored contract item with an empty entry
+    /// point would also be rejected uncharged, with `NoSuchMethod` or `NoSuchEntryPoint`, when its
+    /// module is looked up.
+    pub fn validate(&self) -> Result<(), error::Error> {
+        match self {
+            ExecutableDeployItem::ModuleBytes { .. } => Ok(()),
+            ExecutableDeployItem::Transfer { .. } => self.validate_transfer_target(),
+            ExecutableDeployItem::StoredContractByHash { entry_point, .. }
+            | ExecutableDeployItem::StoredContractByName { entry_point, .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { entry_point, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { entry_point, .. }
+                if entry_point.is_empty() =>
+            {
+                Err(error::Error::InconsistentDeployItem(format!(
+                    "{} requires an entry point",
+                    self.summary().variant
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_transfer_target(&self) -> Result<(), error::Error> {
+        // Args which can't be deserialized at all are reported when the item is executed.
+        let runtime_args = match self.clone().into_runtime_args() {
+            Ok(runtime_args) => runtime_args,
+            Err(_) => return Ok(()),
+        };
+        let target = match runtime_args.get(TRANSFER_ARG_TARGET) {
+            Some(target) => target.clone(),
+            None => return Ok(()),
+        };
+        let cl_type = target.cl_type().clone();
+        let is_valid = match cl_type {
+            CLType::URef => target.into_t::<URef>().is_ok(),
+            CLType::Key => match target.into_t::<Key>() {
+                Ok(Key::Account(_)) => true,
+                _ => false,
+            },
+            _ if cl_type == AccountHash::cl_type() => target.into_t::<AccountHash>().is_ok(),
+            _ => false,
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err(error::Error::InvalidTransferTarget(format!(
+                "malformed target of type {:?}",
+                cl_type
+            )))
+        }
+    }
+
     pub(crate) fn to_contract_hash_key(
         &self,
         account: &Account,
@@ -64,6 +214,125 @@ impl ExecutableDeployItem {
         }
     }
 
+    /// Returns a reference to the stored contract invoked by this item, or `None` for
+    /// `ModuleBytes` and `Transfer` items.  Versioned items refer to their contract package.
+    pub fn contract_ref(&self) -> Option<ContractRef> {
+        match self {
+            ExecutableDeployItem::StoredContractByHash { hash, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { hash, .. } => {
+                Some(ContractRef::Hash(*hash))
+            }
+            ExecutableDeployItem::StoredContractByName { name, .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { name, .. } => {
+                Some(ContractRef::Name(name.clone()))
+            }
+            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+                None
+            }
+        }
+    }
+
+    /// Converts name-based items to their hash-based equivalents by resolving the name in the
+    /// named keys of `account`.  Other items are returned unchanged.
+    pub fn normalize(self, account: &Account) -> Result<Self, error::Error> {
+        match self {
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => Ok(ExecutableDeployItem::StoredContractByHash {
+                hash: ContractRef::Name(name).resolve(account)?,
+                entry_point,
+                args,
+            }),
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => Ok(ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: ContractRef::Name(name).resolve(account)?,
+                version,
+                entry_point,
+                args,
+            }),
+            item => Ok(item),
+        }
+    }
+
+    /// Returns this item with its entry point replaced by `entry_point`, leaving its target and
+    /// args unchanged.  `ModuleBytes` and `Transfer` items have no entry point, so are returned
+    /// unchanged.
+    pub fn replace_entry_point(mut self, entry_point: String) -> Self {
+        match &mut self {
+            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {}
+            ExecutableDeployItem::StoredContractByHash {
+                entry_point: current,
+                ..
+            }
+            | ExecutableDeployItem::StoredContractByName {
+                entry_point: current,
+                ..
+            }
+            | ExecutableDeployItem::StoredVersionedContractByHash {
+                entry_point: current,
+                ..
+            }
+            | ExecutableDeployItem::StoredVersionedContractByName {
+                entry_point: current,
+                ..
+            } => *current = entry_point,
+        }
+        self
+    }
+
+    /// Returns `true` if the contract invoked by this item is allowed.
+    ///
+    /// Unversioned items are allowed if their contract hash is in `allowlist`.  Versioned items
+    /// only identify a contract package, so they are allowed if their contract package hash is in
+    /// `package_allowlist`.  Name-based items are resolved in the named keys of `account`, and are
+    /// disallowed if the name doesn't resolve to a hash.  `ModuleBytes` and `Transfer` items don't
+    /// invoke a stored contract, so are always allowed.
+    pub fn target_allowed(
+        &self,
+        account: &Account,
+        allowlist: &[ContractHash],
+        package_allowlist: &[ContractPackageHash],
+    ) -> bool {
+        let allowlist = match self {
+            ExecutableDeployItem::StoredVersionedContractByHash { .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { .. } => package_allowlist,
+            _ => allowlist,
+        };
+        match self.contract_ref() {
+            Some(contract_ref) => contract_ref
+                .resolve(account)
+                .map_or(false, |hash| allowlist.contains(&hash)),
+            None => true,
+        }
+    }
+
+    /// Returns the lower-case hex encoding of the serialized item.
+    pub fn to_hex(&self) -> Result<String, bytesrepr::Error> {
+        let bytes = self.to_bytes()?;
+        Ok(base16::encode_lower(&bytes))
+    }
+
+    /// Parses an item from the hex encoding produced by [`ExecutableDeployItem::to_hex`].
+    pub fn from_hex(hex: &str) -> Result<Self, FromHexError> {
+        let bytes = base16::decode(hex).map_err(FromHexError::Hex)?;
+        bytesrepr::deserialize(bytes).map_err(FromHexError::BytesRepr)
+    }
+
+    /// Deserializes the args of this item, decompressing them first if they use the encoding
+    /// produced by [`args_compression::compress_args`].
+    ///
+    /// Before anything is allocated for them, the declared number of args and the lengths of their
+    /// names and values are checked against fixed limits and the size of the input, failing with
+    /// `DeserializationLimitExceeded` rather than attempting a huge allocation.  The same error is
+    /// returned if the args decompress to more than
+    /// [`args_compression::MAX_DECOMPRESSED_ARGS_LENGTH`] bytes or declare a `CLType` nested too
+    /// deeply.
     pub fn into_runtime_args(self) -> Result<RuntimeArgs, bytesrepr::Error> {
         match self {
             ExecutableDeployItem::ModuleBytes { args, .. }
@@ -72,12 +341,47 @@ impl ExecutableDeployItem {
             | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
             | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
             | ExecutableDeployItem::Transfer { args } => {
+                let args = args_compression::decompress_args(args)?;
+                check_args_limits(&args)?;
                 let runtime_args: RuntimeArgs = bytesrepr::deserialize(args)?;
                 Ok(runtime_args)
             }
         }
     }
 
+    /// Returns the serialized args of this item, in whichever encoding they were given.
+    pub fn args(&self) -> &[u8] {
+        match self {
+            ExecutableDeployItem::ModuleBytes { args, .. }
+            | ExecutableDeployItem::StoredContractByHash { args, .. }
+            | ExecutableDeployItem::StoredContractByName { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
+            | ExecutableDeployItem::Transfer { args } => args,
+        }
+    }
+
+    /// Returns at most the first `max` bytes of the serialized args of this item, for previewing
+    /// them in logs without flooding them.
+    pub fn args_preview(&self, max: usize) -> Vec<u8> {
+        let args = self.args();
+        args[..args.len().min(max)].to_vec()
+    }
+
+    /// Returns this item with its serialized args replaced by `args`, leaving its target and entry
+    /// point unchanged.
+    pub fn with_args(mut self, new_args: Vec<u8>) -> Self {
+        match &mut self {
+            ExecutableDeployItem::ModuleBytes { args, .. }
+            | ExecutableDeployItem::StoredContractByHash { args, .. }
+            | ExecutableDeployItem::StoredContractByName { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
+            | ExecutableDeployItem::Transfer { args } => *args = new_args,
+        }
+        self
+    }
+
     pub fn entry_point_name(&self) -> &str {
         match self {
             ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
@@ -89,4 +393,1084 @@ impl ExecutableDeployItem {
             | ExecutableDeployItem::StoredContractByName { entry_point, .. } => &entry_point,
         }
     }
+    /// Returns a flattened summary of this deploy item, suitable for exposing over RPC.
+    pub fn summary(&self) -> DeployItemSummary {
+        let (variant, target, version) = match self {
+            ExecutableDeployItem::ModuleBytes { .. } => ("ModuleBytes", None, None),
+            ExecutableDeployItem::StoredContractByHash { hash, .. } => (
+                "StoredContractByHash",
+                Some(base16::encode_lower(hash)),
+                None,
+            ),
+            ExecutableDeployItem::StoredContractByName { name, .. } => {
+                ("StoredContractByName", Some(name.clone()), None)
+            }
+            ExecutableDeployItem::StoredVersionedContractByName { name, version, .. } => (
+                "StoredVersionedContractByName",
+                Some(name.clone()),
+                *version,
+            ),
+            ExecutableDeployItem::StoredVersionedContractByHash { hash, version, .. } => (
+                "StoredVersionedContractByHash",
+                Some(base16::encode_lower(hash)),
+                *version,
+            ),
+            ExecutableDeployItem::Transfer { .. } => ("Transfer", None, None),
+        };
+        let args_length = self.args().len();
+        DeployItemSummary {
+            variant: variant.to_string(),
+            target,
+            version,
+            entry_point: self.entry_point_name().to_string(),
+            args_length,
+        }
+    }
+
+    /// Returns a JSON object describing this item for debugging, e.g. when a test fails.
+    ///
+    /// The object holds the fields of [`ExecutableDeployItem::summary`], and either the decoded
+    /// args as `args`, sorted by name and each with its name, type and hex-encoded value, or
+    /// `args_error` if the args can't be decoded.  Module bytes are omitted.
+    pub fn to_debug_json(&self) -> String {
+        let (args, args_error) = match self.clone().into_runtime_args() {
+            Ok(runtime_args) => {
+                let args: BTreeMap<String, CLValue> = runtime_args.into();
+                let args = args
+                    .into_iter()
+                    .map(|(name, cl_value)| ArgDebugJson {
+                        name,
+                        cl_type: format!("{:?}", cl_value.cl_type()),
+                        value: base16::encode_lower(cl_value.inner_bytes()),
+                    })
+                    .collect();
+                (Some(args), None)
+            }
+            Err(error) => (None, Some(error.to_string())),
+        };
+        let debug_json = DeployItemDebugJson {
+            summary: self.summary(),
+            args,
+            args_error,
+        };
+        serde_json::to_string(&debug_json).expect("should serialize deploy item debug JSON")
+    }
+}
+
+impl ToBytes for ExecutableDeployItem {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                result.push(Tag::ModuleBytes as u8);
+                result.append(&mut module_bytes.to_bytes()?);
+                result.append(&mut args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => {
+                result.push(Tag::StoredContractByHash as u8);
+                result.append(&mut hash.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+                result.append(&mut args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => {
+                result.push(Tag::StoredContractByName as u8);
+                result.append(&mut name.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+                result.append(&mut args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => {
+                result.push(Tag::StoredVersionedContractByName as u8);
+                result.append(&mut name.to_bytes()?);
+                result.append(&mut version.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+                result.append(&mut args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => {
+                result.push(Tag::StoredVersionedContractByHash as u8);
+                result.append(&mut hash.to_bytes()?);
+                result.append(&mut version.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+                result.append(&mut args.to_bytes()?);
+            }
+            ExecutableDeployItem::Transfer { args } => {
+                result.push(Tag::Transfer as u8);
+                result.append(&mut args.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                    module_bytes.serialized_length() + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredContractByHash {
+                    hash,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredContractByName {
+                    name,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::Transfer { args } => args.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for ExecutableDeployItem {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            tag if tag == Tag::ModuleBytes as u8 => {
+                let (module_bytes, remainder) = FromBytes::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::ModuleBytes { module_bytes, args },
+                    remainder,
+                ))
+            }
+            tag if tag == Tag::StoredContractByHash as u8 => {
+                let (hash, remainder) = FromBytes::from_bytes(remainder)?;
+                let (entry_point, remainder) = FromBytes::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByHash {
+                        hash,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            tag if tag == Tag::StoredContractByName as u8 => {
+                let (name, remainder) = FromBytes::from_bytes(remainder)?;
+                let (entry_point, remainder) = FromBytes::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByName {
+                        name,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            tag if tag == Tag::StoredVersionedContractByName as u8 => {
+                let (name, remainder) = FromBytes::from_bytes(remainder)?;
+                let (version, remainder) = FromBytes::from_bytes(remainder)?;
+                let (entry_point, remainder) = FromBytes::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByName {
+                        name,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            tag if tag == Tag::StoredVersionedContractByHash as u8 => {
+                let (hash, remainder) = FromBytes::from_bytes(remainder)?;
+                let (version, remainder) = FromBytes::from_bytes(remainder)?;
+                let (entry_point, remainder) = FromBytes::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByHash {
+                        hash,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            tag if tag == Tag::Transfer as u8 => {
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((ExecutableDeployItem::Transfer { args }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// A flattened summary of an [`ExecutableDeployItem`] which doesn't expose the enum's internals.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct DeployItemSummary {
+    /// Name of the deploy item variant.
+    pub variant: String,
+    /// Hex-encoded hash or name of the targeted stored contract, if any.
+    pub target: Option<String>,
+    /// Requested contract version, if any.
+    pub version: Option<ContractVersion>,
+    /// Name of the entry point to be called.
+    pub entry_point: String,
+    /// Length in bytes of the serialized arguments.
+    pub args_length: usize,
+}
+
+/// A decoded arg of an [`ExecutableDeployItem`], as output by
+/// [`ExecutableDeployItem::to_debug_json`].
+#[derive(Serialize)]
+struct ArgDebugJson {
+    name: String,
+    cl_type: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct DeployItemDebugJson {
+    #[serde(flatten)]
+    summary: DeployItemSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<Vec<ArgDebugJson>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args_error: Option<String>,
+}
+
+/// Walks the framing of serialized `RuntimeArgs` without allocating, checking each declared count
+/// and length against its limit.
+fn check_args_limits(args: &[u8]) -> Result<(), bytesrepr::Error> {
+    let (count, mut remainder) = u32::from_bytes(args)?;
+    // Every arg takes at least one byte, so no more can be declared than there are bytes left.
+    if count > MAX_RUNTIME_ARGS_COUNT || count as usize > remainder.len() {
+        return Err(bytesrepr::Error::DeserializationLimitExceeded);
+    }
+    for _ in 0..count {
+        remainder = skip_length_prefixed(remainder, MAX_ARG_NAME_LENGTH)?;
+        remainder = skip_length_prefixed(remainder, u32::max_value())?;
+        let (_cl_type, rem) = CLType::from_bytes(remainder)?;
+        remainder = rem;
+    }
+    Ok(())
+}
+
+/// Skips over a `u32` length-prefixed run of bytes, failing if the declared length exceeds `max`
+/// or the bytes remaining.
+fn skip_length_prefixed(bytes: &[u8], max: u32) -> Result<&[u8], bytesrepr::Error> {
+    let (length, remainder) = u32::from_bytes(bytes)?;
+    if length > max || length as usize > remainder.len() {
+        return Err(bytesrepr::Error::DeserializationLimitExceeded);
+    }
+    Ok(&remainder[length as usize..])
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::account::Account;
+    use types::{
+        account::AccountHash,
+        bytesrepr::{self, ToBytes},
+        contracts::{NamedKeys, DEFAULT_ENTRY_POINT_NAME},
+        runtime_args, AccessRights, CLTyped, CLValue, Key, RuntimeArgs, URef, U512,
+    };
+
+    use super::{
+        DeployItemSummary, ExecutableDeployItem, FromHexError, ModuleBytesChunk,
+        MAX_ARG_NAME_LENGTH, MAX_RUNTIME_ARGS_COUNT,
+    };
+    use crate::engine_state::{args_compression, Error};
+
+    const ENTRY_POINT: &str = "entry_point";
+    const NAME: &str = "contract";
+
+    fn args() -> Vec<u8> {
+        vec![1, 2, 3]
+    }
+
+    fn summary(
+        variant: &str,
+        target: Option<&str>,
+        version: Option<u32>,
+        entry_point: &str,
+    ) -> DeployItemSummary {
+        DeployItemSummary {
+            variant: variant.to_string(),
+            target: target.map(str::to_string),
+            version,
+            entry_point: entry_point.to_string(),
+            args_length: args().len(),
+        }
+    }
+
+    #[test]
+    fn args_preview_should_return_short_args_whole() {
+        let item = ExecutableDeployItem::Transfer { args: args() };
+        assert_eq!(item.args_preview(args().len()), args());
+        assert_eq!(item.args_preview(usize::max_value()), args());
+    }
+
+    #[test]
+    fn args_preview_should_truncate_long_args() {
+        let item = ExecutableDeployItem::StoredContractByName {
+            name: NAME.to_string(),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(item.args_preview(2), &args()[..2]);
+        assert!(item.args_preview(0).is_empty());
+    }
+
+    fn transfer_with_args(args: Vec<u8>) -> ExecutableDeployItem {
+        ExecutableDeployItem::Transfer { args }
+    }
+
+    #[test]
+    fn should_reject_args_declaring_too_many_args() {
+        let mut args = (MAX_RUNTIME_ARGS_COUNT + 1).to_le_bytes().to_vec();
+        args.resize(args.len() + MAX_RUNTIME_ARGS_COUNT as usize + 1, 0);
+        assert_eq!(
+            transfer_with_args(args).into_runtime_args(),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_reject_args_declaring_more_args_than_bytes() {
+        let args = 2u32.to_le_bytes().to_vec();
+        assert_eq!(
+            transfer_with_args(args).into_runtime_args(),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_reject_args_with_huge_name_length() {
+        let mut args = 1u32.to_le_bytes().to_vec();
+        args.extend_from_slice(&(MAX_ARG_NAME_LENGTH + 1).to_le_bytes());
+        args.resize(args.len() + MAX_ARG_NAME_LENGTH as usize + 1, b'a');
+        assert_eq!(
+            transfer_with_args(args).into_runtime_args(),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_reject_args_with_value_length_beyond_input() {
+        let mut args = 1u32.to_le_bytes().to_vec();
+        args.extend("amount".to_string().into_bytes().unwrap());
+        args.extend_from_slice(&u32::max_value().to_le_bytes());
+        args.extend_from_slice(&[0; 8]);
+        assert_eq!(
+            transfer_with_args(args).into_runtime_args(),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_reject_args_decompressing_beyond_limit() {
+        let mut args = args_compression::COMPRESSED_ARGS_TAG.to_vec();
+        args.extend_from_slice(&[0, 1, 0]);
+        for _ in 0..=args_compression::MAX_DECOMPRESSED_ARGS_LENGTH / 255 {
+            args.extend_from_slice(&[1, 1, 0, 255]);
+        }
+        assert_eq!(
+            transfer_with_args(args).into_runtime_args(),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_reject_args_with_deeply_nested_cl_type() {
+        let mut args = 1u32.to_le_bytes().to_vec();
+        args.extend("amount".to_string().into_bytes().unwrap());
+        args.extend(Vec::<u8>::new().into_bytes().unwrap());
+        // An unterminated run of `CLType::List` tags.
+        args.resize(args.len() + 100_000, 14);
+        assert_eq!(
+            transfer_with_args(args).into_runtime_args(),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn should_accept_args_within_limits() {
+        let runtime_args = runtime_args! {
+            "amount" => U512::from(1),
+            "memo" => "hi".to_string(),
+        };
+        let item = transfer_with_args(runtime_args.to_bytes().unwrap());
+        assert_eq!(item.into_runtime_args(), Ok(runtime_args));
+    }
+
+    #[test]
+    fn should_summarize_module_bytes() {
+        let item = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![0; 10],
+            args: args(),
+        };
+        assert_eq!(
+            item.summary(),
+            summary("ModuleBytes", None, None, DEFAULT_ENTRY_POINT_NAME)
+        );
+    }
+
+    #[test]
+    fn should_summarize_stored_contract_by_hash() {
+        let item = ExecutableDeployItem::StoredContractByHash {
+            hash: [0xab; 32],
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(
+            item.summary(),
+            summary(
+                "StoredContractByHash",
+                Some("ab".repeat(32).as_str()),
+                None,
+                ENTRY_POINT
+            )
+        );
+    }
+
+    #[test]
+    fn should_summarize_stored_contract_by_name() {
+        let item = ExecutableDeployItem::StoredContractByName {
+            name: NAME.to_string(),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(
+            item.summary(),
+            summary("StoredContractByName", Some(NAME), None, ENTRY_POINT)
+        );
+    }
+
+    #[test]
+    fn should_summarize_stored_versioned_contract_by_name() {
+        let item = ExecutableDeployItem::StoredVersionedContractByName {
+            name: NAME.to_string(),
+            version: Some(2),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(
+            item.summary(),
+            summary(
+                "StoredVersionedContractByName",
+                Some(NAME),
+                Some(2),
+                ENTRY_POINT
+            )
+        );
+    }
+
+    #[test]
+    fn should_summarize_stored_versioned_contract_by_hash() {
+        let item = ExecutableDeployItem::StoredVersionedContractByHash {
+            hash: [0xcd; 32],
+            version: None,
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(
+            item.summary(),
+            summary(
+                "StoredVersionedContractByHash",
+                Some("cd".repeat(32).as_str()),
+                None,
+                ENTRY_POINT
+            )
+        );
+    }
+
+    #[test]
+    fn should_summarize_transfer() {
+        let item = ExecutableDeployItem::Transfer { args: args() };
+        assert_eq!(
+            item.summary(),
+            summary("Transfer", None, None, DEFAULT_ENTRY_POINT_NAME)
+        );
+    }
+
+    fn debug_json(item: &ExecutableDeployItem) -> serde_json::Value {
+        serde_json::from_str(&item.to_debug_json()).expect("should be valid JSON")
+    }
+
+    fn amount_args() -> Vec<u8> {
+        let args = runtime_args! { "amount" => U512::from(10) };
+        args.to_bytes().unwrap()
+    }
+
+    #[test]
+    fn should_output_debug_json_for_module_bytes() {
+        let item = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![0; 10],
+            args: amount_args(),
+        };
+        let json = debug_json(&item);
+        assert_eq!(json["variant"], "ModuleBytes");
+        assert!(json["target"].is_null());
+        assert_eq!(json["entry_point"], DEFAULT_ENTRY_POINT_NAME);
+        assert_eq!(json["args"][0]["name"], "amount");
+        assert_eq!(json["args"][0]["cl_type"], "U512");
+        assert_eq!(json["args"][0]["value"], "010a");
+        assert!(json.get("args_error").is_none());
+        assert!(json.get("module_bytes").is_none());
+    }
+
+    #[test]
+    fn should_output_debug_json_for_stored_items() {
+        let by_hash = ExecutableDeployItem::StoredContractByHash {
+            hash: [0xab; 32],
+            entry_point: ENTRY_POINT.to_string(),
+            args: amount_args(),
+        };
+        let json = debug_json(&by_hash);
+        assert_eq!(json["variant"], "StoredContractByHash");
+        assert_eq!(json["target"], "ab".repeat(32));
+        assert_eq!(json["entry_point"], ENTRY_POINT);
+        assert_eq!(json["args"][0]["name"], "amount");
+
+        let by_name = ExecutableDeployItem::StoredVersionedContractByName {
+            name: NAME.to_string(),
+            version: Some(2),
+            entry_point: ENTRY_POINT.to_string(),
+            args: RuntimeArgs::new().to_bytes().unwrap(),
+        };
+        let json = debug_json(&by_name);
+        assert_eq!(json["variant"], "StoredVersionedContractByName");
+        assert_eq!(json["target"], NAME);
+        assert_eq!(json["version"], 2);
+        assert_eq!(json["entry_point"], ENTRY_POINT);
+        assert_eq!(json["args"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn should_output_debug_json_for_transfer() {
+        let args = runtime_args! {
+            "target" => AccountHash::new([1; 32]),
+            "amount" => U512::from(10),
+        };
+        let item = ExecutableDeployItem::Transfer {
+            args: args.to_bytes().unwrap(),
+        };
+        let json = debug_json(&item);
+        assert_eq!(json["variant"], "Transfer");
+        assert_eq!(json["args"].as_array().map(Vec::len), Some(2));
+        assert_eq!(json["args"][0]["name"], "amount");
+        assert_eq!(json["args"][1]["name"], "target");
+        assert_eq!(json["args"][1]["value"], "01".repeat(32));
+    }
+
+    #[test]
+    fn should_output_debug_json_with_undecodable_args() {
+        let item = ExecutableDeployItem::Transfer { args: args() };
+        let json = debug_json(&item);
+        assert_eq!(json["variant"], "Transfer");
+        assert!(json.get("args").is_none());
+        assert!(json["args_error"].is_string());
+    }
+
+    fn account() -> Account {
+        let mut named_keys = NamedKeys::new();
+        named_keys.insert(NAME.to_string(), Key::Hash([3; 32]));
+        named_keys.insert(
+            "uref".to_string(),
+            Key::URef(URef::new([4; 32], AccessRights::READ)),
+        );
+        let main_purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        Account::create(AccountHash::new([1; 32]), named_keys, main_purse)
+    }
+
+    #[test]
+    fn should_normalize_name_based_items() {
+        let item = ExecutableDeployItem::StoredContractByName {
+            name: NAME.to_string(),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(
+            item.normalize(&account()).expect("should normalize"),
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [3; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            }
+        );
+
+        let item = ExecutableDeployItem::StoredVersionedContractByName {
+            name: NAME.to_string(),
+            version: Some(2),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert_eq!(
+            item.normalize(&account()).expect("should normalize"),
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [3; 32],
+                version: Some(2),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_change_hash_based_items() {
+        let items = vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![0; 10],
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [5; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [6; 32],
+                version: None,
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::Transfer { args: args() },
+        ];
+        for item in items {
+            assert_eq!(
+                item.clone()
+                    .normalize(&account())
+                    .expect("should normalize"),
+                item
+            );
+        }
+    }
+
+    #[test]
+    fn should_replace_entry_point_of_stored_items() {
+        const NEW_ENTRY_POINT: &str = "new_entry_point";
+
+        let items = vec![
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [5; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: NAME.to_string(),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [6; 32],
+                version: Some(2),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: NAME.to_string(),
+                version: None,
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+        ];
+        for item in items {
+            let replaced = item
+                .clone()
+                .replace_entry_point(NEW_ENTRY_POINT.to_string());
+            assert_eq!(replaced.entry_point_name(), NEW_ENTRY_POINT);
+            assert_eq!(replaced.replace_entry_point(ENTRY_POINT.to_string()), item);
+        }
+    }
+
+    #[test]
+    fn should_not_replace_entry_point_of_module_bytes_or_transfer() {
+        let items = vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![0; 10],
+                args: args(),
+            },
+            ExecutableDeployItem::Transfer { args: args() },
+        ];
+        for item in items {
+            let replaced = item
+                .clone()
+                .replace_entry_point("new_entry_point".to_string());
+            assert_eq!(replaced, item);
+            assert_eq!(replaced.entry_point_name(), DEFAULT_ENTRY_POINT_NAME);
+        }
+    }
+
+    #[test]
+    fn should_allow_listed_targets() {
+        let allowlist = [[3; 32], [5; 32]];
+        let package_allowlist = [[3; 32]];
+        let items = vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![0; 10],
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [5; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: NAME.to_string(),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: NAME.to_string(),
+                version: None,
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::Transfer { args: args() },
+        ];
+        for item in items {
+            assert!(
+                item.target_allowed(&account(), &allowlist, &package_allowlist),
+                "{:?}",
+                item
+            );
+        }
+    }
+
+    #[test]
+    fn should_disallow_unlisted_targets() {
+        let allowlist = [[5; 32]];
+        let package_allowlist = [[6; 32]];
+        let items = vec![
+            // Only listed as a contract package hash, not as a contract hash.
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [6; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            // Only listed as a contract hash, not as a contract package hash.
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [5; 32],
+                version: Some(1),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            // Resolves to a hash which isn't listed.
+            ExecutableDeployItem::StoredContractByName {
+                name: NAME.to_string(),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            // Doesn't resolve to a hash at all.
+            ExecutableDeployItem::StoredContractByName {
+                name: "uref".to_string(),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: "missing".to_string(),
+                version: None,
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+        ];
+        for item in items {
+            assert!(
+                !item.target_allowed(&account(), &allowlist, &package_allowlist),
+                "{:?}",
+                item
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_to_normalize_unresolvable_names() {
+        let missing = ExecutableDeployItem::StoredContractByName {
+            name: "missing".to_string(),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert!(missing.normalize(&account()).is_err());
+
+        let not_a_hash = ExecutableDeployItem::StoredContractByName {
+            name: "uref".to_string(),
+            entry_point: ENTRY_POINT.to_string(),
+            args: args(),
+        };
+        assert!(not_a_hash.normalize(&account()).is_err());
+    }
+
+    fn chunk(index: u32, total: u32, bytes: &[u8]) -> ModuleBytesChunk {
+        ModuleBytesChunk {
+            index,
+            total,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    #[test]
+    fn should_reassemble_module_bytes_chunks() {
+        let module_bytes: Vec<u8> = (0..=255).collect();
+        let chunks = module_bytes
+            .chunks(100)
+            .enumerate()
+            .map(|(index, bytes)| chunk(index as u32, 3, bytes))
+            .collect();
+
+        let item = ExecutableDeployItem::from_module_bytes_chunks(chunks, args(), 256)
+            .expect("should reassemble");
+        assert_eq!(
+            item,
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes,
+                args: args(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_fail_to_reassemble_invalid_module_bytes_chunks() {
+        let assert_invalid = |chunks: Vec<ModuleBytesChunk>| {
+            assert!(ExecutableDeployItem::from_module_bytes_chunks(chunks, args(), 4).is_err())
+        };
+
+        assert_invalid(vec![]);
+        // Out of order.
+        assert_invalid(vec![chunk(1, 2, &[1]), chunk(0, 2, &[0])]);
+        // Missing a chunk.
+        assert_invalid(vec![chunk(0, 3, &[0]), chunk(1, 3, &[1])]);
+        // Too large.
+        assert_invalid(vec![chunk(0, 2, &[0, 1, 2]), chunk(1, 2, &[3, 4])]);
+    }
+
+    #[test]
+    fn should_validate_consistent_items() {
+        let items = vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![0; 10],
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [5; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: NAME.to_string(),
+                entry_point: DEFAULT_ENTRY_POINT_NAME.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: NAME.to_string(),
+                version: None,
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [6; 32],
+                version: Some(1),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::Transfer { args: args() },
+        ];
+        for item in items {
+            assert!(item.validate().is_ok(), "{:?}", item);
+        }
+    }
+
+    #[test]
+    fn should_reject_stored_items_without_entry_point() {
+        let items = vec![
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [5; 32],
+                entry_point: String::new(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: NAME.to_string(),
+                entry_point: String::new(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: NAME.to_string(),
+                version: None,
+                entry_point: String::new(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [6; 32],
+                version: Some(1),
+                entry_point: String::new(),
+                args: args(),
+            },
+        ];
+        for item in items {
+            match item.validate() {
+                Err(Error::InconsistentDeployItem(_)) => (),
+                result => panic!("unexpected result for {:?}: {:?}", item, result),
+            }
+        }
+    }
+
+    fn transfer_to(target: CLValue) -> ExecutableDeployItem {
+        let mut runtime_args = runtime_args! { "amount" => U512::from(1000) };
+        runtime_args.insert_cl_value("target", target);
+        ExecutableDeployItem::Transfer {
+            args: runtime_args.to_bytes().expect("should serialize"),
+        }
+    }
+
+    #[test]
+    fn should_validate_well_formed_transfer_targets() {
+        let targets = vec![
+            CLValue::from_t(AccountHash::new([1; 32])).unwrap(),
+            CLValue::from_t(Key::Account(AccountHash::new([1; 32]))).unwrap(),
+            CLValue::from_t(URef::new([2; 32], AccessRights::ADD)).unwrap(),
+        ];
+        for target in targets {
+            let item = transfer_to(target);
+            assert!(item.validate().is_ok(), "{:?}", item);
+        }
+    }
+
+    #[test]
+    fn should_reject_malformed_transfer_targets() {
+        let targets = vec![
+            CLValue::from_components(AccountHash::cl_type(), vec![1; 20]),
+            CLValue::from_t(Key::Hash([1; 32])).unwrap(),
+            CLValue::from_t("account".to_string()).unwrap(),
+        ];
+        for target in targets {
+            let item = transfer_to(target);
+            match item.validate() {
+                Err(Error::InvalidTransferTarget(_)) => (),
+                result => panic!("unexpected result for {:?}: {:?}", item, result),
+            }
+        }
+    }
+
+    #[test]
+    fn should_get_same_runtime_args_from_compressed_args() {
+        let runtime_args = runtime_args! {
+            "amount" => U512::from(1000),
+            "data" => vec![0u8; 512],
+        };
+        let args = runtime_args.to_bytes().expect("should serialize");
+        let compressed_args = args_compression::compress_args(&args);
+        assert_ne!(compressed_args, args);
+
+        let uncompressed_item = ExecutableDeployItem::Transfer { args };
+        let compressed_item = ExecutableDeployItem::Transfer {
+            args: compressed_args,
+        };
+        assert_eq!(
+            uncompressed_item
+                .into_runtime_args()
+                .expect("should deserialize"),
+            runtime_args
+        );
+        assert_eq!(
+            compressed_item
+                .into_runtime_args()
+                .expect("should deserialize"),
+            runtime_args
+        );
+    }
+
+    #[test]
+    fn should_round_trip_through_hex() {
+        let items = vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![0; 10],
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByHash {
+                hash: [5; 32],
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: NAME.to_string(),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: NAME.to_string(),
+                version: Some(2),
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: [6; 32],
+                version: None,
+                entry_point: ENTRY_POINT.to_string(),
+                args: args(),
+            },
+            ExecutableDeployItem::Transfer { args: args() },
+        ];
+        for item in items {
+            let hex = item.to_hex().expect("should encode");
+            assert_eq!(
+                ExecutableDeployItem::from_hex(&hex).expect("should decode"),
+                item
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_hex() {
+        match ExecutableDeployItem::from_hex("not hex") {
+            Err(FromHexError::Hex(_)) => (),
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // Valid hex, but with an unknown variant tag.
+        match ExecutableDeployItem::from_hex("ff") {
+            Err(FromHexError::BytesRepr(_)) => (),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
 }
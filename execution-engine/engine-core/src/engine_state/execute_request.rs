@@ -11,6 +11,9 @@ pub struct ExecuteRequest {
     pub block_time: u64,
     pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
     pub protocol_version: ProtocolVersion,
+    /// Whether the results are only being previewed rather than committed, allowing them to be
+    /// served from the execution result cache.
+    pub dry_run: bool,
 }
 
 impl ExecuteRequest {
@@ -19,12 +22,14 @@ impl ExecuteRequest {
         block_time: u64,
         deploys: Vec<Result<DeployItem, ExecutionResult>>,
         protocol_version: ProtocolVersion,
+        dry_run: bool,
     ) -> Self {
         Self {
             parent_state_hash,
             block_time,
             deploys,
             protocol_version,
+            dry_run,
         }
     }
 
@@ -40,6 +45,7 @@ impl Default for ExecuteRequest {
             block_time: 0,
             deploys: vec![],
             protocol_version: Default::default(),
+            dry_run: false,
         }
     }
 }
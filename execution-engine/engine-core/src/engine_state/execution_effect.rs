@@ -1,16 +1,58 @@
 use engine_shared::{additive_map::AdditiveMap, transform::Transform};
-use types::Key;
+use types::{Key, URef, U512};
 
 use super::op::Op;
 
+/// A mint transfer made during execution, along with the balances of both purses immediately
+/// before and after it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferRecord {
+    pub source: URef,
+    pub target: URef,
+    pub amount: U512,
+    pub source_balance_before: U512,
+    pub source_balance_after: U512,
+    pub target_balance_before: U512,
+    pub target_balance_after: U512,
+}
+
+/// A host function call made during execution, recorded when host call tracing is enabled in the
+/// [`EngineConfig`](super::EngineConfig).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostCallRecord {
+    /// The name under which the host function is imported by Wasm modules.
+    pub name: &'static str,
+    /// The raw Wasm arguments of the call.
+    pub args: String,
+    /// The value returned by the call, or the error it trapped with.
+    pub result: String,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ExecutionEffect {
     pub ops: AdditiveMap<Key, Op>,
     pub transforms: AdditiveMap<Key, Transform>,
+    /// Transfers in the order they were made.
+    pub transfers: Vec<TransferRecord>,
+    /// Host calls in the order they returned, if host call tracing is enabled.
+    pub host_calls: Vec<HostCallRecord>,
 }
 
 impl ExecutionEffect {
     pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
-        ExecutionEffect { ops, transforms }
+        ExecutionEffect {
+            ops,
+            transforms,
+            transfers: Vec::new(),
+            host_calls: Vec::new(),
+        }
+    }
+
+    pub fn with_transfers(self, transfers: Vec<TransferRecord>) -> Self {
+        ExecutionEffect { transfers, ..self }
+    }
+
+    pub fn with_host_calls(self, host_calls: Vec<HostCallRecord>) -> Self {
+        ExecutionEffect { host_calls, ..self }
     }
 }
@@ -1,4 +1,9 @@
-use super::{error, execution_effect::ExecutionEffect, op::Op, CONV_RATE};
+use super::{
+    error,
+    execution_effect::{ExecutionEffect, HostCallRecord, TransferRecord},
+    op::Op,
+    CONV_RATE,
+};
 use engine_shared::{
     additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
     stored_value::StoredValue, transform::Transform,
@@ -38,6 +43,17 @@ fn make_payment_error_effects(
     ExecutionEffect::new(ops, transforms)
 }
 
+/// The stage of deploy execution at which a failure occurred.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FailurePhase {
+    /// The payment code failed, or didn't pay enough to run the session code.
+    Payment,
+    /// The session code failed.
+    Session,
+    /// The proof of stake contract failed to finalize the payment.
+    Finalization,
+}
+
 #[derive(Debug)]
 pub enum ExecutionResult {
     /// An error condition that happened during execution
@@ -45,6 +61,11 @@ pub enum ExecutionResult {
         error: error::Error,
         effect: ExecutionEffect,
         cost: Gas,
+        /// The phase of the deploy in which execution stopped, or `None` if the failure didn't
+        /// happen while running one of the deploy's phases (e.g. a failed precondition check).
+        /// A failure to finalize the payment is reported as a precondition failure, but still
+        /// records `FailurePhase::Finalization`.
+        phase: Option<FailurePhase>,
     },
     /// Execution was finished successfully
     Success { effect: ExecutionEffect, cost: Gas },
@@ -66,6 +87,7 @@ impl ExecutionResult {
             error,
             effect: Default::default(),
             cost: Gas::default(),
+            phase: None,
         }
     }
 
@@ -106,12 +128,45 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns the phase in which a [`ExecutionResult::Failure`] stopped execution, if known.
+    pub fn failure_phase(&self) -> Option<FailurePhase> {
+        match self {
+            ExecutionResult::Failure { phase, .. } => *phase,
+            ExecutionResult::Success { .. } => None,
+        }
+    }
+
+    /// Records `phase` as the phase in which a [`ExecutionResult::Failure`] stopped execution.
+    /// Has no effect on [`ExecutionResult::Success`].
+    pub fn with_failure_phase(self, phase: FailurePhase) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                phase: Some(phase),
+            },
+            success @ ExecutionResult::Success { .. } => success,
+        }
+    }
+
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
-            ExecutionResult::Failure { error, effect, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                phase,
+                ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                phase,
             },
             ExecutionResult::Success { effect, .. } => ExecutionResult::Success { effect, cost },
         }
@@ -119,10 +174,13 @@ impl ExecutionResult {
 
     pub fn with_effect(self, effect: ExecutionEffect) -> Self {
         match self {
-            ExecutionResult::Failure { error, cost, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error, cost, phase, ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                phase,
             },
             ExecutionResult::Success { cost, .. } => ExecutionResult::Success { effect, cost },
         }
@@ -191,6 +249,7 @@ impl ExecutionResult {
             error,
             effect,
             cost,
+            phase: Some(FailurePhase::Payment),
         }
     }
 
@@ -274,6 +333,8 @@ impl ExecutionResultBuilder {
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
+        let mut transfers = Vec::new();
+        let mut host_calls = Vec::new();
 
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
@@ -283,9 +344,15 @@ impl ExecutionResultBuilder {
         match self.payment_execution_result {
             Some(result) => {
                 if result.is_failure() {
-                    return Ok(result);
+                    return Ok(result.with_failure_phase(FailurePhase::Payment));
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(
+                        &mut ops,
+                        &mut transforms,
+                        &mut transfers,
+                        &mut host_calls,
+                        result.effect(),
+                    );
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -296,9 +363,19 @@ impl ExecutionResultBuilder {
         match self.session_execution_result {
             Some(result) => {
                 if result.is_failure() {
-                    ret = result.with_cost(cost);
+                    // the trace of a failed session is kept to help diagnose the failure
+                    host_calls.extend(result.effect().host_calls.iter().cloned());
+                    ret = result
+                        .with_cost(cost)
+                        .with_failure_phase(FailurePhase::Session);
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(
+                        &mut ops,
+                        &mut transforms,
+                        &mut transfers,
+                        &mut host_calls,
+                        result.effect(),
+                    );
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
@@ -308,18 +385,27 @@ impl ExecutionResultBuilder {
             Some(result) => {
                 if result.is_failure() {
                     // payment_code_spec_5_a: Finalization Error should only ever be raised here
-                    return Ok(ExecutionResult::precondition_failure(
-                        error::Error::Finalization,
-                    ));
+                    return Ok(
+                        ExecutionResult::precondition_failure(error::Error::Finalization)
+                            .with_failure_phase(FailurePhase::Finalization),
+                    );
                 } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_effects(
+                        &mut ops,
+                        &mut transforms,
+                        &mut transfers,
+                        &mut host_calls,
+                        result.effect(),
+                    );
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
         }
 
         // Remove redundant writes to allow more opportunity to commute
-        let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
+        let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id)
+            .with_transfers(transfers)
+            .with_host_calls(host_calls);
 
         Ok(ret.with_effect(reduced_effect))
     }
@@ -327,8 +413,12 @@ impl ExecutionResultBuilder {
     fn add_effects(
         ops: &mut AdditiveMap<Key, Op>,
         transforms: &mut AdditiveMap<Key, Transform>,
+        transfers: &mut Vec<TransferRecord>,
+        host_calls: &mut Vec<HostCallRecord>,
         effect: &ExecutionEffect,
     ) {
+        transfers.extend(effect.transfers.iter().cloned());
+        host_calls.extend(effect.host_calls.iter().cloned());
         for (k, op) in effect.ops.iter() {
             ops.insert_add(*k, op.clone());
         }
@@ -358,12 +448,11 @@ impl ExecutionResultBuilder {
             .collect();
 
         for (k, old_value) in kvs {
-            if let Some(Transform::Write(new_value)) = transforms.remove(&k) {
-                if new_value == old_value {
-                    transforms.insert(k, Transform::Identity);
+            // Update in place so that the transform keeps its position in the execution order.
+            if let Some(transform) = transforms.get_mut(&k) {
+                if *transform == Transform::Write(old_value) {
+                    *transform = Transform::Identity;
                     ops.insert(k, Op::Read);
-                } else {
-                    transforms.insert(k, Transform::Write(new_value));
                 }
             }
         }
@@ -371,3 +460,77 @@ impl ExecutionResultBuilder {
         ExecutionEffect::new(ops, transforms)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::{gas::Gas, newtypes::CorrelationId};
+    use engine_storage::global_state::{in_memory::InMemoryGlobalState, StateProvider};
+    use types::{ApiError, U512};
+
+    use super::{ExecutionResult, ExecutionResultBuilder, FailurePhase};
+    use crate::{engine_state::error::Error, execution};
+
+    fn success() -> ExecutionResult {
+        ExecutionResult::Success {
+            effect: Default::default(),
+            cost: Gas::new(U512::from(1)),
+        }
+    }
+
+    fn failure() -> ExecutionResult {
+        ExecutionResult::Failure {
+            error: Error::Exec(execution::Error::Revert(ApiError::User(1))),
+            effect: Default::default(),
+            cost: Gas::new(U512::from(1)),
+            phase: None,
+        }
+    }
+
+    fn build(
+        payment: ExecutionResult,
+        session: ExecutionResult,
+        finalize: ExecutionResult,
+    ) -> ExecutionResult {
+        let correlation_id = CorrelationId::new();
+        let global_state = InMemoryGlobalState::empty().unwrap();
+        let reader = global_state
+            .checkout(global_state.empty_root_hash)
+            .unwrap()
+            .expect("should have empty root");
+
+        let mut builder = ExecutionResultBuilder::new();
+        builder
+            .set_payment_execution_result(payment)
+            .set_session_execution_result(session)
+            .set_finalize_execution_result(finalize);
+        builder.build(&reader, correlation_id).unwrap()
+    }
+
+    #[test]
+    fn should_report_payment_failure_phase() {
+        let result = build(failure(), success(), success());
+        assert_eq!(result.failure_phase(), Some(FailurePhase::Payment));
+    }
+
+    #[test]
+    fn should_report_session_failure_phase() {
+        let result = build(success(), failure(), success());
+        assert_eq!(result.failure_phase(), Some(FailurePhase::Session));
+    }
+
+    #[test]
+    fn should_report_finalization_failure_phase() {
+        let result = build(success(), success(), failure());
+        assert!(result.has_precondition_failure());
+        assert_eq!(result.failure_phase(), Some(FailurePhase::Finalization));
+    }
+
+    #[test]
+    fn should_not_report_phase_on_success_or_precondition_failure() {
+        let result = build(success(), success(), success());
+        assert_eq!(result.failure_phase(), None);
+
+        let result = ExecutionResult::precondition_failure(Error::Authorization);
+        assert_eq!(result.failure_phase(), None);
+    }
+}
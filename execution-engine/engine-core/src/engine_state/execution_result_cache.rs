@@ -0,0 +1,265 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, RwLock},
+};
+
+use engine_shared::{gas::Gas, newtypes::Blake2bHash};
+use types::{
+    bytesrepr::{self, ToBytes},
+    ProtocolVersion,
+};
+
+use super::{
+    deploy_item::DeployItem, execute_request::ExecuteRequest, execution_effect::ExecutionEffect,
+    execution_result::ExecutionResult,
+};
+
+/// Identifies a single execution of a deploy against a given pre-state.
+///
+/// The deploy is identified by a hash of all of its fields rather than by its deploy hash, since
+/// the latter is supplied by the client and isn't checked against the rest of the deploy.  Besides
+/// the pre-state root, the block time and protocol version are part of the key, since both can
+/// change the outcome of executing the same deploy on the same state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExecutionResultCacheKey {
+    pub deploy_item_hash: Blake2bHash,
+    pub state_root: Blake2bHash,
+    pub block_time: u64,
+    pub protocol_version: ProtocolVersion,
+}
+
+impl ExecutionResultCacheKey {
+    /// Returns the key identifying the execution of `deploy_item` as part of `exec_request`.
+    pub fn new(
+        deploy_item: &DeployItem,
+        exec_request: &ExecuteRequest,
+    ) -> Result<Self, bytesrepr::Error> {
+        Ok(ExecutionResultCacheKey {
+            deploy_item_hash: deploy_item_hash(deploy_item)?,
+            state_root: exec_request.parent_state_hash,
+            block_time: exec_request.block_time,
+            protocol_version: exec_request.protocol_version,
+        })
+    }
+}
+
+fn deploy_item_hash(deploy_item: &DeployItem) -> Result<Blake2bHash, bytesrepr::Error> {
+    let mut bytes = deploy_item.session.to_bytes()?;
+    bytes.append(&mut deploy_item.payment.to_bytes()?);
+    bytes.append(&mut deploy_item.address.to_bytes()?);
+    bytes.append(&mut deploy_item.gas_price.to_bytes()?);
+    bytes.append(&mut deploy_item.authorization_keys.to_bytes()?);
+    bytes.extend_from_slice(&deploy_item.deploy_hash);
+    Ok(Blake2bHash::new(&bytes))
+}
+
+/// The default maximum number of results held by an [`ExecutionResultCache`].
+pub const DEFAULT_EXECUTION_RESULT_CACHE_CAPACITY: usize = 1_000;
+
+#[derive(Debug, Default)]
+struct CachedResults {
+    state_root: Option<Blake2bHash>,
+    results: HashMap<ExecutionResultCacheKey, (ExecutionEffect, Gas)>,
+    /// The keys of `results` in the order they were inserted, oldest first.
+    insertion_order: VecDeque<ExecutionResultCacheKey>,
+    hits: u64,
+}
+
+#[derive(Debug)]
+struct Inner {
+    capacity: usize,
+    cached: RwLock<CachedResults>,
+}
+
+/// A cache of the results of executing deploys, used to avoid re-running identical dry-runs.
+///
+/// Only successful results are cached.  Executing against a new state root discards the results
+/// cached for the previous one, so the cache only ever holds results for a single pre-state.  The
+/// cache holds at most `capacity` results; once full, the oldest result is evicted before caching
+/// a further one.
+#[derive(Clone, Debug)]
+pub struct ExecutionResultCache(Arc<Inner>);
+
+impl ExecutionResultCache {
+    /// Creates an empty cache holding at most `capacity` results.
+    pub fn with_capacity(capacity: usize) -> Self {
+        ExecutionResultCache(Arc::new(Inner {
+            capacity,
+            cached: Default::default(),
+        }))
+    }
+
+    /// Returns the cached result for `key`, if any.
+    pub fn get(&self, key: &ExecutionResultCacheKey) -> Option<ExecutionResult> {
+        let mut guarded_cache = self.0.cached.write().unwrap();
+        let (effect, cost) = guarded_cache.results.get(key).cloned()?;
+        guarded_cache.hits += 1;
+        Some(ExecutionResult::Success { effect, cost })
+    }
+
+    /// Caches `result` under `key` if it is a success.
+    pub fn insert(&self, key: ExecutionResultCacheKey, result: &ExecutionResult) {
+        let (effect, cost) = match result {
+            ExecutionResult::Success { effect, cost } => (effect.clone(), *cost),
+            ExecutionResult::Failure { .. } => return,
+        };
+        if self.0.capacity == 0 {
+            return;
+        }
+        let mut guarded_cache = self.0.cached.write().unwrap();
+        if guarded_cache.state_root != Some(key.state_root) {
+            guarded_cache.state_root = Some(key.state_root);
+            guarded_cache.results.clear();
+            guarded_cache.insertion_order.clear();
+        }
+        if guarded_cache.results.insert(key, (effect, cost)).is_some() {
+            return;
+        }
+        guarded_cache.insertion_order.push_back(key);
+        if guarded_cache.results.len() > self.0.capacity {
+            if let Some(oldest) = guarded_cache.insertion_order.pop_front() {
+                guarded_cache.results.remove(&oldest);
+            }
+        }
+    }
+
+    /// Returns the number of results currently cached.
+    pub fn len(&self) -> usize {
+        self.0.cached.read().unwrap().results.len()
+    }
+
+    /// Returns `true` if no results are currently cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of lookups which were answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.0.cached.read().unwrap().hits
+    }
+}
+
+impl Default for ExecutionResultCache {
+    fn default() -> Self {
+        ExecutionResultCache::with_capacity(DEFAULT_EXECUTION_RESULT_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use engine_shared::{gas::Gas, newtypes::Blake2bHash};
+    use types::{account::AccountHash, ProtocolVersion, U512};
+
+    use super::{ExecutionResultCache, ExecutionResultCacheKey};
+    use crate::engine_state::{
+        deploy_item::DeployItem, error::Error, executable_deploy_item::ExecutableDeployItem,
+        execute_request::ExecuteRequest, execution_result::ExecutionResult,
+    };
+
+    fn key(deploy_item_hash: u8, state_root: &[u8]) -> ExecutionResultCacheKey {
+        ExecutionResultCacheKey {
+            deploy_item_hash: Blake2bHash::new(&[deploy_item_hash]),
+            state_root: Blake2bHash::new(state_root),
+            block_time: 0,
+            protocol_version: ProtocolVersion::V1_0_0,
+        }
+    }
+
+    fn success(cost: u64) -> ExecutionResult {
+        ExecutionResult::Success {
+            effect: Default::default(),
+            cost: Gas::new(U512::from(cost)),
+        }
+    }
+
+    #[test]
+    fn should_return_cached_success() {
+        let cache = ExecutionResultCache::default();
+        assert!(cache.get(&key(1, b"state root")).is_none());
+
+        cache.insert(key(1, b"state root"), &success(10));
+        let cached = cache.get(&key(1, b"state root")).expect("should hit cache");
+        assert!(cached.is_success());
+        assert_eq!(cached.cost(), Gas::new(U512::from(10)));
+        assert_eq!(cache.hits(), 1);
+        assert!(cache.get(&key(2, b"state root")).is_none());
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[test]
+    fn should_not_cache_failure() {
+        let cache = ExecutionResultCache::default();
+        cache.insert(
+            key(1, b"state root"),
+            &ExecutionResult::precondition_failure(Error::Authorization),
+        );
+        assert!(cache.is_empty());
+        assert!(cache.get(&key(1, b"state root")).is_none());
+    }
+
+    #[test]
+    fn should_discard_results_for_previous_state_root() {
+        let cache = ExecutionResultCache::default();
+        cache.insert(key(1, b"state root"), &success(10));
+        cache.insert(key(2, b"state root"), &success(20));
+        assert_eq!(cache.len(), 2);
+
+        cache.insert(key(1, b"other state root"), &success(30));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&key(1, b"state root")).is_none());
+        assert_eq!(
+            cache
+                .get(&key(1, b"other state root"))
+                .expect("should hit cache")
+                .cost(),
+            Gas::new(U512::from(30))
+        );
+    }
+
+    #[test]
+    fn should_key_on_whole_deploy_item() {
+        let deploy_item = |gas_price| {
+            DeployItem::new(
+                AccountHash::new([1; 32]),
+                ExecutableDeployItem::ModuleBytes {
+                    module_bytes: vec![1, 2, 3],
+                    args: vec![],
+                },
+                ExecutableDeployItem::ModuleBytes {
+                    module_bytes: vec![],
+                    args: vec![],
+                },
+                gas_price,
+                BTreeSet::new(),
+                [42; 32],
+            )
+        };
+        let exec_request = ExecuteRequest::default();
+        let key = |item: &DeployItem| {
+            ExecutionResultCacheKey::new(item, &exec_request).expect("should create key")
+        };
+
+        assert_eq!(key(&deploy_item(1)), key(&deploy_item(1)));
+        assert_ne!(key(&deploy_item(1)), key(&deploy_item(2)));
+    }
+
+    #[test]
+    fn should_evict_oldest_results_past_capacity() {
+        let cache = ExecutionResultCache::with_capacity(2);
+        cache.insert(key(1, b"state root"), &success(10));
+        cache.insert(key(2, b"state root"), &success(20));
+        cache.insert(key(3, b"state root"), &success(30));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key(1, b"state root")).is_none());
+        assert!(cache.get(&key(2, b"state root")).is_some());
+        assert!(cache.get(&key(3, b"state root")).is_some());
+
+        // Re-inserting a cached key doesn't count as a further result.
+        cache.insert(key(3, b"state root"), &success(30));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key(2, b"state root")).is_some());
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+use engine_shared::gas::Gas;
+use types::U512;
+
+/// Gas used by the deploys executed for one `ExecutableDeployItem` variant.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct VariantGasStatistics {
+    count: u64,
+    total: Gas,
+    max: Gas,
+}
+
+impl VariantGasStatistics {
+    /// Returns the number of deploys recorded.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean gas used per deploy, rounded down, or zero if none were recorded.
+    pub fn average(&self) -> Gas {
+        if self.count == 0 {
+            return Gas::default();
+        }
+        Gas::new(self.total.value() / U512::from(self.count))
+    }
+
+    /// Returns the most gas used by a single deploy.
+    pub fn max(&self) -> Gas {
+        self.max
+    }
+
+    fn record(&mut self, gas: Gas) {
+        self.count += 1;
+        self.total = self.total + gas;
+        if gas > self.max {
+            self.max = gas;
+        }
+    }
+}
+
+/// Running statistics of the gas used by executed deploys, keyed by the variant name of their
+/// session item, e.g. `"ModuleBytes"` or `"Transfer"`.
+///
+/// These are intended for operators tuning gas costs, and are kept in memory only.
+#[derive(Clone, Default, Debug)]
+pub struct GasStatistics(Arc<RwLock<BTreeMap<String, VariantGasStatistics>>>);
+
+impl GasStatistics {
+    /// Records a deploy of the given variant having used `gas`.
+    pub fn record(&self, variant: &str, gas: Gas) {
+        let mut guarded_map = self.0.write().unwrap();
+        guarded_map
+            .entry(variant.to_string())
+            .or_default()
+            .record(gas);
+    }
+
+    /// Returns the statistics for the given variant, if any deploys of it have been recorded.
+    pub fn get(&self, variant: &str) -> Option<VariantGasStatistics> {
+        let guarded_map = self.0.read().unwrap();
+        guarded_map.get(variant).copied()
+    }
+
+    /// Returns the statistics of every variant recorded so far.
+    pub fn snapshot(&self) -> BTreeMap<String, VariantGasStatistics> {
+        self.0.read().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::gas::Gas;
+    use types::U512;
+
+    use super::GasStatistics;
+
+    const MODULE_BYTES: &str = "ModuleBytes";
+    const TRANSFER: &str = "Transfer";
+
+    fn gas(value: u64) -> Gas {
+        Gas::new(U512::from(value))
+    }
+
+    #[test]
+    fn should_track_average_and_max_per_variant() {
+        let statistics = GasStatistics::default();
+        assert_eq!(statistics.get(MODULE_BYTES), None);
+
+        statistics.record(MODULE_BYTES, gas(100));
+        statistics.record(MODULE_BYTES, gas(300));
+        statistics.record(TRANSFER, gas(10));
+
+        let module_bytes = statistics.get(MODULE_BYTES).unwrap();
+        assert_eq!(module_bytes.count(), 2);
+        assert_eq!(module_bytes.average(), gas(200));
+        assert_eq!(module_bytes.max(), gas(300));
+
+        let transfer = statistics.get(TRANSFER).unwrap();
+        assert_eq!(transfer.count(), 1);
+        assert_eq!(transfer.average(), gas(10));
+        assert_eq!(transfer.max(), gas(10));
+
+        statistics.record(MODULE_BYTES, gas(50));
+        let module_bytes = statistics.get(MODULE_BYTES).unwrap();
+        assert_eq!(module_bytes.count(), 3);
+        assert_eq!(module_bytes.average(), gas(150));
+        assert_eq!(module_bytes.max(), gas(300));
+    }
+
+    #[test]
+    fn average_should_round_down() {
+        let statistics = GasStatistics::default();
+        statistics.record(TRANSFER, gas(1));
+        statistics.record(TRANSFER, gas(2));
+        assert_eq!(statistics.get(TRANSFER).unwrap().average(), gas(1));
+    }
+
+    #[test]
+    fn clones_should_share_statistics() {
+        let statistics = GasStatistics::default();
+        statistics.clone().record(TRANSFER, gas(7));
+        assert_eq!(statistics.snapshot().len(), 1);
+    }
+}
@@ -0,0 +1,81 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use engine_shared::account::Account;
+use types::{account::AccountHash, URef};
+
+/// Main purses to be used in place of those stored in the given accounts, allowing tests to run
+/// balance scenarios against a purse of their choosing.
+#[derive(Clone, Default, Debug)]
+pub struct MainPurseOverrides(Arc<RwLock<HashMap<AccountHash, URef>>>);
+
+impl MainPurseOverrides {
+    /// Resolves the main purse of the account `account_hash` to `main_purse` from now on.
+    ///
+    /// If the account already had an override, the previous main purse is returned.
+    pub fn insert(&self, account_hash: AccountHash, main_purse: URef) -> Option<URef> {
+        let mut guarded_map = self.0.write().unwrap();
+        guarded_map.insert(account_hash, main_purse)
+    }
+
+    /// Stops overriding the main purse of the account `account_hash`, returning the override.
+    pub fn remove(&self, account_hash: AccountHash) -> Option<URef> {
+        let mut guarded_map = self.0.write().unwrap();
+        guarded_map.remove(&account_hash)
+    }
+
+    /// Returns `account` with its main purse replaced by its override, if any.
+    pub fn apply(&self, account: Account) -> Account {
+        let guarded_map = self.0.read().unwrap();
+        match guarded_map.get(&account.account_hash()) {
+            Some(main_purse) => account.with_main_purse(*main_purse),
+            None => account,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::account::Account;
+    use types::{account::AccountHash, contracts::NamedKeys, AccessRights, URef};
+
+    use super::MainPurseOverrides;
+
+    const ACCOUNT_HASH: AccountHash = AccountHash::new([1; 32]);
+
+    fn account() -> Account {
+        let main_purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        Account::create(ACCOUNT_HASH, NamedKeys::new(), main_purse)
+    }
+
+    #[test]
+    fn should_override_main_purse_of_given_account_only() {
+        let overrides = MainPurseOverrides::default();
+        let main_purse = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        assert_eq!(overrides.insert(ACCOUNT_HASH, main_purse), None);
+
+        assert_eq!(overrides.apply(account()).main_purse(), main_purse);
+
+        let other_account = Account::create(
+            AccountHash::new([4; 32]),
+            NamedKeys::new(),
+            account().main_purse(),
+        );
+        assert_eq!(
+            overrides.apply(other_account).main_purse(),
+            account().main_purse()
+        );
+    }
+
+    #[test]
+    fn should_restore_main_purse_when_override_removed() {
+        let overrides = MainPurseOverrides::default();
+        let main_purse = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        overrides.insert(ACCOUNT_HASH, main_purse);
+
+        assert_eq!(overrides.remove(ACCOUNT_HASH), Some(main_purse));
+        assert_eq!(overrides.apply(account()), account());
+    }
+}
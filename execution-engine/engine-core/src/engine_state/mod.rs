@@ -1,3 +1,6 @@
+pub mod arg_redaction;
+pub mod args_compression;
+pub mod contract_ref;
 pub mod deploy_item;
 pub mod engine_config;
 mod error;
@@ -5,10 +8,16 @@ pub mod executable_deploy_item;
 pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
+pub mod execution_result_cache;
+pub mod gas_statistics;
 pub mod genesis;
+#[cfg(feature = "test-support")]
+pub mod main_purse_overrides;
 pub mod op;
 pub mod query;
+pub mod replay_protection;
 pub mod run_genesis_request;
+pub mod stored_args;
 pub mod system_contract_cache;
 mod transfer;
 pub mod upgrade;
@@ -32,6 +41,7 @@ use engine_shared::{
     newtypes::{Blake2bHash, CorrelationId},
     stored_value::StoredValue,
     transform::Transform,
+    TypeMismatch,
 };
 use engine_storage::{
     global_state::{CommitResult, StateProvider, StateReader},
@@ -57,11 +67,14 @@ pub use self::{
 };
 use crate::{
     engine_state::{
+        arg_redaction::ArgRedaction,
         deploy_item::DeployItem,
         error::Error::MissingSystemContract,
         executable_deploy_item::ExecutableDeployItem,
         execute_request::ExecuteRequest,
         execution_result::{ExecutionResult, ForcedTransferResult},
+        execution_result_cache::{ExecutionResultCache, ExecutionResultCacheKey},
+        gas_statistics::GasStatistics,
         genesis::{
             ExecConfig, GenesisAccount, GenesisResult, POS_PAYMENT_PURSE, POS_REWARDS_PURSE,
         },
@@ -74,6 +87,7 @@ use crate::{
         self, AddressGenerator, AddressGeneratorBuilder, DirectSystemContractCall, Executor,
     },
     tracking_copy::{TrackingCopy, TrackingCopyExt},
+    DeployHash,
 };
 
 // TODO?: MAX_PAYMENT && CONV_RATE values are currently arbitrary w/ real values
@@ -90,6 +104,11 @@ const ARG_AMOUNT: &str = "amount";
 pub struct EngineState<S> {
     config: EngineConfig,
     system_contract_cache: SystemContractCache,
+    gas_statistics: GasStatistics,
+    execution_result_cache: ExecutionResultCache,
+    arg_redaction: ArgRedaction,
+    #[cfg(feature = "test-support")]
+    main_purse_overrides: main_purse_overrides::MainPurseOverrides,
     state: S,
 }
 
@@ -129,6 +148,11 @@ where
         EngineState {
             config,
             system_contract_cache,
+            gas_statistics: Default::default(),
+            execution_result_cache: Default::default(),
+            arg_redaction: Default::default(),
+            #[cfg(feature = "test-support")]
+            main_purse_overrides: Default::default(),
             state,
         }
     }
@@ -137,6 +161,36 @@ where
         &self.config
     }
 
+    /// Returns `self` logging the values of deploy args as allowed by `arg_redaction`.  By default
+    /// only the names and types of args are logged.
+    pub fn with_arg_redaction(mut self, arg_redaction: ArgRedaction) -> Self {
+        self.arg_redaction = arg_redaction;
+        self
+    }
+
+    /// Returns which deploy arg values are logged.
+    pub fn arg_redaction(&self) -> &ArgRedaction {
+        &self.arg_redaction
+    }
+
+    /// Returns statistics of the gas used by the deploys executed so far, per session variant.
+    pub fn gas_statistics(&self) -> &GasStatistics {
+        &self.gas_statistics
+    }
+
+    /// Returns the cache of execution results, which is only used if enabled in the
+    /// [`EngineConfig`].
+    pub fn execution_result_cache(&self) -> &ExecutionResultCache {
+        &self.execution_result_cache
+    }
+
+    /// Returns the main purses used in place of those stored in accounts when executing their
+    /// deploys.  Only intended for use in tests.
+    #[cfg(feature = "test-support")]
+    pub fn main_purse_overrides(&self) -> &main_purse_overrides::MainPurseOverrides {
+        &self.main_purse_overrides
+    }
+
     pub fn wasm_costs(
         &self,
         protocol_version: ProtocolVersion,
@@ -705,33 +759,43 @@ where
         let preprocessor = Preprocessor::new(wasm_costs);
 
         let mut results = Vec::new();
+        let mut executed_deploy_hashes = BTreeSet::new();
 
         for deploy_item in exec_request.take_deploys() {
+            let maybe_deploy_hash = deploy_item.as_ref().ok().map(|item| item.deploy_hash);
             let result = match deploy_item {
                 Err(exec_result) => Ok(exec_result),
-                Ok(deploy_item) => match deploy_item.session {
-                    ExecutableDeployItem::Transfer { .. } => self.transfer(
+                Ok(deploy_item) => match self
+                    .check_not_executed(
                         correlation_id,
-                        &executor,
-                        &preprocessor,
-                        exec_request.protocol_version,
                         exec_request.parent_state_hash,
-                        BlockTime::new(exec_request.block_time),
-                        deploy_item,
-                    ),
-                    _ => self.deploy(
+                        deploy_item.deploy_hash,
+                        &executed_deploy_hashes,
+                    )
+                    .and_then(|_| deploy_item.session.validate())
+                    .and_then(|_| deploy_item.payment.validate())
+                {
+                    Err(error) => Ok(ExecutionResult::precondition_failure(error)),
+                    Ok(()) => self.run_deploy_item(
                         correlation_id,
                         &executor,
                         &preprocessor,
-                        exec_request.protocol_version,
-                        exec_request.parent_state_hash,
-                        BlockTime::new(exec_request.block_time),
+                        &exec_request,
                         deploy_item,
                     ),
                 },
             };
             match result {
-                Ok(result) => results.push(result),
+                Ok(result) => match maybe_deploy_hash {
+                    Some(deploy_hash)
+                        if self.config.enable_replay_protection()
+                            && !result.has_precondition_failure() =>
+                    {
+                        executed_deploy_hashes.insert(deploy_hash);
+                        results.push(replay_protection::with_receipt(result, deploy_hash))
+                    }
+                    _ => results.push(result),
+                },
                 Err(error) => {
                     return Err(error);
                 }
@@ -741,6 +805,188 @@ where
         Ok(results)
     }
 
+    /// Runs a single deploy which passed its preconditions, or returns its cached result if the
+    /// execution result cache is enabled, the request is a dry-run and the cache holds one.
+    fn run_deploy_item(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        exec_request: &ExecuteRequest,
+        deploy_item: DeployItem,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        let cache_key = if self.config.enable_execution_result_cache() && exec_request.dry_run {
+            ExecutionResultCacheKey::new(&deploy_item, exec_request).ok()
+        } else {
+            None
+        };
+        if let Some(result) = cache_key
+            .as_ref()
+            .and_then(|key| self.execution_result_cache.get(key))
+        {
+            return Ok(result);
+        }
+
+        let variant = deploy_item.session.summary().variant;
+        let result = match deploy_item.session {
+            ExecutableDeployItem::Transfer { .. } => self.transfer(
+                correlation_id,
+                executor,
+                preprocessor,
+                exec_request.protocol_version,
+                exec_request.parent_state_hash,
+                BlockTime::new(exec_request.block_time),
+                deploy_item,
+            ),
+            _ => self.deploy(
+                correlation_id,
+                executor,
+                preprocessor,
+                exec_request.protocol_version,
+                exec_request.parent_state_hash,
+                BlockTime::new(exec_request.block_time),
+                deploy_item,
+            ),
+        }?;
+
+        // Deploys rejected before execution used no gas, so would skew the statistics.
+        if !result.has_precondition_failure() {
+            self.gas_statistics.record(&variant, result.cost());
+        }
+        if let Some(cache_key) = cache_key {
+            self.execution_result_cache.insert(cache_key, &result);
+        }
+        Ok(result)
+    }
+
+    /// Returns [`Error::AlreadyExecuted`] if replay protection is enabled and the deploy with the
+    /// given hash was already executed, either before `prestate_hash` or earlier in the current
+    /// request.
+    fn check_not_executed(
+        &self,
+        correlation_id: CorrelationId,
+        prestate_hash: Blake2bHash,
+        deploy_hash: DeployHash,
+        executed_deploy_hashes: &BTreeSet<DeployHash>,
+    ) -> Result<(), Error> {
+        if !self.config.enable_replay_protection() {
+            return Ok(());
+        }
+        if executed_deploy_hashes.contains(&deploy_hash) {
+            return Err(Error::AlreadyExecuted(deploy_hash.into()));
+        }
+        // A missing prestate is reported when the deploy is run.
+        let mut tracking_copy = match self.tracking_copy(prestate_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(()),
+        };
+        match tracking_copy
+            .read(
+                correlation_id,
+                &replay_protection::receipt_key(&deploy_hash),
+            )
+            .map_err(|error| Error::Exec(error.into()))?
+        {
+            Some(_) => Err(Error::AlreadyExecuted(deploy_hash.into())),
+            None => Ok(()),
+        }
+    }
+
+    fn check_stored_contract_exists(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        deploy_item: &ExecutableDeployItem,
+    ) -> Result<(), Error> {
+        let (key, error) = match deploy_item {
+            ExecutableDeployItem::StoredContractByHash { hash, .. } => {
+                (Key::from(*hash), Error::NoSuchContract(*hash))
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash { hash, .. } => {
+                (Key::from(*hash), Error::NoSuchContractPackage(*hash))
+            }
+            _ => return Ok(()),
+        };
+        // Uses `get` rather than `read` so that the check isn't recorded in the deploy's effects.
+        let maybe_stored_value = tracking_copy
+            .borrow_mut()
+            .get(correlation_id, &key)
+            .map_err(|error| Error::Exec(error.into()))?;
+        match (deploy_item, maybe_stored_value) {
+            (ExecutableDeployItem::StoredContractByHash { .. }, Some(StoredValue::Contract(_)))
+            | (
+                ExecutableDeployItem::StoredVersionedContractByHash { .. },
+                Some(StoredValue::ContractPackage(_)),
+            ) => Ok(()),
+            _ => Err(error),
+        }
+    }
+
+    /// Returns `deploy_item` with its args replaced by the args stored in global state if they use
+    /// the [`StoredArgs`](stored_args::StoredArgs) encoding, otherwise returns it unchanged.
+    ///
+    /// Only the args of stored session code may be loaded, and only from a `Key::Hash` or from one
+    /// of the account's named keys.  A URef must be readable as held in the named keys.
+    fn load_stored_args(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+        account: &Account,
+        deploy_item: ExecutableDeployItem,
+        phase: Phase,
+    ) -> Result<ExecutableDeployItem, Error> {
+        let args_key = match stored_args::StoredArgs::from_args(deploy_item.args()) {
+            Ok(Some(stored_args)) => stored_args.args_key,
+            Ok(None) => return Ok(deploy_item),
+            Err(error) => return Err(Error::Exec(error.into())),
+        };
+        match deploy_item {
+            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+                return Err(Error::InvalidStoredArgs(
+                    "only stored contracts may use stored args".to_string(),
+                ))
+            }
+            _ if phase != Phase::Session => {
+                return Err(Error::InvalidStoredArgs(
+                    "only session code may use stored args".to_string(),
+                ))
+            }
+            _ => (),
+        }
+        let is_permitted = match args_key {
+            Key::Hash(_) => true,
+            Key::URef(uref) => account.named_keys().values().any(|key| match key {
+                Key::URef(named_uref) => {
+                    named_uref.addr() == uref.addr() && named_uref.is_readable()
+                }
+                _ => false,
+            }),
+            _ => account.named_keys().values().any(|key| *key == args_key),
+        };
+        if !is_permitted {
+            return Err(Error::InvalidStoredArgs(format!(
+                "args key {} is not accessible to the account",
+                args_key
+            )));
+        }
+        let args = match tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &args_key)
+            .map_err(|error| Error::Exec(error.into()))?
+        {
+            Some(StoredValue::CLValue(cl_value)) => cl_value
+                .into_t::<Vec<u8>>()
+                .map_err(|error| Error::Exec(error.into()))?,
+            Some(other) => {
+                return Err(Error::Exec(execution::Error::TypeMismatch(
+                    TypeMismatch::new("CLValue".to_string(), other.type_name()),
+                )))
+            }
+            None => return Err(Error::Exec(execution::Error::KeyNotFound(args_key))),
+        };
+        Ok(deploy_item.with_args(args))
+    }
+
     pub fn get_module(
         &self,
         tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
@@ -801,19 +1047,23 @@ where
                         ))
                     })?;
 
-                if !contract_package.is_version_enabled(contract_version_key) {
-                    return Err(error::Error::Exec(
-                        execution::Error::InvalidContractVersion(contract_version_key),
-                    ));
-                }
-
-                let contract_hash = *contract_package
-                    .lookup_contract_hash(contract_version_key)
-                    .ok_or_else(|| {
-                        error::Error::Exec(execution::Error::InvalidContractVersion(
+                let contract_hash = match contract_package.versions().get(&contract_version_key) {
+                    Some(contract_hash)
+                        if contract_package.is_version_enabled(contract_version_key) =>
+                    {
+                        *contract_hash
+                    }
+                    Some(_) => {
+                        return Err(error::Error::Exec(execution::Error::VersionDisabled(
                             contract_version_key,
-                        ))
-                    })?;
+                        )))
+                    }
+                    None => {
+                        return Err(error::Error::Exec(execution::Error::NoSuchVersion(
+                            contract_version_key,
+                        )))
+                    }
+                };
 
                 let contract = tracking_copy
                     .borrow_mut()
@@ -834,7 +1084,16 @@ where
             .entry_point(entry_point_name)
             .cloned()
             .ok_or_else(|| {
-                error::Error::Exec(execution::Error::NoSuchMethod(entry_point_name.to_owned()))
+                let entry_point_name = entry_point_name.to_owned();
+                // Reported as for the equivalent call from a contract.
+                let exec_error = match deploy_item {
+                    ExecutableDeployItem::StoredVersionedContractByName { .. }
+                    | ExecutableDeployItem::StoredVersionedContractByHash { .. } => {
+                        execution::Error::NoSuchEntryPoint(entry_point_name)
+                    }
+                    _ => execution::Error::NoSuchMethod(entry_point_name),
+                };
+                error::Error::Exec(exec_error)
             })?;
 
         let contract_wasm = tracking_copy
@@ -916,6 +1175,9 @@ where
             return Err(execution::Error::DeploymentAuthorizationFailure.into());
         }
 
+        #[cfg(feature = "test-support")]
+        let account = self.main_purse_overrides.apply(account);
+
         Ok(account)
     }
 
@@ -1009,6 +1271,24 @@ where
         };
 
         let mut runtime_args_builder = TransferRuntimeArgsBuilder::new(input_runtime_args);
+        if runtime_args_builder.is_batch() {
+            return Ok(self.transfer_batch(
+                correlation_id,
+                executor,
+                mint_module,
+                runtime_args_builder,
+                &mut named_keys,
+                base_key,
+                &account,
+                authorization_keys,
+                blocktime,
+                deploy_item.deploy_hash,
+                gas_limit,
+                protocol_version,
+                tracking_copy,
+                protocol_data,
+            ));
+        }
         match runtime_args_builder.transfer_target_mode(correlation_id, Rc::clone(&tracking_copy)) {
             Ok(mode) => match mode {
                 TransferTargetMode::Unknown | TransferTargetMode::PurseExists(_) => { /* noop */ }
@@ -1054,6 +1334,7 @@ where
                     error,
                     effect: Default::default(),
                     cost: Gas::default(),
+                    phase: None,
                 });
             }
         }
@@ -1066,6 +1347,7 @@ where
                         error,
                         effect: Default::default(),
                         cost: Gas::default(),
+                        phase: None,
                     });
                 }
             };
@@ -1094,6 +1376,165 @@ where
         Ok(execution_result)
     }
 
+    /// Performs a batch of transfers from a single source purse to the main purses of many
+    /// accounts, creating any accounts which don't exist yet.
+    ///
+    /// Each target account is resolved at most once, however often it appears in the batch.  The
+    /// batch is atomic: if any of its transfers fails, the effects of all of them are discarded.
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_batch<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        mint_module: Module,
+        runtime_args_builder: TransferRuntimeArgsBuilder,
+        named_keys: &mut NamedKeys,
+        base_key: Key,
+        account: &Account,
+        authorization_keys: BTreeSet<AccountHash>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        protocol_data: ProtocolData,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let failure = |error: Error, cost: Gas| ExecutionResult::Failure {
+            error,
+            effect: Default::default(),
+            cost,
+            phase: None,
+        };
+
+        let (source_uref, targets) = match runtime_args_builder.resolve_batch(
+            account,
+            correlation_id,
+            Rc::clone(&tracking_copy),
+        ) {
+            Ok(batch) => batch,
+            Err(error) => return failure(error, Gas::default()),
+        };
+
+        let mut target_purses: BTreeMap<AccountHash, URef> = BTreeMap::new();
+        let mut extra_keys: Vec<Key> = vec![];
+        let mut cost = Gas::default();
+        let mut effect = Default::default();
+
+        for (target, amount) in targets {
+            let target_uref = match target_purses.get(&target) {
+                Some(target_uref) => *target_uref,
+                None => {
+                    let maybe_account = tracking_copy
+                        .borrow_mut()
+                        .read_account(correlation_id, target);
+                    let target_uref = match maybe_account {
+                        Ok(target_account) => target_account.main_purse(),
+                        Err(_) => {
+                            let (maybe_uref, execution_result): (Option<URef>, ExecutionResult) =
+                                executor.exec_system_contract(
+                                    DirectSystemContractCall::CreatePurse,
+                                    mint_module.clone(),
+                                    runtime_args! {}, // mint create takes no arguments
+                                    named_keys,
+                                    Default::default(),
+                                    base_key,
+                                    account,
+                                    authorization_keys.clone(),
+                                    blocktime,
+                                    deploy_hash,
+                                    gas_limit,
+                                    protocol_version,
+                                    correlation_id,
+                                    Rc::clone(&tracking_copy),
+                                    Phase::Session,
+                                    protocol_data,
+                                    SystemContractCache::clone(&self.system_contract_cache),
+                                );
+                            cost = cost + execution_result.cost();
+                            let main_purse = match (maybe_uref, execution_result) {
+                                (Some(main_purse), _) => main_purse,
+                                (None, ExecutionResult::Failure { error, .. }) => {
+                                    return failure(error, cost)
+                                }
+                                (None, ExecutionResult::Success { .. }) => {
+                                    return failure(
+                                        Error::Exec(execution::Error::ExpectedReturnValue),
+                                        cost,
+                                    )
+                                }
+                            };
+                            let new_account =
+                                Account::create(target, Default::default(), main_purse);
+                            extra_keys.push(Key::from(main_purse));
+                            tracking_copy
+                                .borrow_mut()
+                                .write(Key::Account(target), StoredValue::Account(new_account));
+                            main_purse
+                        }
+                    };
+                    target_purses.insert(target, target_uref);
+                    target_uref
+                }
+            };
+
+            if source_uref.addr() == target_uref.addr() {
+                return failure(
+                    execution::Error::Revert(types::ApiError::InvalidPurse).into(),
+                    cost,
+                );
+            }
+
+            let (transfer_result, execution_result): (Option<Result<(), u8>>, ExecutionResult) =
+                executor.exec_system_contract(
+                    DirectSystemContractCall::Transfer,
+                    mint_module.clone(),
+                    TransferRuntimeArgsBuilder::batch_transfer_args(
+                        source_uref,
+                        target_uref,
+                        amount,
+                    ),
+                    named_keys,
+                    extra_keys.as_slice(),
+                    base_key,
+                    account,
+                    authorization_keys.clone(),
+                    blocktime,
+                    deploy_hash,
+                    gas_limit,
+                    protocol_version,
+                    correlation_id,
+                    Rc::clone(&tracking_copy),
+                    Phase::Session,
+                    protocol_data,
+                    SystemContractCache::clone(&self.system_contract_cache),
+                );
+            cost = cost + execution_result.cost();
+            match (transfer_result, execution_result) {
+                (_, ExecutionResult::Failure { error, .. }) => return failure(error, cost),
+                (Some(Err(code)), _) => {
+                    return failure(
+                        execution::Error::Revert(types::ApiError::Mint(code)).into(),
+                        cost,
+                    )
+                }
+                (
+                    _,
+                    ExecutionResult::Success {
+                        effect: new_effect, ..
+                    },
+                ) => effect = new_effect,
+            }
+        }
+
+        // The tracking copy is shared by every transfer in the batch, so the effect of the last
+        // one includes those of all the others.
+        ExecutionResult::Success { effect, cost }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn deploy(
         &self,
@@ -1162,6 +1603,40 @@ where
         let payment = deploy_item.payment;
         let deploy_hash = deploy_item.deploy_hash;
 
+        // Check that contracts and contract packages referenced directly by hash exist before doing
+        // any work
+        for deploy_item in &[&session, &payment] {
+            if let Err(error) = self.check_stored_contract_exists(
+                correlation_id,
+                Rc::clone(&tracking_copy),
+                deploy_item,
+            ) {
+                return Ok(ExecutionResult::precondition_failure(error));
+            }
+        }
+
+        // Load any args stored in global state, so the rest of the deploy sees them inline
+        let session = match self.load_stored_args(
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            &account,
+            session,
+            Phase::Session,
+        ) {
+            Ok(session) => session,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+        };
+        let payment = match self.load_stored_args(
+            correlation_id,
+            Rc::clone(&tracking_copy),
+            &account,
+            payment,
+            Phase::Payment,
+        ) {
+            Ok(payment) => payment,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+        };
+
         // Create session code `A` from provided session bytes
         // validation_spec_1: valid wasm bytes
         // we do this upfront as there is no reason to continue if session logic is invalid
@@ -1385,14 +1860,22 @@ where
                 ),
             };
 
+            let payment_args_length = payment.args().len();
             let payment_args = match payment.into_runtime_args() {
                 Ok(args) => args,
                 Err(e) => {
                     let exec_err: crate::execution::Error = e.into();
-                    warn!("Unable to deserialize arguments: {:?}", exec_err);
+                    warn!(
+                        "Unable to deserialize {} bytes of arguments: {:?}",
+                        payment_args_length, exec_err
+                    );
                     return Ok(ExecutionResult::precondition_failure(exec_err.into()));
                 }
             };
+            debug!(
+                "Payment arguments: {}",
+                self.arg_redaction.args(&payment_args)
+            );
 
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
@@ -1465,6 +1948,7 @@ where
                         error: error.into(),
                         effect: effects_snapshot,
                         cost: runtime.context().gas_counter(),
+                        phase: None,
                     },
                 }
             }
@@ -1582,14 +2066,22 @@ where
             ),
         };
 
+        let session_args_length = session.args().len();
         let session_args = match session.into_runtime_args() {
             Ok(args) => args,
             Err(e) => {
                 let exec_err: crate::execution::Error = e.into();
-                warn!("Unable to deserialize session arguments: {:?}", exec_err);
+                warn!(
+                    "Unable to deserialize {} bytes of session arguments: {:?}",
+                    session_args_length, exec_err
+                );
                 return Ok(ExecutionResult::precondition_failure(exec_err.into()));
             }
         };
+        debug!(
+            "Session arguments: {}",
+            self.arg_redaction.args(&session_args)
+        );
         let session_result = {
             // payment_code_spec_3_b_i: if (balance of PoS pay purse) >= (gas spent during
             // payment code execution) * conv_rate, yes session
@@ -0,0 +1,70 @@
+//! Detection of deploys which have already been executed.
+//!
+//! When replay protection is enabled, the effects of every executed deploy include a receipt
+//! written under [`receipt_key`] of its hash.  A deploy whose receipt is already present in the
+//! prestate is rejected with [`Error::AlreadyExecuted`](super::Error::AlreadyExecuted) rather than
+//! being run again.  Deploys which fail a precondition aren't executed, so get no receipt.
+
+use engine_shared::{newtypes::Blake2bHash, stored_value::StoredValue, transform::Transform};
+use types::{CLValue, Key};
+
+use crate::{
+    engine_state::{execution_result::ExecutionResult, op::Op},
+    DeployHash,
+};
+
+/// Domain separator hashed together with a deploy hash to derive its receipt key, so that
+/// receipts can't collide with contracts stored under `Key::Hash`.
+const RECEIPT_KEY_PREFIX: &[u8] = b"executed-deploy-receipt";
+
+/// Returns the key under which the receipt for the deploy with the given hash is stored.
+pub fn receipt_key(deploy_hash: &DeployHash) -> Key {
+    let mut bytes = RECEIPT_KEY_PREFIX.to_vec();
+    bytes.extend_from_slice(deploy_hash);
+    Key::Hash(Blake2bHash::new(&bytes).value())
+}
+
+/// Returns `result` with the receipt for the deploy with the given hash added to its effects.
+pub fn with_receipt(result: ExecutionResult, deploy_hash: DeployHash) -> ExecutionResult {
+    let key = receipt_key(&deploy_hash);
+    let receipt = CLValue::from_t(deploy_hash).expect("should convert deploy hash");
+    let mut effect = result.effect().clone();
+    effect.ops.insert(key, Op::Write);
+    effect
+        .transforms
+        .insert(key, Transform::Write(StoredValue::CLValue(receipt)));
+    result.with_effect(effect)
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::gas::Gas;
+
+    use super::*;
+
+    #[test]
+    fn receipt_key_should_depend_only_on_deploy_hash() {
+        assert_eq!(receipt_key(&[1; 32]), receipt_key(&[1; 32]));
+        assert_ne!(receipt_key(&[1; 32]), receipt_key(&[2; 32]));
+        assert_ne!(receipt_key(&[1; 32]), Key::Hash([1; 32]));
+    }
+
+    #[test]
+    fn with_receipt_should_write_deploy_hash() {
+        let deploy_hash = [3; 32];
+        let result = ExecutionResult::Success {
+            effect: Default::default(),
+            cost: Gas::default(),
+        };
+        let result = with_receipt(result, deploy_hash);
+
+        let key = receipt_key(&deploy_hash);
+        assert_eq!(result.effect().ops.get(&key), Some(&Op::Write));
+        assert_eq!(
+            result.effect().transforms.get(&key),
+            Some(&Transform::Write(StoredValue::CLValue(
+                CLValue::from_t(deploy_hash).unwrap()
+            )))
+        );
+    }
+}
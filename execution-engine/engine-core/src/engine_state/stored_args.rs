@@ -0,0 +1,77 @@
+//! An encoding for deploy item args which refers to args stored in global state.
+//!
+//! Stored args are prefixed with [`STORED_ARGS_TAG`], which, like the tag of compressed args,
+//! can't begin a serialized `RuntimeArgs` in practice.  The remainder is the serialized [`Key`]
+//! under which the args are stored.  The value under that key must be a `CLValue` holding the
+//! serialized `RuntimeArgs` as bytes, optionally using the compressed encoding.
+//!
+//! Only the args of stored session code may be stored.  The key must be a `Key::Hash`, or one of
+//! the deploying account's named keys; a URef must be readable as held in the named keys.
+
+use types::{
+    bytesrepr::{self, ToBytes},
+    Key,
+};
+
+/// Prefix marking args as stored in global state.
+pub const STORED_ARGS_TAG: [u8; 4] = [0xfe, 0xff, 0xff, 0xff];
+
+/// A reference to deploy item args stored in global state, allowing large sets of args to be
+/// shared between deploys rather than inlined in each of them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StoredArgs {
+    pub args_key: Key,
+}
+
+impl StoredArgs {
+    pub fn new(args_key: Key) -> Self {
+        StoredArgs { args_key }
+    }
+
+    /// Returns the deploy item args referring to the args stored under `self.args_key`.
+    pub fn to_args(&self) -> Vec<u8> {
+        let mut args = STORED_ARGS_TAG.to_vec();
+        args.append(&mut self.args_key.to_bytes().expect("should serialize key"));
+        args
+    }
+
+    /// Parses deploy item args produced by [`StoredArgs::to_args`], returning `None` if they don't
+    /// use the stored encoding.
+    pub fn from_args(args: &[u8]) -> Result<Option<Self>, bytesrepr::Error> {
+        if !args.starts_with(&STORED_ARGS_TAG) {
+            return Ok(None);
+        }
+        let args_key: Key = bytesrepr::deserialize(args[STORED_ARGS_TAG.len()..].to_vec())?;
+        Ok(Some(StoredArgs::new(args_key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{bytesrepr::ToBytes, runtime_args, RuntimeArgs, U512};
+
+    use super::*;
+
+    #[test]
+    fn should_round_trip_stored_args() {
+        let stored_args = StoredArgs::new(Key::Hash([42; 32]));
+        let args = stored_args.to_args();
+        assert_eq!(StoredArgs::from_args(&args), Ok(Some(stored_args)));
+    }
+
+    #[test]
+    fn should_pass_over_inline_args() {
+        let args = runtime_args! { "amount" => U512::from(42) }
+            .to_bytes()
+            .expect("should serialize");
+        assert_eq!(StoredArgs::from_args(&args), Ok(None));
+        assert_eq!(StoredArgs::from_args(&[]), Ok(None));
+    }
+
+    #[test]
+    fn should_fail_to_parse_truncated_key() {
+        let mut args = StoredArgs::new(Key::Hash([42; 32])).to_args();
+        args.pop();
+        assert!(StoredArgs::from_args(&args).is_err());
+    }
+}
@@ -1,7 +1,10 @@
 use engine_shared::{account::Account, newtypes::CorrelationId, stored_value::StoredValue};
 use engine_storage::global_state::StateReader;
 use std::{cell::RefCell, rc::Rc};
-use types::{account::AccountHash, AccessRights, ApiError, Key, RuntimeArgs, URef, U512};
+use types::{
+    account::AccountHash, system_contract_errors::mint, AccessRights, ApiError, Key, RuntimeArgs,
+    URef, U512,
+};
 
 use crate::{
     engine_state::Error,
@@ -12,6 +15,7 @@ use crate::{
 const SOURCE: &str = "source";
 const TARGET: &str = "target";
 const AMOUNT: &str = "amount";
+const TARGETS: &str = "targets";
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TransferTargetMode {
@@ -50,7 +54,7 @@ impl TransferRuntimeArgsBuilder {
             .is_ok()
     }
 
-    fn resolve_source_uref<R>(
+    pub(crate) fn resolve_source_uref<R>(
         &self,
         account: &Account,
         correlation_id: CorrelationId,
@@ -283,4 +287,72 @@ impl TransferRuntimeArgsBuilder {
 
         Ok(runtime_args)
     }
+
+    /// Returns `true` if the args request a batch of transfers to accounts via a `targets` arg,
+    /// rather than a single transfer via `target` and `amount` args.
+    pub fn is_batch(&self) -> bool {
+        self.inner.get(TARGETS).is_some()
+    }
+
+    /// Resolves the source purse and the `(account, amount)` pairs of a batch of transfers.
+    ///
+    /// The batch is rejected up front if it's empty, if any amount is zero, or if the total of
+    /// all amounts exceeds the balance of the source purse.
+    pub fn resolve_batch<R>(
+        &self,
+        account: &Account,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+    ) -> Result<(URef, Vec<(AccountHash, U512)>), Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<ExecError>,
+    {
+        let targets: Vec<(AccountHash, U512)> = match self.inner.get(TARGETS) {
+            Some(cl_value) => match cl_value.clone().into_t() {
+                Ok(targets) => targets,
+                Err(error) => return Err(Error::Exec(ExecError::Revert(error.into()))),
+            },
+            None => return Err(Error::Exec(ExecError::Revert(ApiError::MissingArgument))),
+        };
+        if targets.is_empty() {
+            return Err(Error::Exec(ExecError::Revert(ApiError::InvalidArgument)));
+        }
+
+        let total = targets
+            .iter()
+            .try_fold(U512::zero(), |total, (_, amount)| {
+                if amount.is_zero() {
+                    None
+                } else {
+                    total.checked_add(*amount)
+                }
+            })
+            .ok_or(Error::Exec(ExecError::Revert(ApiError::Transfer)))?;
+
+        let source_uref =
+            self.resolve_source_uref(account, correlation_id, Rc::clone(&tracking_copy))?;
+        let source_balance = {
+            let mut tracking_copy = tracking_copy.borrow_mut();
+            let balance_key =
+                tracking_copy.get_purse_balance_key(correlation_id, source_uref.into())?;
+            tracking_copy.get_purse_balance(correlation_id, balance_key)?
+        };
+        if total > source_balance.value() {
+            return Err(Error::Exec(ExecError::Revert(
+                mint::Error::InsufficientFunds.into(),
+            )));
+        }
+
+        Ok((source_uref, targets))
+    }
+
+    /// Returns the runtime args for a single transfer of a batch, as passed to the mint.
+    pub fn batch_transfer_args(source_uref: URef, target_uref: URef, amount: U512) -> RuntimeArgs {
+        let mut runtime_args = RuntimeArgs::new();
+        runtime_args.insert(SOURCE, source_uref);
+        runtime_args.insert(TARGET, target_uref);
+        runtime_args.insert(AMOUNT, amount);
+        runtime_args
+    }
 }
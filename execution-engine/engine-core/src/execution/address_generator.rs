@@ -84,6 +84,17 @@ mod tests {
         assert_eq!(random_a, random_b)
     }
 
+    #[test]
+    fn should_generate_same_sequence_for_same_seed() {
+        let mut ag_a = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut ag_b = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let sequence_a: Vec<_> = (0..5).map(|_| ag_a.create_address()).collect();
+        let sequence_b: Vec<_> = (0..5).map(|_| ag_b.create_address()).collect();
+
+        assert_eq!(sequence_a, sequence_b);
+        assert_ne!(sequence_a[0], sequence_a[1]);
+    }
+
     #[test]
     fn should_not_generate_same_numbers_for_different_phase() {
         let mut ag_a = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Payment);
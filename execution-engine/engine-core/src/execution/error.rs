@@ -47,6 +47,12 @@ pub enum Error {
     /// Reverts execution with a provided status
     #[fail(display = "{}", _0)]
     Revert(ApiError),
+    /// Ends execution successfully before the end of the entry point
+    #[fail(display = "Finished early")]
+    FinishEarly,
+    /// Reverts execution with a provided status and a message explaining the failure
+    #[fail(display = "{}: {}", error, message)]
+    RevertWithMessage { error: ApiError, message: String },
     #[fail(display = "{}", _0)]
     AddKeyFailure(AddKeyFailure),
     #[fail(display = "{}", _0)]
@@ -82,6 +88,12 @@ pub enum Error {
     InvalidContractVersion(ContractVersionKey),
     #[fail(display = "No such method: {}", _0)]
     NoSuchMethod(String),
+    #[fail(display = "No such contract version: {}", _0)]
+    NoSuchVersion(ContractVersionKey),
+    #[fail(display = "Contract version is disabled: {}", _0)]
+    VersionDisabled(ContractVersionKey),
+    #[fail(display = "No such entry point: {}", _0)]
+    NoSuchEntryPoint(String),
     #[fail(display = "Wasm preprocessing error: {}", _0)]
     WasmPreprocessing(engine_wasm_prep::PreprocessingError),
     #[fail(
@@ -89,6 +101,16 @@ pub enum Error {
         expected, actual
     )]
     InvalidKeyLength { expected: usize, actual: usize },
+    #[fail(
+        display = "Too many effects: {} entries exceed the limit of {}",
+        actual, max
+    )]
+    TooManyEffects { max: usize, actual: usize },
+    #[fail(
+        display = "Named key {} of new contract version conflicts with previous version",
+        _0
+    )]
+    DuplicateNamedKey(String),
 }
 
 impl From<engine_wasm_prep::PreprocessingError> for Error {
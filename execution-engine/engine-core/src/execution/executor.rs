@@ -22,7 +22,7 @@ use crate::{
     execution::{address_generator::AddressGenerator, Error},
     runtime::{
         extract_access_rights_from_keys, extract_access_rights_from_urefs, instance_and_memory,
-        Runtime,
+        is_finish_early, Runtime,
     },
     runtime_context::{self, RuntimeContext},
     tracking_copy::TrackingCopy,
@@ -51,6 +51,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: Default::default(),
                     cost: $cost,
+                    phase: None,
                 };
             }
         }
@@ -65,6 +66,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: $effect,
                     cost: $cost,
+                    phase: None,
                 };
             }
         }
@@ -189,6 +191,7 @@ impl Executor {
                             error: error.into(),
                             effect: effects_snapshot,
                             cost: runtime.context().gas_counter(),
+                            phase: None,
                         };
                     }
                 }
@@ -211,20 +214,49 @@ impl Executor {
                             error: error.into(),
                             effect: effects_snapshot,
                             cost: runtime.context().gas_counter(),
+                            phase: None,
                         };
                     }
                 }
             }
         }
 
-        on_fail_charge!(
-            instance.invoke_export(entry_point_name, &[], &mut runtime),
-            runtime.context().gas_counter(),
-            effects_snapshot
-        );
+        if let Err(error) = instance.invoke_export(entry_point_name, &[], &mut runtime) {
+            // A call to `finish_early` ends execution successfully, keeping its effects.
+            if !is_finish_early(&error) {
+                let exec_err: Error = error.into();
+                warn!(
+                    "Execution failed: {:?}, {} args: {:?}",
+                    exec_err,
+                    args.len(),
+                    args.names()
+                );
+                return ExecutionResult::Failure {
+                    error: exec_err.into(),
+                    effect: effects_snapshot.with_host_calls(runtime.context().host_calls()),
+                    cost: runtime.context().gas_counter(),
+                    phase: None,
+                };
+            }
+        }
+
+        let effect = runtime.context().effect();
+        if let Some(max) = self.config.max_effect_entries() {
+            let actual = effect.transforms.len() - effects_snapshot.transforms.len();
+            if actual > max {
+                let exec_err = Error::TooManyEffects { max, actual };
+                warn!("Execution failed: {:?}", exec_err);
+                return ExecutionResult::Failure {
+                    error: exec_err.into(),
+                    effect: effects_snapshot.with_host_calls(effect.host_calls),
+                    cost: runtime.context().gas_counter(),
+                    phase: None,
+                };
+            }
+        }
 
         ExecutionResult::Success {
-            effect: runtime.context().effect(),
+            effect,
             cost: runtime.context().gas_counter(),
         }
     }
@@ -314,6 +346,7 @@ impl Executor {
                     effect: effect_snapshot.clone(),
                     cost: gas_counter,
                     error: e.into(),
+                    phase: None,
                 }
                 .take_without_ret::<T>();
             })
@@ -386,6 +419,7 @@ impl Executor {
                 error: error.into(),
                 effect,
                 cost,
+                phase: None,
             },
             None => ExecutionResult::Success { effect, cost },
         };
@@ -608,6 +642,7 @@ impl DirectSystemContractCall {
                     error: Error::CLValue(error).into(),
                     effect: execution_effect,
                     cost: runtime.context().gas_counter(),
+                    phase: None,
                 }
                 .take_without_ret(),
             },
@@ -615,6 +650,7 @@ impl DirectSystemContractCall {
                 error: error.into(),
                 effect: execution_effect,
                 cost: runtime.context().gas_counter(),
+                phase: None,
             }
             .take_without_ret(),
         }
@@ -49,6 +49,108 @@ pub enum FunctionIndex {
     RemoveContractUserGroupIndex,
     ExtendContractUserGroupURefsIndex,
     RemoveContractUserGroupURefsIndex,
+    GetSeedIndex,
+    UpgradeContractIndex,
+    TransferFromPurseToAccountWithPolicyIndex,
+    ReadContractValueIndex,
+    GetRemainingGasIndex,
+    RevertWithMessageIndex,
+    FinishEarlyIndex,
+    GetArgSizeIndex,
+    GetArgIndex,
+    GetContractPackageIndex,
+    GetActionThresholdsIndex,
+    GetTotalWeightIndex,
+    AcquireLockIndex,
+    ReleaseLockIndex,
+    GetApprovalCountIndex,
+    ListURefsIndex,
+    LoadRuntimeArgsIndex,
+    ReadValuesIndex,
+}
+
+impl FunctionIndex {
+    /// Returns the name under which Wasm modules import the host function.
+    pub fn import_name(self) -> &'static str {
+        match self {
+            FunctionIndex::WriteFuncIndex => "write",
+            FunctionIndex::WriteLocalFuncIndex => "write_local",
+            FunctionIndex::ReadFuncIndex => "read_value",
+            FunctionIndex::ReadLocalFuncIndex => "read_value_local",
+            FunctionIndex::AddFuncIndex => "add",
+            FunctionIndex::NewFuncIndex => "new_uref",
+            FunctionIndex::RetFuncIndex => "ret",
+            FunctionIndex::CallContractFuncIndex => "call_contract",
+            FunctionIndex::GetKeyFuncIndex => "get_key",
+            FunctionIndex::GasFuncIndex => "gas",
+            FunctionIndex::HasKeyFuncIndex => "has_key",
+            FunctionIndex::PutKeyFuncIndex => "put_key",
+            FunctionIndex::IsValidURefFnIndex => "is_valid_uref",
+            FunctionIndex::RevertFuncIndex => "revert",
+            FunctionIndex::AddAssociatedKeyFuncIndex => "add_associated_key",
+            FunctionIndex::RemoveAssociatedKeyFuncIndex => "remove_associated_key",
+            FunctionIndex::UpdateAssociatedKeyFuncIndex => "update_associated_key",
+            FunctionIndex::SetActionThresholdFuncIndex => "set_action_threshold",
+            FunctionIndex::LoadNamedKeysFuncIndex => "load_named_keys",
+            FunctionIndex::RemoveKeyFuncIndex => "remove_key",
+            FunctionIndex::GetCallerIndex => "get_caller",
+            FunctionIndex::GetBlocktimeIndex => "get_blocktime",
+            FunctionIndex::CreatePurseIndex => "create_purse",
+            FunctionIndex::TransferToAccountIndex => "transfer_to_account",
+            FunctionIndex::TransferFromPurseToAccountIndex => "transfer_from_purse_to_account",
+            FunctionIndex::TransferFromPurseToPurseIndex => "transfer_from_purse_to_purse",
+            FunctionIndex::GetBalanceIndex => "get_balance",
+            FunctionIndex::GetPhaseIndex => "get_phase",
+            FunctionIndex::GetSystemContractIndex => "get_system_contract",
+            FunctionIndex::GetMainPurseIndex => "get_main_purse",
+            FunctionIndex::ReadHostBufferIndex => "read_host_buffer",
+            FunctionIndex::CreateContractPackageAtHash => "create_contract_package_at_hash",
+            FunctionIndex::AddContractVersion => "add_contract_version",
+            FunctionIndex::DisableContractVersion => "disable_contract_version",
+            FunctionIndex::CallVersionedContract => "call_versioned_contract",
+            FunctionIndex::CreateContractUserGroup => "create_contract_user_group",
+            #[cfg(feature = "test-support")]
+            FunctionIndex::PrintIndex => "print",
+            FunctionIndex::GetRuntimeArgsizeIndex => "get_named_arg_size",
+            FunctionIndex::GetRuntimeArgIndex => "get_named_arg",
+            FunctionIndex::RemoveContractUserGroupIndex => "remove_contract_user_group",
+            FunctionIndex::ExtendContractUserGroupURefsIndex => {
+                "provision_contract_user_group_uref"
+            }
+            FunctionIndex::RemoveContractUserGroupURefsIndex => "remove_contract_user_group_urefs",
+            FunctionIndex::GetSeedIndex => "get_seed",
+            FunctionIndex::UpgradeContractIndex => "upgrade_contract",
+            FunctionIndex::TransferFromPurseToAccountWithPolicyIndex => {
+                "transfer_from_purse_to_account_with_policy"
+            }
+            FunctionIndex::ReadContractValueIndex => "read_contract_value",
+            FunctionIndex::GetRemainingGasIndex => "get_remaining_gas",
+            FunctionIndex::RevertWithMessageIndex => "revert_with_message",
+            FunctionIndex::FinishEarlyIndex => "finish_early",
+            FunctionIndex::GetArgSizeIndex => "get_arg_size",
+            FunctionIndex::GetArgIndex => "get_arg",
+            FunctionIndex::GetContractPackageIndex => "get_contract_package",
+            FunctionIndex::GetActionThresholdsIndex => "get_action_thresholds",
+            FunctionIndex::GetTotalWeightIndex => "get_total_weight",
+            FunctionIndex::AcquireLockIndex => "acquire_lock",
+            FunctionIndex::ReleaseLockIndex => "release_lock",
+            FunctionIndex::GetApprovalCountIndex => "get_approval_count",
+            FunctionIndex::ListURefsIndex => "list_urefs",
+            FunctionIndex::LoadRuntimeArgsIndex => "load_runtime_args",
+            FunctionIndex::ReadValuesIndex => "read_values",
+        }
+    }
+}
+
+impl FunctionIndex {
+    /// Returns the host function which Wasm modules import under `import_name`, if any.
+    pub fn from_import_name(import_name: &str) -> Option<FunctionIndex> {
+        (0usize..)
+            .map(FunctionIndex::try_from)
+            .take_while(Result::is_ok)
+            .filter_map(Result::ok)
+            .find(|function_index| function_index.import_name() == import_name)
+    }
 }
 
 impl Into<usize> for FunctionIndex {
@@ -69,7 +171,7 @@ impl TryFrom<usize> for FunctionIndex {
 #[cfg(test)]
 mod tests {
     use super::FunctionIndex;
-    use std::convert::TryFrom;
+    use std::{collections::HashSet, convert::TryFrom};
 
     #[test]
     fn primitive_to_enum() {
@@ -82,6 +184,34 @@ mod tests {
         let _primitive: usize = element.into();
     }
 
+    #[test]
+    fn import_names_should_be_unique() {
+        let import_names: Vec<&str> = (0usize..)
+            .map(FunctionIndex::try_from)
+            .take_while(Result::is_ok)
+            .map(|function_index| function_index.unwrap().import_name())
+            .collect();
+        let unique_import_names: HashSet<&str> = import_names.iter().cloned().collect();
+        assert_eq!(unique_import_names.len(), import_names.len());
+        assert_eq!(
+            FunctionIndex::CallVersionedContract.import_name(),
+            "call_versioned_contract"
+        );
+    }
+
+    #[test]
+    fn should_find_function_index_by_import_name() {
+        assert_eq!(
+            FunctionIndex::from_import_name("call_versioned_contract"),
+            Some(FunctionIndex::CallVersionedContract)
+        );
+        assert_eq!(
+            FunctionIndex::from_import_name("read_values"),
+            Some(FunctionIndex::ReadValuesIndex)
+        );
+        assert_eq!(FunctionIndex::from_import_name("memory"), None);
+    }
+
     #[test]
     fn invalid_index() {
         assert!(FunctionIndex::try_from(123_456_789usize).is_err());
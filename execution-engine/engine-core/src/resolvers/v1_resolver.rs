@@ -39,184 +39,156 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
         field_name: &str,
         _signature: &Signature,
     ) -> Result<FuncRef, InterpreterError> {
-        let func_ref = match field_name {
-            "read_value" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::ReadFuncIndex.into(),
-            ),
-            "read_value_local" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::ReadLocalFuncIndex.into(),
-            ),
-            "load_named_keys" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::LoadNamedKeysFuncIndex.into(),
-            ),
-            "write" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::WriteFuncIndex.into(),
-            ),
-            "write_local" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::WriteLocalFuncIndex.into(),
-            ),
-            "add" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::AddFuncIndex.into(),
-            ),
-            "new_uref" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], None),
-                FunctionIndex::NewFuncIndex.into(),
-            ),
-            "ret" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], None),
-                FunctionIndex::RetFuncIndex.into(),
-            ),
-            "get_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
-                FunctionIndex::GetKeyFuncIndex.into(),
-            ),
-            "has_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::HasKeyFuncIndex.into(),
-            ),
-            "put_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], None),
-                FunctionIndex::PutKeyFuncIndex.into(),
-            ),
-            "gas" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GasFuncIndex.into(),
-            ),
-            "is_valid_uref" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::IsValidURefFnIndex.into(),
-            ),
-            "revert" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::RevertFuncIndex.into(),
-            ),
-            "add_associated_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::AddAssociatedKeyFuncIndex.into(),
-            ),
-            "remove_associated_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::RemoveAssociatedKeyFuncIndex.into(),
-            ),
-            "update_associated_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::UpdateAssociatedKeyFuncIndex.into(),
-            ),
-            "set_action_threshold" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::SetActionThresholdFuncIndex.into(),
-            ),
-            "remove_key" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], None),
-                FunctionIndex::RemoveKeyFuncIndex.into(),
-            ),
-            "get_caller" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
-                FunctionIndex::GetCallerIndex.into(),
-            ),
-            "get_blocktime" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GetBlocktimeIndex.into(),
-            ),
-            "create_purse" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
-                FunctionIndex::CreatePurseIndex.into(),
-            ),
-            "transfer_to_account" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
-                FunctionIndex::TransferToAccountIndex.into(),
-            ),
-            "transfer_from_purse_to_account" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
-                FunctionIndex::TransferFromPurseToAccountIndex.into(),
-            ),
-            "transfer_from_purse_to_purse" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
-                FunctionIndex::TransferFromPurseToPurseIndex.into(),
-            ),
-            "get_balance" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::GetBalanceIndex.into(),
-            ),
-            "get_phase" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GetPhaseIndex.into(),
-            ),
-            "get_system_contract" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::GetSystemContractIndex.into(),
-            ),
-            "get_main_purse" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 1][..], None),
-                FunctionIndex::GetMainPurseIndex.into(),
-            ),
-            "read_host_buffer" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::ReadHostBufferIndex.into(),
-            ),
-            "create_contract_package_at_hash" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], None),
-                FunctionIndex::CreateContractPackageAtHash.into(),
-            ),
-            "create_contract_user_group" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 8][..], Some(ValueType::I32)),
-                FunctionIndex::CreateContractUserGroup.into(),
-            ),
-            "add_contract_version" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 10][..], Some(ValueType::I32)),
-                FunctionIndex::AddContractVersion.into(),
-            ),
-            "disable_contract_version" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
-                FunctionIndex::DisableContractVersion.into(),
-            ),
-            "call_contract" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32)),
-                FunctionIndex::CallContractFuncIndex.into(),
-            ),
-            "call_versioned_contract" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32)),
-                FunctionIndex::CallVersionedContract.into(),
-            ),
-            "get_named_arg_size" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
-                FunctionIndex::GetRuntimeArgsizeIndex.into(),
-            ),
-            "get_named_arg" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
-                FunctionIndex::GetRuntimeArgIndex.into(),
-            ),
-            "remove_contract_user_group" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
-                FunctionIndex::RemoveContractUserGroupIndex.into(),
-            ),
-            "provision_contract_user_group_uref" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32)),
-                FunctionIndex::ExtendContractUserGroupURefsIndex.into(),
-            ),
-            "remove_contract_user_group_urefs" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32)),
-                FunctionIndex::RemoveContractUserGroupURefsIndex.into(),
-            ),
-            #[cfg(feature = "test-support")]
-            "print" => FuncInstance::alloc_host(
-                Signature::new(&[ValueType::I32; 2][..], None),
-                FunctionIndex::PrintIndex.into(),
-            ),
-            _ => {
-                return Err(InterpreterError::Function(format!(
-                    "host module doesn't export function with name {}",
-                    field_name
-                )));
+        let function_index = FunctionIndex::from_import_name(field_name).ok_or_else(|| {
+            InterpreterError::Function(format!(
+                "host module doesn't export function with name {}",
+                field_name
+            ))
+        })?;
+        let signature = match function_index {
+            FunctionIndex::ReadFuncIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::ReadLocalFuncIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::LoadNamedKeysFuncIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::WriteFuncIndex => Signature::new(&[ValueType::I32; 4][..], None),
+            FunctionIndex::WriteLocalFuncIndex => Signature::new(&[ValueType::I32; 4][..], None),
+            FunctionIndex::AddFuncIndex => Signature::new(&[ValueType::I32; 4][..], None),
+            FunctionIndex::NewFuncIndex => Signature::new(&[ValueType::I32; 3][..], None),
+            FunctionIndex::RetFuncIndex => Signature::new(&[ValueType::I32; 2][..], None),
+            FunctionIndex::GetKeyFuncIndex => {
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32))
+            }
+            FunctionIndex::HasKeyFuncIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::PutKeyFuncIndex => Signature::new(&[ValueType::I32; 4][..], None),
+            FunctionIndex::GasFuncIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::IsValidURefFnIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::RevertFuncIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::AddAssociatedKeyFuncIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::RemoveAssociatedKeyFuncIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::UpdateAssociatedKeyFuncIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::SetActionThresholdFuncIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::RemoveKeyFuncIndex => Signature::new(&[ValueType::I32; 2][..], None),
+            FunctionIndex::GetCallerIndex => {
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetBlocktimeIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::CreatePurseIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::TransferToAccountIndex => {
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32))
+            }
+            FunctionIndex::TransferFromPurseToAccountIndex => {
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32))
+            }
+            FunctionIndex::TransferFromPurseToPurseIndex => {
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetBalanceIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetPhaseIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::GetSystemContractIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetMainPurseIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::ReadHostBufferIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::CreateContractPackageAtHash => {
+                Signature::new(&[ValueType::I32; 2][..], None)
+            }
+            FunctionIndex::CreateContractUserGroup => {
+                Signature::new(&[ValueType::I32; 8][..], Some(ValueType::I32))
             }
+            FunctionIndex::AddContractVersion => {
+                Signature::new(&[ValueType::I32; 10][..], Some(ValueType::I32))
+            }
+            FunctionIndex::DisableContractVersion => {
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32))
+            }
+            FunctionIndex::CallContractFuncIndex => {
+                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32))
+            }
+            FunctionIndex::CallVersionedContract => {
+                Signature::new(&[ValueType::I32; 9][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetRuntimeArgsizeIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetRuntimeArgIndex => {
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32))
+            }
+            FunctionIndex::RemoveContractUserGroupIndex => {
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32))
+            }
+            FunctionIndex::ExtendContractUserGroupURefsIndex => {
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32))
+            }
+            FunctionIndex::RemoveContractUserGroupURefsIndex => {
+                Signature::new(&[ValueType::I32; 6][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetSeedIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::UpgradeContractIndex => {
+                Signature::new(&[ValueType::I32; 10][..], Some(ValueType::I32))
+            }
+            FunctionIndex::TransferFromPurseToAccountWithPolicyIndex => {
+                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32))
+            }
+            FunctionIndex::ReadContractValueIndex => {
+                Signature::new(&[ValueType::I32; 5][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetRemainingGasIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::RevertWithMessageIndex => Signature::new(&[ValueType::I32; 3][..], None),
+            FunctionIndex::FinishEarlyIndex => Signature::new(&[][..], None),
+            FunctionIndex::GetArgSizeIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetArgIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetContractPackageIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            FunctionIndex::GetActionThresholdsIndex => {
+                Signature::new(&[ValueType::I32; 1][..], None)
+            }
+            FunctionIndex::GetTotalWeightIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::AcquireLockIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::ReleaseLockIndex => Signature::new(&[ValueType::I32; 2][..], None),
+            FunctionIndex::GetApprovalCountIndex => Signature::new(&[ValueType::I32; 1][..], None),
+            FunctionIndex::ListURefsIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::LoadRuntimeArgsIndex => {
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32))
+            }
+            FunctionIndex::ReadValuesIndex => {
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32))
+            }
+            #[cfg(feature = "test-support")]
+            FunctionIndex::PrintIndex => Signature::new(&[ValueType::I32; 2][..], None),
         };
-        Ok(func_ref)
+        Ok(FuncInstance::alloc_host(signature, function_index.into()))
     }
 
     fn resolve_memory(
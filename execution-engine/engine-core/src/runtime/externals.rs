@@ -14,7 +14,9 @@ use engine_shared::{gas::Gas, stored_value::StoredValue};
 use engine_storage::global_state::StateReader;
 
 use super::{args::Args, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
-use crate::resolvers::v1_function_index::FunctionIndex;
+use crate::{
+    engine_state::execution_effect::HostCallRecord, resolvers::v1_function_index::FunctionIndex,
+};
 
 impl<'a, R> Externals for Runtime<'a, R>
 where
@@ -27,6 +29,37 @@ where
         args: RuntimeArgs,
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
+        // `gas` is called for every metered block of code, so it's left out of the trace
+        if !self.config.enable_host_call_tracing() || func == FunctionIndex::GasFuncIndex {
+            return self.invoke_host_function(func, args);
+        }
+
+        let traced_args = format!("{:?}", args.as_ref());
+        let result = self.invoke_host_function(func, args);
+        let traced_result = match &result {
+            Ok(Some(value)) => format!("{:?}", value),
+            Ok(None) => String::from("()"),
+            Err(trap) => format!("{:?}", trap),
+        };
+        self.context.record_host_call(HostCallRecord {
+            name: func.import_name(),
+            args: traced_args,
+            result: traced_result,
+        });
+        result
+    }
+}
+
+impl<'a, R> Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<Error>,
+{
+    fn invoke_host_function(
+        &mut self,
+        func: FunctionIndex,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
         let mut scoped_instrumenter = ScopedInstrumenter::new(func);
         match func {
             FunctionIndex::ReadFuncIndex => {
@@ -667,6 +700,228 @@ where
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
+
+            FunctionIndex::GetSeedIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_seed(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::UpgradeContractIndex => {
+                // args(0) = pointer to package key in wasm memory
+                // args(1) = size of package key in wasm memory
+                // args(2) = pointer to where the new contract version is written
+                // args(3) = pointer to entrypoints in wasm memory
+                // args(4) = size of entrypoints in wasm memory
+                // args(5) = pointer to named keys in wasm memory
+                // args(6) = size of named keys in wasm memory
+                // args(7) = pointer to output buffer for serialized key
+                // args(8) = size of output buffer
+                // args(9) = pointer to bytes written
+                let (
+                    contract_package_hash_ptr,
+                    contract_package_hash_size,
+                    version_ptr,
+                    entry_points_ptr,
+                    entry_points_size,
+                    named_keys_ptr,
+                    named_keys_size,
+                    output_ptr,
+                    output_size,
+                    bytes_written_ptr,
+                ): (u32, u32, u32, u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+
+                scoped_instrumenter
+                    .add_property("entry_points_size", entry_points_size.to_string());
+                scoped_instrumenter.add_property("named_keys_size", named_keys_size.to_string());
+
+                let contract_package_hash: ContractPackageHash =
+                    self.t_from_mem(contract_package_hash_ptr, contract_package_hash_size)?;
+                let entry_points: EntryPoints =
+                    self.t_from_mem(entry_points_ptr, entry_points_size)?;
+                let named_keys: NamedKeys = self.t_from_mem(named_keys_ptr, named_keys_size)?;
+                let ret = self.upgrade_contract(
+                    contract_package_hash,
+                    entry_points,
+                    named_keys,
+                    output_ptr,
+                    output_size as usize,
+                    bytes_written_ptr,
+                    version_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::TransferFromPurseToAccountWithPolicyIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of a source purse
+                // args(1) = length of array of bytes in Wasm memory of a source purse
+                // args(2) = pointer to array of bytes in Wasm memory of an account hash
+                // args(3) = length of array of bytes in Wasm memory of an account hash
+                // args(4) = pointer to array of bytes in Wasm memory of an amount
+                // args(5) = length of array of bytes in Wasm memory of an amount
+                // args(6) = non-zero if a missing target account should be created
+                let (
+                    source_ptr,
+                    source_size,
+                    key_ptr,
+                    key_size,
+                    amount_ptr,
+                    amount_size,
+                    create_if_missing,
+                ): (u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+
+                let source_purse = {
+                    let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+                let account_hash: AccountHash = {
+                    let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+                let amount: U512 = {
+                    let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+                let ret = self.transfer_from_purse_to_account_with_policy(
+                    source_purse,
+                    account_hash,
+                    amount,
+                    create_if_missing != 0,
+                )?;
+                Ok(Some(RuntimeValue::I32(TransferredTo::i32_from(ret))))
+            }
+
+            FunctionIndex::ReadContractValueIndex => {
+                // args(0) = pointer to contract hash in Wasm memory
+                // args(1) = size of contract hash in Wasm memory
+                // args(2) = pointer to name of the contract's named key in Wasm memory
+                // args(3) = size of name in Wasm memory
+                // args(4) = pointer to output size (output param)
+                let (contract_ptr, contract_size, name_ptr, name_size, output_size_ptr) =
+                    Args::parse(args)?;
+                scoped_instrumenter.add_property("name_size", name_size);
+                let ret = self.read_contract_value(
+                    contract_ptr,
+                    contract_size,
+                    name_ptr,
+                    name_size,
+                    output_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetRemainingGasIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_remaining_gas(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::RevertWithMessageIndex => {
+                // args(0) = status u32
+                // args(1) = pointer to serialized message in Wasm memory
+                // args(2) = size of serialized message
+                let (status, message_ptr, message_size): (u32, u32, u32) = Args::parse(args)?;
+                scoped_instrumenter.add_property("message_size", message_size);
+                Err(self.revert_with_message(status, message_ptr, message_size))
+            }
+
+            FunctionIndex::FinishEarlyIndex => {
+                // no args
+                Err(Error::FinishEarly.into())
+            }
+
+            FunctionIndex::GetArgSizeIndex => {
+                // args(0) = index of host runtime arg to load
+                // args(1) = pointer to a argument size (output)
+                let (index, size_ptr): (u32, u32) = Args::parse(args)?;
+                let ret = self.get_arg_size(index as usize, size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetArgIndex => {
+                // args(0) = index of host runtime arg to load
+                // args(1) = pointer to output pointer where host will write argument bytes
+                // args(2) = size of available data under output pointer
+                let (index, dest_ptr, dest_size): (u32, u32, u32) = Args::parse(args)?;
+                scoped_instrumenter.add_property("dest_size", dest_size.to_string());
+                let ret = self.get_arg(index as usize, dest_ptr, dest_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetContractPackageIndex => {
+                // args(0) = pointer to contract package hash in Wasm memory
+                // args(1) = size of contract package hash in Wasm memory
+                // args(2) = pointer to output size (output param)
+                let (package_ptr, package_size, output_size_ptr) = Args::parse(args)?;
+                let ret = self.get_contract_package(package_ptr, package_size, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetActionThresholdsIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_action_thresholds(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetTotalWeightIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_total_weight(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::AcquireLockIndex => {
+                // args(0) = pointer to lock name in Wasm memory
+                // args(1) = size of lock name
+                let (name_ptr, name_size): (_, u32) = Args::parse(args)?;
+                scoped_instrumenter.add_property("name_size", name_size);
+                let result = self.acquire_lock(name_ptr, name_size)?;
+                Ok(Some(RuntimeValue::I32(result)))
+            }
+
+            FunctionIndex::ReleaseLockIndex => {
+                // args(0) = pointer to lock name in Wasm memory
+                // args(1) = size of lock name
+                let (name_ptr, name_size): (_, u32) = Args::parse(args)?;
+                scoped_instrumenter.add_property("name_size", name_size);
+                self.release_lock(name_ptr, name_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetApprovalCountIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_approval_count(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::ListURefsIndex => {
+                // args(0) = pointer to amount of urefs (output)
+                // args(1) = pointer to amount of serialized bytes (output)
+                let (total_urefs_ptr, result_size_ptr) = Args::parse(args)?;
+                let ret = self.list_urefs(total_urefs_ptr, result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::LoadRuntimeArgsIndex => {
+                // args(0) = pointer to amount of args (output)
+                // args(1) = pointer to amount of serialized bytes (output)
+                let (total_args_ptr, result_size_ptr) = Args::parse(args)?;
+                let ret = self.load_runtime_args(total_args_ptr, result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::ReadValuesIndex => {
+                // args(0) = pointer to serialized keys in Wasm memory
+                // args(1) = size of serialized keys in Wasm memory
+                // args(2) = pointer to output size (output param)
+                let (keys_ptr, keys_size, output_size_ptr) = Args::parse(args)?;
+                let ret = self.read_values(keys_ptr, keys_size, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
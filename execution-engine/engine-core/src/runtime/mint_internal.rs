@@ -23,6 +23,10 @@ where
         // TODO: update RuntimeProvider to better handle errors
         self.put_key(name.to_string(), key).expect("should put key")
     }
+
+    fn has_key(&self, name: &str) -> bool {
+        self.named_keys_contains_key(name)
+    }
 }
 
 // TODO: update Mint + StorageProvider to better handle errors
@@ -35,7 +35,9 @@ use types::{
 };
 
 use crate::{
-    engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
+    engine_state::{
+        execution_effect::TransferRecord, system_contract_cache::SystemContractCache, EngineConfig,
+    },
     execution::Error,
     resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
     runtime_context::{self, RuntimeContext},
@@ -137,6 +139,33 @@ pub fn extract_access_rights_from_keys<I: IntoIterator<Item = Key>>(
         .collect()
 }
 
+/// Returns `true` if `error` is the trap raised by a call to `runtime::finish_early()`.
+pub fn is_finish_early(error: &wasmi::Error) -> bool {
+    error
+        .as_host_error()
+        .and_then(|host_error| host_error.downcast_ref::<Error>())
+        .map_or(false, |error| matches!(error, Error::FinishEarly))
+}
+
+/// Checks that the named keys given for a new contract version can be merged with those of the
+/// previous version.
+///
+/// A name present in both is only allowed if it maps to the same key in each, otherwise
+/// `Error::DuplicateNamedKey` is returned for the first such name.
+fn check_named_keys_mergeable(
+    named_keys: &NamedKeys,
+    previous_named_keys: &NamedKeys,
+) -> Result<(), Error> {
+    match named_keys.iter().find(|(name, key)| {
+        previous_named_keys
+            .get(*name)
+            .map_or(false, |previous_key| previous_key != *key)
+    }) {
+        Some((name, _)) => Err(Error::DuplicateNamedKey(name.clone())),
+        None => Ok(()),
+    }
+}
+
 #[allow(clippy::cognitive_complexity)]
 fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
     match cl_value.cl_type() {
@@ -1526,6 +1555,23 @@ where
         }
     }
 
+    /// Tries to take the reentrancy lock whose name is read from the Wasm memory.  Returns `0` if
+    /// the lock was acquired and `1` if it was already held.
+    fn acquire_lock(&mut self, name_ptr: u32, name_size: u32) -> Result<i32, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        if self.context.acquire_lock(&name) {
+            Ok(0)
+        } else {
+            Ok(1)
+        }
+    }
+
+    fn release_lock(&mut self, name_ptr: u32, name_size: u32) -> Result<(), Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        self.context.release_lock(&name);
+        Ok(())
+    }
+
     fn put_key(
         &mut self,
         name_ptr: u32,
@@ -1553,6 +1599,30 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes the deployment and key management thresholds of the deploy account to [dest_ptr] in
+    /// the Wasm memory.
+    fn get_action_thresholds(&mut self, dest_ptr: u32) -> Result<(), Trap> {
+        let action_thresholds = self.context.account().action_thresholds();
+        let bytes = [
+            action_thresholds.deployment().value(),
+            action_thresholds.key_management().value(),
+        ];
+        self.memory
+            .set(dest_ptr, &bytes)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
+    /// Writes the total weight of the deploy's authorization keys to [dest_ptr] in the Wasm memory.
+    fn get_total_weight(&mut self, dest_ptr: u32) -> Result<(), Trap> {
+        let total_weight = self
+            .context
+            .account()
+            .authorization_keys_weight(self.context.authorization_keys());
+        self.memory
+            .set(dest_ptr, &[total_weight.value()])
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
     /// Writes caller (deploy) account public key to [dest_ptr] in the Wasm
     /// memory.
     fn get_caller(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
@@ -1597,6 +1667,121 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes the gas remaining for the current execution as a `u64` to [dest_ptr] in the Wasm
+    /// memory, saturating at `u64::MAX`.
+    fn get_remaining_gas(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let remaining_gas = self
+            .context
+            .gas_limit()
+            .value()
+            .saturating_sub(self.context.gas_counter().value());
+        let remaining_gas = if remaining_gas > U512::from(u64::max_value()) {
+            u64::max_value()
+        } else {
+            remaining_gas.as_u64()
+        };
+        let remaining_gas_bytes = remaining_gas.into_bytes().map_err(Error::BytesRepr)?;
+        self.memory
+            .set(dest_ptr, &remaining_gas_bytes)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
+    /// Writes the number of approvals carried by the current deploy as a `u32` to [dest_ptr] in
+    /// the Wasm memory.
+    fn get_approval_count(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let approval_count = self.context.authorization_keys().len() as u32;
+        let approval_count_bytes = approval_count.into_bytes().map_err(Error::BytesRepr)?;
+        self.memory
+            .set(dest_ptr, &approval_count_bytes)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
+    /// Writes the number of URefs accessible in the current context to [total_urefs_ptr] and the
+    /// size of their serialized form to [result_size_ptr] in Wasm memory, leaving the serialized
+    /// URefs in the host buffer.  The host buffer is left empty if there are no URefs.
+    fn list_urefs(
+        &mut self,
+        total_urefs_ptr: u32,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let urefs = self.context.accessible_urefs();
+        let total_urefs = urefs.len() as u32;
+        let total_urefs_bytes = total_urefs.to_le_bytes();
+        if let Err(error) = self.memory.set(total_urefs_ptr, &total_urefs_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        if total_urefs == 0 {
+            // No need to do anything else, we leave host buffer empty.
+            return Ok(Ok(()));
+        }
+
+        let urefs = CLValue::from_t(urefs).map_err(Error::CLValue)?;
+        let length = urefs.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(urefs) {
+            return Ok(Err(error));
+        }
+
+        let length_bytes = length.to_le_bytes();
+        if let Err(error) = self.memory.set(result_size_ptr, &length_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the number of args passed to the current invocation to [total_args_ptr] and the size
+    /// of their serialized form to [result_size_ptr] in Wasm memory, leaving the serialized
+    /// `RuntimeArgs` in the host buffer.  The host buffer is left empty if there are no args.
+    fn load_runtime_args(
+        &mut self,
+        total_args_ptr: u32,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let total_args = self.context.args().len() as u32;
+        let total_args_bytes = total_args.to_le_bytes();
+        if let Err(error) = self.memory.set(total_args_ptr, &total_args_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        if total_args == 0 {
+            // No need to do anything else, we leave host buffer empty.
+            return Ok(Ok(()));
+        }
+
+        let args_bytes = self.context.args().to_bytes().map_err(Error::BytesRepr)?;
+        let length = args_bytes.len() as u32;
+        let cl_value = CLValue::from_components(CLType::Any, args_bytes);
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let length_bytes = length.to_le_bytes();
+        if let Err(error) = self.memory.set(result_size_ptr, &length_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes a deterministic, per-deploy seed to [dest_ptr] in Wasm memory.
+    fn get_seed(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let seed = self.context.get_seed()?;
+        self.memory
+            .set(dest_ptr, &seed)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
     /// Return some bytes from the memory and terminate the current `sub_call`. Note that the return
     /// type is `Trap`, indicating that this function will always kill the current Wasm instance.
     fn ret(
@@ -1676,6 +1861,7 @@ where
         const METHOD_CREATE: &str = "create";
         const METHOD_BALANCE: &str = "balance";
         const METHOD_TRANSFER: &str = "transfer";
+        const METHOD_SET_BALANCE_CAP: &str = "set_balance_cap";
 
         let state = self.context.state();
         let access_rights = {
@@ -1746,6 +1932,13 @@ where
                 let result: Result<(), mint::Error> = mint_context.transfer(source, target, amount);
                 CLValue::from_t(result).map_err(Self::reverter)?
             }
+            // Type: `fn set_balance_cap(purse: URef, cap: U512) -> Result<(), Error>`
+            METHOD_SET_BALANCE_CAP => {
+                let purse: URef = Self::get_named_argument(&runtime_args, "purse")?;
+                let cap: U512 = Self::get_named_argument(&runtime_args, "cap")?;
+                let result: Result<(), mint::Error> = mint_context.set_balance_cap(purse, cap);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1768,6 +1961,8 @@ where
         const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
         const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
         const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+        const METHOD_GET_VALIDATOR_SET: &str = "get_validator_set";
+        const METHOD_DISTRIBUTE: &str = "distribute";
         const ARG_AMOUNT: &str = "amount";
         const ARG_PURSE: &str = "purse";
 
@@ -1871,6 +2066,16 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_GET_VALIDATOR_SET => {
+                let validator_set = runtime.get_validator_set().map_err(Self::reverter)?;
+                CLValue::from_t(validator_set).map_err(Self::reverter)?
+            }
+            METHOD_DISTRIBUTE => {
+                let amounts: Vec<(AccountHash, U512)> =
+                    Self::get_named_argument(&runtime_args, "amounts")?;
+                runtime.distribute(amounts).map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1954,10 +2159,13 @@ where
         };
 
         // Get contract entry point hash
-        let contract_hash = contract_package
-            .lookup_contract_hash(contract_version_key)
-            .cloned()
-            .ok_or_else(|| Error::InvalidContractVersion(contract_version_key))?;
+        let contract_hash = match contract_package.versions().get(&contract_version_key) {
+            Some(contract_hash) if contract_package.is_version_enabled(contract_version_key) => {
+                *contract_hash
+            }
+            Some(_) => return Err(Error::VersionDisabled(contract_version_key)),
+            None => return Err(Error::NoSuchVersion(contract_version_key)),
+        };
 
         // Get contract data
         let contract = match self.context.read_gs(&contract_hash.into())? {
@@ -1974,7 +2182,7 @@ where
         let entry_point = contract
             .entry_point(&entry_point_name)
             .cloned()
-            .ok_or_else(|| Error::NoSuchMethod(entry_point_name.to_owned()))?;
+            .ok_or_else(|| Error::NoSuchEntryPoint(entry_point_name.to_owned()))?;
 
         self.validate_entry_point_access(&contract_package, entry_point.access())?;
 
@@ -2128,7 +2336,9 @@ where
 
         let host_buffer = None;
 
-        let context = RuntimeContext::new(
+        let main_purse_cache = self.context.main_purse_cache_for(base_key);
+
+        let mut context = RuntimeContext::new(
             self.context.state(),
             entry_point.entry_point_type(),
             &mut named_keys,
@@ -2148,6 +2358,8 @@ where
             self.context.phase(),
             self.context.protocol_data(),
         );
+        context.set_main_purse_cache(main_purse_cache);
+        context.set_reentrancy_locks(self.context.reentrancy_locks());
 
         let mut runtime = Runtime {
             system_contract_cache,
@@ -2166,12 +2378,13 @@ where
         self.context.set_gas_counter(runtime.context.gas_counter());
 
         let error = match result {
-            Err(error) => error,
+            Err(error) if !is_finish_early(&error) => error,
             // If `Ok` and the `host_buffer` is `None`, the contract's execution succeeded but did
             // not explicitly call `runtime::ret()`.  Treat as though the execution
             // returned the unit type `()` as per Rust functions which don't specify a
-            // return value.
-            Ok(_) => {
+            // return value.  A call to `runtime::finish_early()` ends only the called contract, in
+            // the same way.
+            _ => {
                 if self.context.entry_point_type() == EntryPointType::Session
                     && runtime.context.entry_point_type() == EntryPointType::Session
                 {
@@ -2429,6 +2642,41 @@ where
         Ok(Ok(()))
     }
 
+    /// Adds a new version of a contract to the given package, like `add_contract_version`, but
+    /// returns [`ApiError::UpgradeNotAuthorized`] rather than trapping if the caller doesn't hold
+    /// the package's access key.
+    #[allow(clippy::too_many_arguments)]
+    fn upgrade_contract(
+        &mut self,
+        contract_package_hash: ContractPackageHash,
+        entry_points: EntryPoints,
+        named_keys: NamedKeys,
+        output_ptr: u32,
+        output_size: usize,
+        bytes_written_ptr: u32,
+        version_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let contract_package: ContractPackage =
+            self.context.read_gs_typed(&contract_package_hash.into())?;
+        if self
+            .context
+            .validate_uref(&contract_package.access_key())
+            .is_err()
+        {
+            return Ok(Err(ApiError::UpgradeNotAuthorized));
+        }
+
+        self.add_contract_version(
+            contract_package_hash,
+            entry_points,
+            named_keys,
+            output_ptr,
+            output_size,
+            bytes_written_ptr,
+            version_ptr,
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn add_contract_version(
         &mut self,
@@ -2466,6 +2714,7 @@ where
                 self.context.read_gs_typed(&previous_contract_hash.into())?;
 
             let mut previous_named_keys = previous_contract.take_named_keys();
+            check_named_keys_mergeable(&named_keys, &previous_named_keys)?;
             named_keys.append(&mut previous_named_keys);
         }
 
@@ -2651,6 +2900,139 @@ where
         Ok(Ok(()))
     }
 
+    /// Reads the values under each of the serialized `Vec<Key>` at `keys_ptr` and writes them to
+    /// the host buffer as a `Vec<Option<Vec<u8>>>`, with `None` for each key holding no value.
+    fn read_values(
+        &mut self,
+        keys_ptr: u32,
+        keys_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let keys: Vec<Key> = self.t_from_mem(keys_ptr, keys_size)?;
+        let mut values: Vec<Option<Vec<u8>>> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let value = match self.context.read_gs(&key)? {
+                Some(stored_value) => {
+                    let cl_value = CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?;
+                    Some(cl_value.destructure().1)
+                }
+                None => None,
+            };
+            values.push(value);
+        }
+
+        let values_bytes = values.to_bytes().map_err(Error::BytesRepr)?;
+        let values_size = values_bytes.len() as u32;
+        let cl_value = CLValue::from_components(CLType::Any, values_bytes);
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let values_size_bytes = values_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &values_size_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Reads the value stored under the named key `name` of the contract under `contract_hash` and
+    /// writes it to the host buffer.  If either the contract or the named key doesn't exist, the
+    /// result is `ApiError::ValueNotFound`.
+    fn read_contract_value(
+        &mut self,
+        contract_ptr: u32,
+        contract_size: u32,
+        name_ptr: u32,
+        name_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let contract_hash: ContractHash = {
+            let bytes = self.bytes_from_mem(contract_ptr, contract_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        let name = self.string_from_mem(name_ptr, name_size)?;
+
+        let cl_value = match self.context.read_contract_value(contract_hash, &name)? {
+            Some(cl_value) => cl_value,
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size = cl_value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the contract package under `package_ptr` to the host buffer, with all access rights
+    /// removed from its URefs so that the copy doesn't grant the caller anything.
+    fn get_contract_package(
+        &mut self,
+        package_ptr: u32,
+        package_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let package_hash: ContractPackageHash = {
+            let bytes = self.bytes_from_mem(package_ptr, package_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let package = match self.context.read_gs(&Key::from(package_hash))? {
+            Some(StoredValue::ContractPackage(package)) => package,
+            _ => return Ok(Err(ApiError::ValueNotFound)),
+        };
+        let groups = package
+            .groups()
+            .iter()
+            .map(|(group, urefs)| {
+                let urefs = urefs.iter().map(|uref| uref.remove_access_rights());
+                (group.clone(), urefs.collect())
+            })
+            .collect();
+        let package = ContractPackage::new(
+            package.access_key().remove_access_rights(),
+            package.versions().clone(),
+            package.disabled_versions().clone(),
+            groups,
+        );
+
+        let package_bytes = package.into_bytes().map_err(Error::BytesRepr)?;
+        let value_size = package_bytes.len() as u32;
+        let cl_value = CLValue::from_components(CLType::Any, package_bytes);
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     /// Similar to `read`, this function is for reading from the "local cluster"
     /// of global state
     fn read_local(
@@ -2689,6 +3071,18 @@ where
         Error::Revert(status.into()).into()
     }
 
+    /// Reverts contract execution with a status specified, recording the given message.
+    fn revert_with_message(&mut self, status: u32, message_ptr: u32, message_size: u32) -> Trap {
+        match self.string_from_mem(message_ptr, message_size) {
+            Ok(message) => Error::RevertWithMessage {
+                error: status.into(),
+                message,
+            }
+            .into(),
+            Err(trap) => trap,
+        }
+    }
+
     fn add_associated_key(
         &mut self,
         account_hash_ptr: u32,
@@ -2830,15 +3224,39 @@ where
         const ARG_TARGET: &str = "target";
         const ARG_AMOUNT: &str = "amount";
 
+        // reject the sentinel purse up front rather than treating it as a forged reference
+        if source.is_sentinel() {
+            return Err(system_contract_errors::Error::from(mint::Error::SourceNotFound).into());
+        }
+        if target.is_sentinel() {
+            return Err(system_contract_errors::Error::from(mint::Error::DestNotFound).into());
+        }
+
         let args_values: RuntimeArgs = runtime_args! {
             ARG_SOURCE => source,
             ARG_TARGET => target,
             ARG_AMOUNT => amount,
         };
 
+        let source_balance_before = self.context.peek_balance(source)?;
+        let target_balance_before = self.context.peek_balance(target)?;
+
         let result = self.call_contract(mint_contract_hash, "transfer", args_values)?;
         let result: Result<(), mint::Error> = result.into_t()?;
-        Ok(result.map_err(system_contract_errors::Error::from)?)
+        result.map_err(system_contract_errors::Error::from)?;
+
+        let transfer = TransferRecord {
+            source,
+            target,
+            amount,
+            source_balance_before,
+            source_balance_after: self.context.peek_balance(source)?,
+            target_balance_before,
+            target_balance_after: self.context.peek_balance(target)?,
+        };
+        self.context.record_transfer(transfer);
+
+        Ok(())
     }
 
     /// Creates a new account at a given public key, transferring a given amount
@@ -2937,6 +3355,22 @@ where
         }
     }
 
+    /// Transfers `amount` of motes from `source` purse to `target` account.  If `target` does not
+    /// exist, it is only created if `create_if_missing` is set, otherwise the transfer fails with
+    /// [`ApiError::TargetAccountMissing`].
+    fn transfer_from_purse_to_account_with_policy(
+        &mut self,
+        source: URef,
+        target: AccountHash,
+        amount: U512,
+        create_if_missing: bool,
+    ) -> Result<TransferResult, Error> {
+        if !create_if_missing && self.context.read_account(&Key::Account(target))?.is_none() {
+            return Ok(Err(ApiError::TargetAccountMissing));
+        }
+        self.transfer_from_purse_to_account(source, target, amount)
+    }
+
     /// Transfers `amount` of motes from `source` purse to `target` purse.
     fn transfer_from_purse_to_purse(
         &mut self,
@@ -2962,6 +3396,14 @@ where
             bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
         };
 
+        if source.is_sentinel() || target.is_sentinel() {
+            return Ok(Err(ApiError::Transfer));
+        }
+
+        if !target.is_addable() {
+            return Ok(Err(ApiError::TargetNotWritable));
+        }
+
         let mint_contract_key = self.get_mint_contract();
 
         if self
@@ -3024,6 +3466,12 @@ where
             }
         };
 
+        // The caller has to hold a readable reference to the purse, otherwise it could learn the
+        // balance of any purse whose address it happens to know.
+        if !purse.is_readable() || self.context.validate_uref(&purse).is_err() {
+            return Ok(Err(ApiError::InvalidPurse));
+        }
+
         let balance = match self.get_balance(purse)? {
             Some(balance) => balance,
             None => return Ok(Err(ApiError::InvalidPurse)),
@@ -3175,10 +3623,47 @@ where
             return Ok(Err(ApiError::OutOfMemory));
         }
 
-        if let Err(e) = self
-            .memory
-            .set(output_ptr, &arg.inner_bytes()[..output_size])
-        {
+        if let Err(e) = self.memory.set(output_ptr, arg.inner_bytes()) {
+            return Err(Error::Interpreter(e.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn get_arg_size(&mut self, index: usize, size_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
+        let arg_size = match self.context.args().get_by_index(index) {
+            Some(arg) if arg.inner_bytes().len() > u32::max_value() as usize => {
+                return Ok(Err(ApiError::OutOfMemory));
+            }
+            Some(arg) => arg.inner_bytes().len() as u32,
+            None => return Ok(Err(ApiError::MissingArgument)),
+        };
+
+        let arg_size_bytes = arg_size.to_le_bytes(); // Wasm is little-endian
+
+        if let Err(e) = self.memory.set(size_ptr, &arg_size_bytes) {
+            return Err(Error::Interpreter(e.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn get_arg(
+        &mut self,
+        index: usize,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let arg = match self.context.args().get_by_index(index) {
+            Some(arg) => arg,
+            None => return Ok(Err(ApiError::MissingArgument)),
+        };
+
+        if arg.inner_bytes().len() > output_size {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        if let Err(e) = self.memory.set(output_ptr, arg.inner_bytes()) {
             return Err(Error::Interpreter(e.into()).into());
         }
 
@@ -3368,9 +3853,9 @@ mod tests {
         result,
     };
 
-    use types::{gens::*, CLType, CLValue, Key, URef};
+    use types::{contracts::NamedKeys, gens::*, AccessRights, CLType, CLValue, Key, URef};
 
-    use super::extract_urefs;
+    use super::{check_named_keys_mergeable, extract_urefs, Error};
 
     fn cl_value_with_urefs_arb() -> impl Strategy<Value = (CLValue, Vec<URef>)> {
         // If compiler brings you here it most probably means you've added a variant to `CLType`
@@ -3512,4 +3997,31 @@ mod tests {
             assert_eq!(extracted_urefs, urefs);
         }
     }
+
+    fn named_keys(entries: &[(&str, Key)]) -> NamedKeys {
+        entries
+            .iter()
+            .map(|(name, key)| (name.to_string(), *key))
+            .collect()
+    }
+
+    #[test]
+    fn should_merge_named_keys_without_conflicts() {
+        let uref = Key::URef(URef::new([1; 32], AccessRights::READ));
+        let previous = named_keys(&[("a", Key::Hash([2; 32])), ("shared", uref)]);
+        let new = named_keys(&[("b", Key::Hash([3; 32])), ("shared", uref)]);
+        assert!(check_named_keys_mergeable(&new, &previous).is_ok());
+        assert!(check_named_keys_mergeable(&NamedKeys::new(), &previous).is_ok());
+        assert!(check_named_keys_mergeable(&new, &NamedKeys::new()).is_ok());
+    }
+
+    #[test]
+    fn should_reject_conflicting_named_keys() {
+        let previous = named_keys(&[("a", Key::Hash([2; 32])), ("b", Key::Hash([3; 32]))]);
+        let new = named_keys(&[("b", Key::Hash([4; 32])), ("c", Key::Hash([5; 32]))]);
+        match check_named_keys_mergeable(&new, &previous) {
+            Err(Error::DuplicateNamedKey(name)) => assert_eq!(name, "b"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
 }
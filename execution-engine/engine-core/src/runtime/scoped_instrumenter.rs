@@ -139,6 +139,26 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::RemoveContractUserGroupURefsIndex => {
                 "host_remove_contract_user_group_urefs"
             }
+            FunctionIndex::GetSeedIndex => "host_function_get_seed",
+            FunctionIndex::UpgradeContractIndex => "host_function_upgrade_contract",
+            FunctionIndex::TransferFromPurseToAccountWithPolicyIndex => {
+                "host_function_transfer_from_purse_to_account_with_policy"
+            }
+            FunctionIndex::ReadContractValueIndex => "host_function_read_contract_value",
+            FunctionIndex::GetRemainingGasIndex => "host_function_get_remaining_gas",
+            FunctionIndex::RevertWithMessageIndex => "host_function_revert_with_message",
+            FunctionIndex::FinishEarlyIndex => "host_function_finish_early",
+            FunctionIndex::GetArgSizeIndex => "host_get_arg_size",
+            FunctionIndex::GetArgIndex => "host_get_arg",
+            FunctionIndex::GetContractPackageIndex => "host_get_contract_package",
+            FunctionIndex::GetActionThresholdsIndex => "host_get_action_thresholds",
+            FunctionIndex::GetTotalWeightIndex => "host_get_total_weight",
+            FunctionIndex::AcquireLockIndex => "host_acquire_lock",
+            FunctionIndex::ReleaseLockIndex => "host_release_lock",
+            FunctionIndex::GetApprovalCountIndex => "host_function_get_approval_count",
+            FunctionIndex::ListURefsIndex => "host_function_list_urefs",
+            FunctionIndex::LoadRuntimeArgsIndex => "host_function_load_runtime_args",
+            FunctionIndex::ReadValuesIndex => "host_function_read_values",
         };
 
         let mut properties = mem::take(&mut self.properties);
@@ -1,6 +1,6 @@
 use std::{
-    cell::RefCell,
-    collections::{BTreeSet, HashMap, HashSet},
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     convert::{TryFrom, TryInto},
     fmt::Debug,
     rc::Rc,
@@ -20,15 +20,15 @@ use types::{
         AccountHash, ActionType, AddKeyFailure, RemoveKeyFailure, SetThresholdFailure,
         UpdateKeyFailure, Weight,
     },
-    bytesrepr,
+    bytesrepr::{self, ToBytes},
     contracts::NamedKeys,
-    AccessRights, BlockTime, CLType, CLValue, Contract, ContractPackage, ContractPackageHash,
-    EntryPointAccess, EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
-    KEY_HASH_LENGTH,
+    AccessRights, BlockTime, CLType, CLValue, Contract, ContractHash, ContractPackage,
+    ContractPackageHash, EntryPointAccess, EntryPointType, Key, Phase, ProtocolVersion,
+    RuntimeArgs, URef, KEY_HASH_LENGTH, U512,
 };
 
 use crate::{
-    engine_state::execution_effect::ExecutionEffect,
+    engine_state::execution_effect::{ExecutionEffect, HostCallRecord, TransferRecord},
     execution::{AddressGenerator, Error},
     tracking_copy::{AddResult, TrackingCopy},
     Address,
@@ -81,6 +81,53 @@ pub fn validate_entry_point_access_with(
     Ok(())
 }
 
+/// The resolved main purse of the account a context runs under.
+///
+/// A context shares its cache with the contexts of its sub-calls which run under the same base
+/// key, so that the main purse is only resolved once across them.
+#[derive(Clone, Default, Debug)]
+pub struct MainPurseCache {
+    purse: Rc<Cell<Option<URef>>>,
+    resolutions: Rc<Cell<usize>>,
+}
+
+impl MainPurseCache {
+    fn get_or_resolve(&self, resolve: impl FnOnce() -> URef) -> URef {
+        if let Some(purse) = self.purse.get() {
+            return purse;
+        }
+        let purse = resolve();
+        self.resolutions.set(self.resolutions.get() + 1);
+        self.purse.set(Some(purse));
+        purse
+    }
+
+    /// Returns the number of times the main purse had to be resolved.
+    pub fn resolutions(&self) -> usize {
+        self.resolutions.get()
+    }
+}
+
+/// Named reentrancy locks held by contracts during an execution.
+///
+/// A context shares its locks with the contexts of all of its sub-calls.  Locks are transient, in
+/// that they are never written to global state, and are namespaced by the base key of the context
+/// which holds them.
+#[derive(Clone, Default, Debug)]
+pub struct ReentrancyLocks {
+    held: Rc<RefCell<BTreeSet<(Key, String)>>>,
+}
+
+impl ReentrancyLocks {
+    fn acquire(&self, base_key: Key, name: &str) -> bool {
+        self.held.borrow_mut().insert((base_key, name.to_string()))
+    }
+
+    fn release(&self, base_key: Key, name: &str) {
+        self.held.borrow_mut().remove(&(base_key, name.to_string()));
+    }
+}
+
 /// Holds information specific to the deployed contract.
 pub struct RuntimeContext<'a, R> {
     tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
@@ -106,6 +153,8 @@ pub struct RuntimeContext<'a, R> {
     phase: Phase,
     protocol_data: ProtocolData,
     entry_point_type: EntryPointType,
+    main_purse_cache: MainPurseCache,
+    reentrancy_locks: ReentrancyLocks,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -153,6 +202,8 @@ where
             correlation_id,
             phase,
             protocol_data,
+            main_purse_cache: MainPurseCache::default(),
+            reentrancy_locks: ReentrancyLocks::default(),
         }
     }
 
@@ -297,6 +348,20 @@ where
         self.phase
     }
 
+    /// Returns a deterministic seed derived from the deploy hash, the current phase and the key of
+    /// the entity currently being executed.
+    pub fn get_seed(&self) -> Result<[u8; KEY_HASH_LENGTH], Error> {
+        let mut pre_hash_bytes = self.deploy_hash.to_vec();
+        pre_hash_bytes.push(self.phase as u8);
+        pre_hash_bytes.append(&mut self.base_key.to_bytes()?);
+
+        let mut hasher = VarBlake2b::new(KEY_HASH_LENGTH).unwrap();
+        hasher.input(&pre_hash_bytes);
+        let mut seed = [0; KEY_HASH_LENGTH];
+        hasher.variable_result(|hash| seed.clone_from_slice(hash));
+        Ok(seed)
+    }
+
     /// Generates new deterministic hash for uses as an address.
     pub fn new_hash_address(&mut self) -> Result<[u8; KEY_HASH_LENGTH], Error> {
         let pre_hash_bytes = self.hash_address_generator.borrow_mut().create_address();
@@ -392,6 +457,33 @@ where
             .map_err(Into::into)
     }
 
+    /// Reads the value stored under the named key `name` of the contract under `contract_hash`.
+    ///
+    /// The value is read via [`RuntimeContext::read_gs`], so if the named key is a `URef`, the
+    /// current context must hold it with read access.
+    pub fn read_contract_value(
+        &mut self,
+        contract_hash: ContractHash,
+        name: &str,
+    ) -> Result<Option<CLValue>, Error> {
+        let contract: Contract = match self.read_gs(&Key::Hash(contract_hash))? {
+            Some(stored_value) => stored_value.try_into().map_err(Error::TypeMismatch)?,
+            None => return Ok(None),
+        };
+
+        let key = match contract.named_keys().get(name) {
+            Some(key) => *key,
+            None => return Ok(None),
+        };
+
+        match self.read_gs(&key)? {
+            Some(stored_value) => Ok(Some(
+                CLValue::try_from(stored_value).map_err(Error::TypeMismatch)?,
+            )),
+            None => Ok(None),
+        }
+    }
+
     /// This method is a wrapper over `read_gs` in the sense that it extracts the type held by a
     /// `StoredValue` stored in the global state in a type safe manner.
     ///
@@ -480,10 +572,64 @@ where
         entry.insert(rights);
     }
 
+    /// Returns every URef accessible in the current context, including the account's main purse.
+    ///
+    /// Each address appears once, carrying the union of the access rights known for it.
+    pub fn accessible_urefs(&self) -> Vec<URef> {
+        let mut rights_by_addr: BTreeMap<Address, AccessRights> = BTreeMap::new();
+        for (addr, known_rights) in &self.access_rights {
+            let rights = rights_by_addr.entry(*addr).or_insert(AccessRights::NONE);
+            for known_right in known_rights {
+                *rights |= *known_right;
+            }
+        }
+        let main_purse = self.account.main_purse();
+        *rights_by_addr
+            .entry(main_purse.addr())
+            .or_insert(AccessRights::NONE) |= main_purse.access_rights();
+
+        rights_by_addr
+            .into_iter()
+            .map(|(addr, rights)| URef::new(addr, rights))
+            .collect()
+    }
+
     pub fn effect(&self) -> ExecutionEffect {
         self.tracking_copy.borrow_mut().effect()
     }
 
+    pub fn record_transfer(&mut self, transfer: TransferRecord) {
+        self.tracking_copy.borrow_mut().record_transfer(transfer)
+    }
+
+    pub fn record_host_call(&mut self, host_call: HostCallRecord) {
+        self.tracking_copy.borrow_mut().record_host_call(host_call)
+    }
+
+    pub fn host_calls(&self) -> Vec<HostCallRecord> {
+        self.tracking_copy.borrow().host_calls().to_vec()
+    }
+
+    /// Returns the balance of `purse`, or zero if it has none, without recording any reads in the
+    /// execution effect.
+    pub fn peek_balance(&mut self, purse: URef) -> Result<U512, Error> {
+        let mut tracking_copy = self.tracking_copy.borrow_mut();
+        let balance_key = match tracking_copy
+            .get(self.correlation_id, &Key::Hash(purse.addr()))
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::CLValue(cl_value)) => cl_value.into_t::<Key>()?,
+            _ => return Ok(U512::zero()),
+        };
+        match tracking_copy
+            .get(self.correlation_id, &balance_key.normalize())
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::CLValue(cl_value)) => Ok(cl_value.into_t()?),
+            _ => Ok(U512::zero()),
+        }
+    }
+
     /// Validates whether keys used in the `value` are not forged.
     fn validate_value(&self, value: &StoredValue) -> Result<(), Error> {
         match value {
@@ -835,7 +981,49 @@ where
         if !self.is_valid_context() {
             return Err(Error::InvalidContext);
         }
-        Ok(self.account().main_purse())
+        let account = self.account;
+        Ok(self
+            .main_purse_cache
+            .get_or_resolve(|| account.main_purse()))
+    }
+
+    /// Returns the main purse cache to be used by a sub-call context running under `base_key`.
+    ///
+    /// The cache is only shared if the sub-call runs under the same base key as this context, as
+    /// otherwise the resolved main purse doesn't apply to it.
+    pub fn main_purse_cache_for(&self, base_key: Key) -> MainPurseCache {
+        if base_key == self.base_key {
+            self.main_purse_cache.clone()
+        } else {
+            MainPurseCache::default()
+        }
+    }
+
+    pub fn set_main_purse_cache(&mut self, main_purse_cache: MainPurseCache) {
+        self.main_purse_cache = main_purse_cache;
+    }
+
+    pub fn main_purse_cache(&self) -> &MainPurseCache {
+        &self.main_purse_cache
+    }
+
+    /// Tries to take the reentrancy lock `name` for the current base key.  Returns `false` if it
+    /// is already held by this context or one of its callers.
+    pub fn acquire_lock(&self, name: &str) -> bool {
+        self.reentrancy_locks.acquire(self.base_key, name)
+    }
+
+    /// Releases the reentrancy lock `name` for the current base key if it is held.
+    pub fn release_lock(&self, name: &str) {
+        self.reentrancy_locks.release(self.base_key, name)
+    }
+
+    pub fn reentrancy_locks(&self) -> ReentrancyLocks {
+        self.reentrancy_locks.clone()
+    }
+
+    pub fn set_reentrancy_locks(&mut self, reentrancy_locks: ReentrancyLocks) {
+        self.reentrancy_locks = reentrancy_locks;
     }
 
     /// Gets entry point type.
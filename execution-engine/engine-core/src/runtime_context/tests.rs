@@ -831,3 +831,126 @@ fn validate_valid_purse_of_an_account() {
     let purse = URef::new([53; 32], AccessRights::READ_ADD_WRITE);
     assert!(runtime_context.validate_uref(&purse).is_err());
 }
+
+#[test]
+fn should_share_resolved_main_purse_with_sub_call_under_same_base_key() {
+    let mock_purse = [42u8; 32];
+    let deploy_hash = [1u8; 32];
+    let (base_key, account) = mock_account_with_purse(AccountHash::new([0u8; 32]), mock_purse);
+
+    let mut named_keys = NamedKeys::new();
+    let runtime_context = mock_runtime_context(
+        &account,
+        base_key,
+        &mut named_keys,
+        HashMap::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+    );
+    let main_purse = runtime_context
+        .get_main_purse()
+        .expect("should get main purse");
+    assert_eq!(main_purse.addr(), mock_purse);
+    assert_eq!(runtime_context.main_purse_cache().resolutions(), 1);
+
+    // A sub-call running under the same base key reuses the resolved main purse.
+    let mut sub_call_named_keys = NamedKeys::new();
+    let mut sub_call_context = mock_runtime_context(
+        &account,
+        base_key,
+        &mut sub_call_named_keys,
+        HashMap::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+    );
+    sub_call_context.set_main_purse_cache(runtime_context.main_purse_cache_for(base_key));
+    assert_eq!(sub_call_context.get_main_purse().unwrap(), main_purse);
+    assert_eq!(runtime_context.get_main_purse().unwrap(), main_purse);
+    assert_eq!(runtime_context.main_purse_cache().resolutions(), 1);
+
+    // A sub-call running under a different base key doesn't share the cache.
+    let mut rng = AddressGenerator::new(&deploy_hash, Phase::Session);
+    let contract_cache = runtime_context.main_purse_cache_for(random_contract_key(&mut rng));
+    assert_eq!(contract_cache.resolutions(), 0);
+}
+
+#[test]
+fn should_reject_reentrant_lock_acquisition_in_sub_call() {
+    const LOCK_NAME: &str = "withdraw";
+
+    let deploy_hash = [1u8; 32];
+    let (base_key, account) = mock_account(AccountHash::new([0u8; 32]));
+
+    let mut named_keys = NamedKeys::new();
+    let runtime_context = mock_runtime_context(
+        &account,
+        base_key,
+        &mut named_keys,
+        HashMap::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+    );
+    assert!(runtime_context.acquire_lock(LOCK_NAME));
+    assert!(!runtime_context.acquire_lock(LOCK_NAME));
+
+    // A sub-call re-entering the same base key sees the lock as held.
+    let mut reentrant_named_keys = NamedKeys::new();
+    let mut reentrant_context = mock_runtime_context(
+        &account,
+        base_key,
+        &mut reentrant_named_keys,
+        HashMap::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+    );
+    reentrant_context.set_reentrancy_locks(runtime_context.reentrancy_locks());
+    assert!(!reentrant_context.acquire_lock(LOCK_NAME));
+
+    // Locks are namespaced by base key, so other contracts may use the same name.
+    let mut rng = AddressGenerator::new(&deploy_hash, Phase::Session);
+    let mut other_named_keys = NamedKeys::new();
+    let mut other_context = mock_runtime_context(
+        &account,
+        random_contract_key(&mut rng),
+        &mut other_named_keys,
+        HashMap::new(),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+        AddressGenerator::new(&deploy_hash, Phase::Session),
+    );
+    other_context.set_reentrancy_locks(runtime_context.reentrancy_locks());
+    assert!(other_context.acquire_lock(LOCK_NAME));
+
+    runtime_context.release_lock(LOCK_NAME);
+    assert!(reentrant_context.acquire_lock(LOCK_NAME));
+}
+
+#[test]
+fn accessible_urefs_should_merge_rights_and_include_main_purse() {
+    let deploy_hash = [1u8; 32];
+    let mut rng = AddressGenerator::new(&deploy_hash, Phase::Session);
+    let readable = create_uref(&mut rng, AccessRights::READ);
+    let addable = Key::URef(
+        readable
+            .as_uref()
+            .unwrap()
+            .with_access_rights(AccessRights::ADD),
+    );
+    let writeable = create_uref(&mut rng, AccessRights::WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![readable, addable, writeable]);
+
+    let query_result = test(access_rights, |mut rc| {
+        let new_uref = rc.new_uref(StoredValue::CLValue(CLValue::from_t(()).unwrap()))?;
+        Ok((rc.accessible_urefs(), rc.account().main_purse(), new_uref))
+    });
+    let (urefs, main_purse, new_uref) = query_result.expect("should list urefs");
+
+    let merged = readable
+        .as_uref()
+        .unwrap()
+        .with_access_rights(AccessRights::READ_ADD);
+    assert_eq!(urefs.len(), 4);
+    assert!(urefs.contains(&merged));
+    assert!(urefs.contains(writeable.as_uref().unwrap()));
+    assert!(urefs.contains(&main_purse));
+    assert!(urefs.contains(&new_uref));
+}
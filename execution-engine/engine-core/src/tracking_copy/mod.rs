@@ -22,7 +22,10 @@ use engine_shared::{
 use engine_storage::global_state::StateReader;
 use types::{bytesrepr, CLType, CLValueError, Key};
 
-use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
+use crate::engine_state::{
+    execution_effect::{ExecutionEffect, HostCallRecord, TransferRecord},
+    op::Op,
+};
 
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
@@ -154,6 +157,8 @@ pub struct TrackingCopy<R> {
     cache: TrackingCopyCache<HeapSize>,
     ops: AdditiveMap<Key, Op>,
     fns: AdditiveMap<Key, Transform>,
+    transfers: Vec<TransferRecord>,
+    host_calls: Vec<HostCallRecord>,
 }
 
 #[derive(Debug)]
@@ -187,6 +192,8 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
              * limit? */
             ops: AdditiveMap::new(),
             fns: AdditiveMap::new(),
+            transfers: Vec::new(),
+            host_calls: Vec::new(),
         }
     }
 
@@ -327,6 +334,23 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
 
     pub fn effect(&self) -> ExecutionEffect {
         ExecutionEffect::new(self.ops.clone(), self.fns.clone())
+            .with_transfers(self.transfers.clone())
+            .with_host_calls(self.host_calls.clone())
+    }
+
+    /// Records a transfer made against this `TrackingCopy`, to be reported in its effect.
+    pub fn record_transfer(&mut self, transfer: TransferRecord) {
+        self.transfers.push(transfer);
+    }
+
+    /// Records a host call made against this `TrackingCopy`, to be reported in its effect.
+    pub fn record_host_call(&mut self, host_call: HostCallRecord) {
+        self.host_calls.push(host_call);
+    }
+
+    /// Returns the host calls recorded against this `TrackingCopy`.
+    pub fn host_calls(&self) -> &[HostCallRecord] {
+        &self.host_calls
     }
 
     /// Calling `query()` avoids calling into `self.cache`, so this will not return any values
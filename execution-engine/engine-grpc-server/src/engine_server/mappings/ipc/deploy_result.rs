@@ -1,13 +1,16 @@
 use engine_core::{
     engine_state::{
-        execution_effect::ExecutionEffect, execution_result::ExecutionResult,
+        execution_effect::ExecutionEffect,
+        execution_result::{ExecutionResult, FailurePhase},
         Error as EngineStateError,
     },
     execution::Error as ExecutionError,
 };
 use engine_shared::gas::Gas;
 
-use crate::engine_server::ipc::{DeployError_OutOfGasError, DeployResult};
+use crate::engine_server::ipc::{
+    DeployError_OutOfGasError, DeployResult, DeployResult_FailurePhase,
+};
 
 impl From<ExecutionResult> for DeployResult {
     fn from(execution_result: ExecutionResult) -> DeployResult {
@@ -17,7 +20,24 @@ impl From<ExecutionResult> for DeployResult {
                 error,
                 effect,
                 cost,
-            } => (error, effect, cost).into(),
+                phase,
+            } => {
+                let mut pb_deploy_result: DeployResult = (error, effect, cost).into();
+                if let Some(phase) = phase {
+                    pb_deploy_result.set_failure_phase(phase.into());
+                }
+                pb_deploy_result
+            }
+        }
+    }
+}
+
+impl From<FailurePhase> for DeployResult_FailurePhase {
+    fn from(phase: FailurePhase) -> Self {
+        match phase {
+            FailurePhase::Payment => DeployResult_FailurePhase::PAYMENT,
+            FailurePhase::Session => DeployResult_FailurePhase::SESSION,
+            FailurePhase::Finalization => DeployResult_FailurePhase::FINALIZATION,
         }
     }
 }
@@ -38,6 +58,13 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             | error @ EngineStateError::InvalidKeyVariant(_)
             | error @ EngineStateError::Authorization
             | error @ EngineStateError::InvalidDeployItemVariant(_)
+            | error @ EngineStateError::InvalidModuleBytesChunks(_)
+            | error @ EngineStateError::InconsistentDeployItem(_)
+            | error @ EngineStateError::NoSuchContract(_)
+            | error @ EngineStateError::NoSuchContractPackage(_)
+            | error @ EngineStateError::AlreadyExecuted(_)
+            | error @ EngineStateError::InvalidTransferTarget(_)
+            | error @ EngineStateError::InvalidStoredArgs(_)
             | error @ EngineStateError::InvalidUpgradeResult => {
                 detail::precondition_error(error.to_string())
             }
@@ -67,6 +94,9 @@ impl From<(ExecutionError, ExecutionEffect, Gas)> for DeployResult {
             ExecutionError::Revert(status) => {
                 detail::execution_error(status.to_string(), effect, cost)
             }
+            error @ ExecutionError::RevertWithMessage { .. } => {
+                detail::execution_error(error.to_string(), effect, cost)
+            }
             ExecutionError::Interpreter(error) => detail::execution_error(error, effect, cost),
             // TODO(mateusz.gorski): Be more specific about execution errors
             other => detail::execution_error(format!("{:?}", other), effect, cost),
@@ -190,6 +220,7 @@ mod tests {
             error: error.into(),
             effect: Default::default(),
             cost: expected_cost,
+            phase: None,
         };
         let mut ipc_deploy_result: DeployResult = execution_failure.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -233,6 +264,7 @@ mod tests {
             error: EngineStateError::Exec(revert_error),
             effect: Default::default(),
             cost: Gas::new(amount),
+            phase: None,
         };
         let mut ipc_result: DeployResult = exec_result.into();
         assert!(
@@ -253,4 +285,38 @@ mod tests {
             expected_revert.to_string(),
         );
     }
+
+    #[test]
+    fn failure_phase_maps_to_deploy_result() {
+        let payment_failure = ExecutionResult::Failure {
+            error: EngineStateError::Exec(ExecutionError::GasLimit),
+            effect: Default::default(),
+            cost: Gas::new(U512::from(15)),
+            phase: Some(FailurePhase::Payment),
+        };
+        let ipc_result: DeployResult = payment_failure.into();
+        assert!(ipc_result.has_execution_result());
+        assert_eq!(
+            ipc_result.get_failure_phase(),
+            DeployResult_FailurePhase::PAYMENT
+        );
+
+        let finalization_failure =
+            ExecutionResult::precondition_failure(EngineStateError::Finalization)
+                .with_failure_phase(FailurePhase::Finalization);
+        let ipc_result: DeployResult = finalization_failure.into();
+        assert_eq!(
+            ipc_result.get_failure_phase(),
+            DeployResult_FailurePhase::FINALIZATION
+        );
+
+        let precondition_failure =
+            ExecutionResult::precondition_failure(EngineStateError::Authorization);
+        let ipc_result: DeployResult = precondition_failure.into();
+        assert!(ipc_result.has_precondition_failure());
+        assert_eq!(
+            ipc_result.get_failure_phase(),
+            DeployResult_FailurePhase::UNKNOWN
+        );
+    }
 }
@@ -41,11 +41,14 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
 
         let protocol_version = request.take_protocol_version().into();
 
+        let dry_run = request.get_dry_run();
+
         Ok(ExecuteRequest::new(
             parent_state_hash,
             block_time,
             deploys,
             protocol_version,
+            dry_run,
         ))
     }
 }
@@ -65,6 +68,7 @@ impl From<ExecuteRequest> for ipc::ExecuteRequest {
                 .collect(),
         );
         result.set_protocol_version(req.protocol_version.into());
+        result.set_dry_run(req.dry_run);
         result
     }
 }
@@ -12,7 +12,7 @@ use std::{
 
 use clap::{App, Arg, ArgMatches};
 use dirs::home_dir;
-use engine_core::engine_state::{EngineConfig, EngineState};
+use engine_core::engine_state::{arg_redaction::ArgRedaction, EngineConfig, EngineState};
 use lmdb::DatabaseFlags;
 use log::{error, info, Level, LevelFilter};
 
@@ -107,6 +107,27 @@ const ARG_ENABLE_BONDING: &str = "enable-bonding";
 const ARG_ENABLE_BONDING_SHORT: &str = "b";
 const ARG_ENABLE_BONDING_HELP: &str = "Enable bonding";
 
+// max effect entries
+const ARG_MAX_EFFECT_ENTRIES: &str = "max-effect-entries";
+const ARG_MAX_EFFECT_ENTRIES_VALUE: &str = "NUM";
+const ARG_MAX_EFFECT_ENTRIES_HELP: &str =
+    "Sets the max number of effect entries a single execution may produce";
+const ARG_MAX_EFFECT_ENTRIES_EXPECT: &str = "expected valid max effect entries";
+
+// replay protection
+const ARG_ENABLE_REPLAY_PROTECTION: &str = "enable-replay-protection";
+const ARG_ENABLE_REPLAY_PROTECTION_HELP: &str =
+    "Reject deploys whose hash matches a deploy which has already been executed";
+
+// arg redaction
+const ARG_LOG_ARG_VALUES: &str = "log-arg-values";
+const ARG_LOG_ARG_VALUES_HELP: &str =
+    "Includes the values of deploy args in log output, rather than only their names and types";
+const ARG_REDACT_ARG: &str = "redact-arg";
+const ARG_REDACT_ARG_VALUE: &str = "NAME";
+const ARG_REDACT_ARG_HELP: &str =
+    "Redacts the value of deploy args with this name when logging arg values (may be repeated)";
+
 // runnable
 const SIGINT_HANDLE_EXPECT: &str = "Error setting Ctrl-C handler";
 const RUNNABLE_CHECK_INTERVAL_SECONDS: u64 = 3;
@@ -135,7 +156,16 @@ fn main() {
 
     let engine_config: EngineConfig = get_engine_config(&arg_matches);
 
-    let _server = get_grpc_server(&socket, data_dir, map_size, thread_count, engine_config);
+    let arg_redaction = get_arg_redaction(&arg_matches);
+
+    let _server = get_grpc_server(
+        &socket,
+        data_dir,
+        map_size,
+        thread_count,
+        engine_config,
+        arg_redaction,
+    );
 
     log_listening_message(&socket);
 
@@ -239,6 +269,32 @@ fn get_args() -> ArgMatches<'static> {
                 .long(ARG_ENABLE_BONDING)
                 .help(ARG_ENABLE_BONDING_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_MAX_EFFECT_ENTRIES)
+                .long(ARG_MAX_EFFECT_ENTRIES)
+                .takes_value(true)
+                .value_name(ARG_MAX_EFFECT_ENTRIES_VALUE)
+                .help(ARG_MAX_EFFECT_ENTRIES_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_ENABLE_REPLAY_PROTECTION)
+                .long(ARG_ENABLE_REPLAY_PROTECTION)
+                .help(ARG_ENABLE_REPLAY_PROTECTION_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_LOG_ARG_VALUES)
+                .long(ARG_LOG_ARG_VALUES)
+                .help(ARG_LOG_ARG_VALUES_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_REDACT_ARG)
+                .long(ARG_REDACT_ARG)
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name(ARG_REDACT_ARG_VALUE)
+                .help(ARG_REDACT_ARG_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
                 .required(true)
@@ -304,9 +360,27 @@ fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
     // feature flags go here
     let use_system_contracts = arg_matches.is_present(ARG_USE_SYSTEM_CONTRACTS);
     let enable_bonding = arg_matches.is_present(ARG_ENABLE_BONDING);
+    let max_effect_entries = arg_matches
+        .value_of(ARG_MAX_EFFECT_ENTRIES)
+        .map(|value| usize::from_str(value).expect(ARG_MAX_EFFECT_ENTRIES_EXPECT));
+    let enable_replay_protection = arg_matches.is_present(ARG_ENABLE_REPLAY_PROTECTION);
     EngineConfig::new()
         .with_use_system_contracts(use_system_contracts)
         .with_enable_bonding(enable_bonding)
+        .with_max_effect_entries(max_effect_entries)
+        .with_enable_replay_protection(enable_replay_protection)
+}
+
+/// Returns an [`ArgRedaction`] of the names given by the redact-arg arguments, logging arg values
+/// only if the log-arg-values flag is set.
+fn get_arg_redaction(arg_matches: &ArgMatches) -> ArgRedaction {
+    let log_arg_values = arg_matches.is_present(ARG_LOG_ARG_VALUES);
+    let redacted_names = arg_matches
+        .values_of(ARG_REDACT_ARG)
+        .into_iter()
+        .flatten()
+        .map(str::to_string);
+    ArgRedaction::new(redacted_names).with_log_values(log_arg_values)
 }
 
 /// Builds and returns a gRPC server.
@@ -316,8 +390,10 @@ fn get_grpc_server(
     map_size: usize,
     thread_count: usize,
     engine_config: EngineConfig,
+    arg_redaction: ArgRedaction,
 ) -> grpc::Server {
-    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+    let engine_state =
+        get_engine_state(data_dir, map_size, engine_config).with_arg_redaction(arg_redaction);
 
     engine_server::new(socket.as_str(), thread_count, engine_state)
         .build()
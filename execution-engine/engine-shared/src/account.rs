@@ -74,6 +74,12 @@ impl Account {
         self.main_purse
     }
 
+    /// Returns this account with its main purse replaced by `main_purse`.
+    pub fn with_main_purse(mut self, main_purse: URef) -> Self {
+        self.main_purse = main_purse;
+        self
+    }
+
     /// Returns an [`AccessRights::ADD`]-only version of the [`URef`].
     pub fn main_purse_add_only(&self) -> URef {
         URef::new(self.main_purse.addr(), AccessRights::ADD)
@@ -186,6 +192,13 @@ impl Account {
                 .all(|e| self.associated_keys.contains_key(e))
     }
 
+    /// Calculates the sum of the weights of those authorization keys which are associated with this
+    /// account.
+    pub fn authorization_keys_weight(&self, authorization_keys: &BTreeSet<AccountHash>) -> Weight {
+        self.associated_keys
+            .calculate_keys_weight(authorization_keys)
+    }
+
     /// Checks whether the sum of the weights of all authorization keys is
     /// greater or equal to deploy threshold.
     pub fn can_deploy_with(&self, authorization_keys: &BTreeSet<AccountHash>) -> bool {
@@ -358,6 +371,40 @@ mod tests {
         assert!(!account.can_authorize(&BTreeSet::new()));
     }
 
+    #[test]
+    fn account_authorization_keys_weight() {
+        let identity_key = AccountHash::new([1u8; 32]);
+        let key_1 = AccountHash::new([2u8; 32]);
+        let unknown_key = AccountHash::new([3u8; 32]);
+        let associated_keys = {
+            let mut res = AssociatedKeys::new(identity_key, Weight::new(1));
+            res.add_key(key_1, Weight::new(2))
+                .expect("should add key 1");
+            res
+        };
+        let account = Account::new(
+            AccountHash::new([0u8; 32]),
+            NamedKeys::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            associated_keys,
+            ActionThresholds::new(Weight::new(1), Weight::new(3))
+                .expect("should create thresholds"),
+        );
+
+        assert_eq!(
+            account.authorization_keys_weight(&BTreeSet::from_iter(vec![identity_key])),
+            Weight::new(1)
+        );
+        assert_eq!(
+            account.authorization_keys_weight(&BTreeSet::from_iter(vec![
+                identity_key,
+                key_1,
+                unknown_key
+            ])),
+            Weight::new(3)
+        );
+    }
+
     #[test]
     fn account_can_deploy_with() {
         let associated_keys = {
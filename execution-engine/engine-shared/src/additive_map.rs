@@ -1,17 +1,20 @@
 use std::{
     borrow::Borrow,
-    collections::{
-        hash_map::{IntoIter, Iter, IterMut, Keys, RandomState, Values},
-        HashMap,
-    },
+    collections::hash_map::RandomState,
     fmt::{self, Debug, Formatter},
     hash::{BuildHasher, Hash},
     iter::{FromIterator, IntoIterator},
     ops::{AddAssign, Index},
 };
 
-#[derive(Clone)]
-pub struct AdditiveMap<K, V, S = RandomState>(HashMap<K, V, S>);
+use indexmap::{
+    map::{IntoIter, Iter, IterMut, Keys, Values},
+    IndexMap,
+};
+
+/// A map which iterates over its entries in the order in which their keys were first inserted, so
+/// that e.g. the effects of an execution are reported in a deterministic order.
+pub struct AdditiveMap<K, V, S = RandomState>(IndexMap<K, V, S>);
 
 impl<K: Eq + Hash, V> AdditiveMap<K, V, RandomState> {
     pub fn new() -> Self {
@@ -23,12 +26,12 @@ impl<K: Eq + Hash, V: AddAssign + Default, S: BuildHasher> AdditiveMap<K, V, S>
     /// Modifies the existing value stored under `key`, or the default value for `V` if none, by
     /// adding `value_to_add`.
     pub fn insert_add(&mut self, key: K, value_to_add: V) {
-        let current_value = self.0.entry(key).or_default();
+        let current_value = self.0.entry(key).or_insert_with(Default::default);
         *current_value += value_to_add;
     }
 }
 
-impl<K, V, S> AdditiveMap<K, V, S> {
+impl<K: Eq + Hash, V, S: BuildHasher> AdditiveMap<K, V, S> {
     pub fn keys(&self) -> Keys<'_, K, V> {
         self.0.keys()
     }
@@ -48,9 +51,7 @@ impl<K, V, S> AdditiveMap<K, V, S> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
-}
 
-impl<K: Eq + Hash, V, S: BuildHasher> AdditiveMap<K, V, S> {
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
         K: Borrow<Q>,
@@ -59,6 +60,18 @@ impl<K: Eq + Hash, V, S: BuildHasher> AdditiveMap<K, V, S> {
         self.0.get(key)
     }
 
+    /// Returns a mutable reference to the value stored under `key`, without affecting the
+    /// iteration order.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Inserts `value` under `key`.  If `key` is already present, its value is replaced without
+    /// affecting the iteration order.
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         self.0.insert(key, value)
     }
@@ -68,7 +81,7 @@ impl<K: Eq + Hash, V, S: BuildHasher> AdditiveMap<K, V, S> {
         K: Borrow<Q>,
         Q: Eq + Hash + ?Sized,
     {
-        self.0.remove(key)
+        self.0.shift_remove(key)
     }
 
     pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
@@ -76,17 +89,23 @@ impl<K: Eq + Hash, V, S: BuildHasher> AdditiveMap<K, V, S> {
         K: Borrow<Q>,
         Q: Eq + Hash + ?Sized,
     {
-        self.0.remove_entry(key)
+        self.0.shift_remove_entry(key)
     }
 }
 
 impl<K: Eq + Hash, V, S: BuildHasher + Default> Default for AdditiveMap<K, V, S> {
     fn default() -> Self {
-        Self(HashMap::with_hasher(Default::default()))
+        Self(IndexMap::with_hasher(Default::default()))
     }
 }
 
-impl<'a, K, V, S> IntoIterator for &'a AdditiveMap<K, V, S> {
+impl<K: Eq + Hash + Clone, V: Clone, S: BuildHasher + Clone> Clone for AdditiveMap<K, V, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, K: Eq + Hash, V, S: BuildHasher> IntoIterator for &'a AdditiveMap<K, V, S> {
     type Item = (&'a K, &'a V);
     type IntoIter = Iter<'a, K, V>;
 
@@ -95,7 +114,7 @@ impl<'a, K, V, S> IntoIterator for &'a AdditiveMap<K, V, S> {
     }
 }
 
-impl<'a, K, V, S> IntoIterator for &'a mut AdditiveMap<K, V, S> {
+impl<'a, K: Eq + Hash, V, S: BuildHasher> IntoIterator for &'a mut AdditiveMap<K, V, S> {
     type Item = (&'a K, &'a mut V);
     type IntoIter = IterMut<'a, K, V>;
 
@@ -104,7 +123,7 @@ impl<'a, K, V, S> IntoIterator for &'a mut AdditiveMap<K, V, S> {
     }
 }
 
-impl<K, V, S> IntoIterator for AdditiveMap<K, V, S> {
+impl<K: Eq + Hash, V, S: BuildHasher> IntoIterator for AdditiveMap<K, V, S> {
     type Item = (K, V);
     type IntoIter = IntoIter<K, V>;
 
@@ -115,7 +134,11 @@ impl<K, V, S> IntoIterator for AdditiveMap<K, V, S> {
 
 impl<K: Eq + Hash, V, S: BuildHasher + Default> FromIterator<(K, V)> for AdditiveMap<K, V, S> {
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-        Self(HashMap::from_iter(iter))
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
     }
 }
 
@@ -132,9 +155,13 @@ where
     }
 }
 
+/// Equality doesn't take the iteration order into account.
 impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq for AdditiveMap<K, V, S> {
     fn eq(&self, other: &AdditiveMap<K, V, S>) -> bool {
-        self.0 == other.0
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key).map_or(false, |other| value == other))
     }
 }
 
@@ -166,4 +193,30 @@ mod tests {
         transform_map.insert_add(key, Transform::AddInt32(2));
         assert_eq!(Transform::AddInt32(3), transform_map[key]);
     }
+
+    #[test]
+    fn should_iterate_in_insertion_order() {
+        let keys = [5, 3, 9, 1, 7];
+
+        let mut map = AdditiveMap::new();
+        for key in keys.iter() {
+            map.insert_add(*key, 1);
+        }
+        // Adding to or replacing an existing entry doesn't change the order.
+        map.insert_add(3, 1);
+        map.insert(9, 5);
+
+        assert_eq!(map.keys().cloned().collect::<Vec<_>>(), keys.to_vec());
+
+        // Removing an entry keeps the order of the remaining ones.
+        assert_eq!(map.remove_entry(&9), Some((9, 5)));
+        assert_eq!(map.keys().cloned().collect::<Vec<_>>(), vec![5, 3, 1, 7]);
+    }
+
+    #[test]
+    fn equality_should_ignore_insertion_order() {
+        let first: AdditiveMap<_, _> = vec![(1, 1), (2, 2)].into_iter().collect();
+        let second: AdditiveMap<_, _> = vec![(2, 2), (1, 1)].into_iter().collect();
+        assert_eq!(first, second);
+    }
 }
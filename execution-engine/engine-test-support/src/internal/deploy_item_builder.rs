@@ -1,24 +1,79 @@
 use std::{collections::BTreeSet, path::Path};
 
 use engine_core::{
-    engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
+    engine_state::{
+        deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem,
+        stored_args::StoredArgs,
+    },
     DeployHash,
 };
 use types::{
     account::AccountHash, bytesrepr::ToBytes, contracts::ContractVersion, ContractHash, HashAddr,
-    RuntimeArgs,
+    Key, RuntimeArgs,
 };
 
 use crate::internal::utils;
 
+/// Selects the version of a stored versioned contract to be called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VersionSelector {
+    /// No version was specified.  The active version is called, unless the builder requires
+    /// explicit versions, in which case building the deploy item fails.
+    Unset,
+    /// The active version is called.
+    Active,
+    /// The given version is called.
+    Version(ContractVersion),
+}
+
+impl VersionSelector {
+    fn is_unset(&self) -> bool {
+        *self == VersionSelector::Unset
+    }
+
+    fn into_version(self) -> Option<ContractVersion> {
+        match self {
+            VersionSelector::Unset | VersionSelector::Active => None,
+            VersionSelector::Version(version) => Some(version),
+        }
+    }
+}
+
+impl From<Option<ContractVersion>> for VersionSelector {
+    fn from(version: Option<ContractVersion>) -> Self {
+        match version {
+            None => VersionSelector::Unset,
+            Some(version) => VersionSelector::Version(version),
+        }
+    }
+}
+
+impl From<ContractVersion> for VersionSelector {
+    fn from(version: ContractVersion) -> Self {
+        VersionSelector::Version(version)
+    }
+}
+
+/// An error returned by [`DeployItemBuilder::try_build`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DeployItemBuilderError {
+    /// Explicit versions are required, but the version of the versioned session code was unset.
+    UnsetSessionVersion,
+    /// Explicit versions are required, but the version of the versioned payment code was unset.
+    UnsetPaymentVersion,
+}
+
 #[derive(Default)]
 struct DeployItemData {
     pub address: Option<AccountHash>,
     pub payment_code: Option<ExecutableDeployItem>,
+    pub payment_version_unset: bool,
     pub session_code: Option<ExecutableDeployItem>,
+    pub session_version_unset: bool,
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: DeployHash,
+    pub require_explicit_versions: bool,
 }
 
 pub struct DeployItemBuilder {
@@ -37,8 +92,7 @@ impl DeployItemBuilder {
 
     pub fn with_payment_bytes(mut self, module_bytes: Vec<u8>, args: RuntimeArgs) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code =
-            Some(ExecutableDeployItem::ModuleBytes { module_bytes, args });
+        self.set_payment_code(ExecutableDeployItem::ModuleBytes { module_bytes, args });
         self
     }
 
@@ -58,7 +112,7 @@ impl DeployItemBuilder {
         args: RuntimeArgs,
     ) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByHash {
+        self.set_payment_code(ExecutableDeployItem::StoredContractByHash {
             hash,
             entry_point: entry_point.into(),
             args,
@@ -73,7 +127,7 @@ impl DeployItemBuilder {
         args: RuntimeArgs,
     ) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByName {
+        self.set_payment_code(ExecutableDeployItem::StoredContractByName {
             name: uref_name.to_owned(),
             entry_point: entry_point_name.into(),
             args,
@@ -83,8 +137,7 @@ impl DeployItemBuilder {
 
     pub fn with_session_bytes(mut self, module_bytes: Vec<u8>, args: RuntimeArgs) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code =
-            Some(ExecutableDeployItem::ModuleBytes { module_bytes, args });
+        self.set_session_code(ExecutableDeployItem::ModuleBytes { module_bytes, args });
         self
     }
 
@@ -95,7 +148,7 @@ impl DeployItemBuilder {
 
     pub fn with_transfer_args(mut self, args: RuntimeArgs) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code = Some(ExecutableDeployItem::Transfer { args });
+        self.set_session_code(ExecutableDeployItem::Transfer { args });
         self
     }
 
@@ -106,7 +159,7 @@ impl DeployItemBuilder {
         args: RuntimeArgs,
     ) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByHash {
+        self.set_session_code(ExecutableDeployItem::StoredContractByHash {
             hash,
             entry_point: entry_point.into(),
             args,
@@ -121,7 +174,7 @@ impl DeployItemBuilder {
         args: RuntimeArgs,
     ) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByName {
+        self.set_session_code(ExecutableDeployItem::StoredContractByName {
             name: name.to_owned(),
             entry_point: entry_point.into(),
             args,
@@ -132,64 +185,106 @@ impl DeployItemBuilder {
     pub fn with_stored_versioned_contract_by_name(
         mut self,
         name: &str,
-        version: Option<ContractVersion>,
+        version: impl Into<VersionSelector>,
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
-        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredVersionedContractByName {
-            name: name.to_owned(),
+        let version = version.into();
+        self.set_versioned_session_code(
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: name.to_owned(),
+                version: version.into_version(),
+                entry_point: entry_point.to_owned(),
+                args: args.to_bytes().expect("should serialize runtime args"),
+            },
             version,
-            entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
-        });
+        );
         self
     }
 
     pub fn with_stored_versioned_contract_by_hash(
         mut self,
         hash: HashAddr,
-        version: Option<ContractVersion>,
+        version: impl Into<VersionSelector>,
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
-        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredVersionedContractByHash {
-            hash,
+        let version = version.into();
+        self.set_versioned_session_code(
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version: version.into_version(),
+                entry_point: entry_point.to_owned(),
+                args: args.to_bytes().expect("should serialize runtime args"),
+            },
             version,
-            entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
-        });
+        );
         self
     }
 
     pub fn with_stored_versioned_payment_contract_by_name(
         mut self,
         key_name: &str,
-        version: Option<ContractVersion>,
+        version: impl Into<VersionSelector>,
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
-        self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredVersionedContractByName {
-            name: key_name.to_owned(),
+        let version = version.into();
+        self.set_versioned_payment_code(
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: key_name.to_owned(),
+                version: version.into_version(),
+                entry_point: entry_point.to_owned(),
+                args: args.to_bytes().expect("should serialize runtime args"),
+            },
             version,
-            entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
-        });
+        );
         self
     }
 
     pub fn with_stored_versioned_payment_contract_by_hash(
         mut self,
         hash: HashAddr,
-        version: Option<ContractVersion>,
+        version: impl Into<VersionSelector>,
         entry_point: &str,
         args: RuntimeArgs,
     ) -> Self {
-        self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredVersionedContractByHash {
-            hash,
+        let version = version.into();
+        self.set_versioned_payment_code(
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version: version.into_version(),
+                entry_point: entry_point.to_owned(),
+                args: args.to_bytes().expect("should serialize runtime args"),
+            },
             version,
-            entry_point: entry_point.to_owned(),
-            args: args.to_bytes().expect("should serialize runtime args"),
-        });
+        );
+        self
+    }
+
+    /// Replaces the args of the session code set so far with a reference to the args stored under
+    /// `args_key`.
+    pub fn with_stored_session_args(mut self, args_key: Key) -> Self {
+        let session_code = self
+            .deploy_item
+            .session_code
+            .take()
+            .expect("should have session code");
+        self.deploy_item.session_code =
+            Some(session_code.with_args(StoredArgs::new(args_key).to_args()));
+        self
+    }
+
+    /// Replaces the args of the payment code set so far with a reference to the args stored under
+    /// `args_key`.  The engine rejects stored payment args, so this is for testing that rejection.
+    pub fn with_stored_payment_args(mut self, args_key: Key) -> Self {
+        let payment_code = self
+            .deploy_item
+            .payment_code
+            .take()
+            .expect("should have payment code");
+        self.deploy_item.payment_code =
+            Some(payment_code.with_args(StoredArgs::new(args_key).to_args()));
         self
     }
 
@@ -215,8 +310,32 @@ impl DeployItemBuilder {
         self
     }
 
+    /// Requires stored versioned contracts to be given an explicit [`VersionSelector`], i.e. either
+    /// [`VersionSelector::Active`] or a specific version.  An unset version then causes
+    /// [`DeployItemBuilder::try_build`] to fail rather than silently selecting the active version.
+    pub fn with_explicit_versions(mut self) -> Self {
+        self.deploy_item.require_explicit_versions = true;
+        self
+    }
+
+    /// Builds the deploy item, panicking on error.
     pub fn build(self) -> DeployItem {
-        DeployItem {
+        self.try_build().expect("should build deploy item")
+    }
+
+    /// Builds the deploy item, returning an error if explicit versions are required but a version
+    /// was left unset.
+    pub fn try_build(self) -> Result<DeployItem, DeployItemBuilderError> {
+        if self.deploy_item.require_explicit_versions {
+            if self.deploy_item.session_version_unset {
+                return Err(DeployItemBuilderError::UnsetSessionVersion);
+            }
+            if self.deploy_item.payment_version_unset {
+                return Err(DeployItemBuilderError::UnsetPaymentVersion);
+            }
+        }
+
+        Ok(DeployItem {
             address: self
                 .deploy_item
                 .address
@@ -232,7 +351,35 @@ impl DeployItemBuilder {
             gas_price: self.deploy_item.gas_price,
             authorization_keys: self.deploy_item.authorization_keys,
             deploy_hash: self.deploy_item.deploy_hash,
-        }
+        })
+    }
+
+    fn set_session_code(&mut self, session_code: ExecutableDeployItem) {
+        self.deploy_item.session_code = Some(session_code);
+        self.deploy_item.session_version_unset = false;
+    }
+
+    fn set_versioned_session_code(
+        &mut self,
+        session_code: ExecutableDeployItem,
+        version: VersionSelector,
+    ) {
+        self.deploy_item.session_code = Some(session_code);
+        self.deploy_item.session_version_unset = version.is_unset();
+    }
+
+    fn set_payment_code(&mut self, payment_code: ExecutableDeployItem) {
+        self.deploy_item.payment_code = Some(payment_code);
+        self.deploy_item.payment_version_unset = false;
+    }
+
+    fn set_versioned_payment_code(
+        &mut self,
+        payment_code: ExecutableDeployItem,
+        version: VersionSelector,
+    ) {
+        self.deploy_item.payment_code = Some(payment_code);
+        self.deploy_item.payment_version_unset = version.is_unset();
     }
 
     fn serialize_args(args: RuntimeArgs) -> Vec<u8> {
@@ -247,3 +394,94 @@ impl Default for DeployItemBuilder {
         DeployItemBuilder { deploy_item }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use engine_core::engine_state::executable_deploy_item::ExecutableDeployItem;
+    use types::RuntimeArgs;
+
+    use super::{DeployItemBuilder, DeployItemBuilderError, VersionSelector};
+
+    const PACKAGE_NAME: &str = "package";
+    const ENTRY_POINT: &str = "call";
+
+    fn builder() -> DeployItemBuilder {
+        DeployItemBuilder::new()
+            .with_explicit_versions()
+            .with_empty_payment_bytes(RuntimeArgs::default())
+    }
+
+    fn session_version(builder: DeployItemBuilder) -> Option<u32> {
+        let deploy_item = builder.try_build().expect("should build");
+        match deploy_item.session {
+            ExecutableDeployItem::StoredVersionedContractByName { version, .. } => version,
+            other => panic!("unexpected session code {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_build_with_explicitly_active_version() {
+        let builder = builder().with_stored_versioned_contract_by_name(
+            PACKAGE_NAME,
+            VersionSelector::Active,
+            ENTRY_POINT,
+            RuntimeArgs::default(),
+        );
+        assert_eq!(session_version(builder), None);
+    }
+
+    #[test]
+    fn should_build_with_explicit_version() {
+        let builder = builder().with_stored_versioned_contract_by_name(
+            PACKAGE_NAME,
+            VersionSelector::Version(2),
+            ENTRY_POINT,
+            RuntimeArgs::default(),
+        );
+        assert_eq!(session_version(builder), Some(2));
+    }
+
+    #[test]
+    fn should_fail_to_build_with_unset_version() {
+        let result = builder()
+            .with_stored_versioned_contract_by_name(
+                PACKAGE_NAME,
+                None,
+                ENTRY_POINT,
+                RuntimeArgs::default(),
+            )
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            DeployItemBuilderError::UnsetSessionVersion
+        );
+
+        let result = DeployItemBuilder::new()
+            .with_explicit_versions()
+            .with_session_bytes(vec![], RuntimeArgs::default())
+            .with_stored_versioned_payment_contract_by_name(
+                PACKAGE_NAME,
+                None,
+                ENTRY_POINT,
+                RuntimeArgs::default(),
+            )
+            .try_build();
+        assert_eq!(
+            result.unwrap_err(),
+            DeployItemBuilderError::UnsetPaymentVersion
+        );
+    }
+
+    #[test]
+    fn should_treat_unset_version_as_active_by_default() {
+        let builder = DeployItemBuilder::new()
+            .with_empty_payment_bytes(RuntimeArgs::default())
+            .with_stored_versioned_contract_by_name(
+                PACKAGE_NAME,
+                None,
+                ENTRY_POINT,
+                RuntimeArgs::default(),
+            );
+        assert_eq!(session_version(builder), None);
+    }
+}
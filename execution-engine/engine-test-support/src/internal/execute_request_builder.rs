@@ -46,6 +46,11 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.execute_request.dry_run = dry_run;
+        self
+    }
+
     pub fn build(self) -> ExecuteRequest {
         self.execute_request
     }
@@ -19,7 +19,7 @@ use types::{account::AccountHash, ProtocolVersion, U512};
 
 use super::{DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE};
 pub use additive_map_diff::AdditiveMapDiff;
-pub use deploy_item_builder::DeployItemBuilder;
+pub use deploy_item_builder::{DeployItemBuilder, DeployItemBuilderError, VersionSelector};
 pub use execute_request_builder::ExecuteRequestBuilder;
 pub use upgrade_request_builder::UpgradeRequestBuilder;
 pub use wasm_test_builder::{
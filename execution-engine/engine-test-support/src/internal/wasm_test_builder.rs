@@ -14,8 +14,9 @@ use log::LevelFilter;
 
 use engine_core::{
     engine_state::{
-        execute_request::ExecuteRequest, execution_result::ExecutionResult,
-        run_genesis_request::RunGenesisRequest, EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
+        arg_redaction::ArgRedaction, execute_request::ExecuteRequest,
+        execution_result::ExecutionResult, run_genesis_request::RunGenesisRequest, EngineConfig,
+        EngineState, SYSTEM_ACCOUNT_ADDR,
     },
     execution,
 };
@@ -98,11 +99,17 @@ impl<S> WasmTestBuilder<S> {
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
-        Self::initialize_logging();
         let engine_config = EngineConfig::new()
             .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
             .with_enable_bonding(cfg!(feature = "enable-bonding"));
+        Self::new_with_config(engine_config)
+    }
+}
 
+impl InMemoryWasmTestBuilder {
+    /// Creates a builder with empty global state, using the given engine config.
+    pub fn new_with_config(engine_config: EngineConfig) -> Self {
+        Self::initialize_logging();
         let global_state = InMemoryGlobalState::empty().expect("should create global state");
         let engine_state = EngineState::new(global_state, engine_config);
 
@@ -121,6 +128,17 @@ impl Default for InMemoryWasmTestBuilder {
             standard_payment_hash: None,
         }
     }
+
+    /// Returns `self` with the engine logging the values of deploy args as allowed by
+    /// `arg_redaction`.
+    ///
+    /// Panics if the builder has been cloned.
+    pub fn with_arg_redaction(mut self, arg_redaction: ArgRedaction) -> Self {
+        let engine_state = Rc::try_unwrap(self.engine_state)
+            .unwrap_or_else(|_| panic!("engine state should not be shared"));
+        self.engine_state = Rc::new(engine_state.with_arg_redaction(arg_redaction));
+        self
+    }
 }
 
 // TODO: Deriving `Clone` for `WasmTestBuilder<S>` doesn't work correctly (unsure why), so
@@ -571,6 +589,20 @@ where
         &self.engine_state
     }
 
+    /// Makes subsequent deploys from `account_hash` use `main_purse` as the account's main purse,
+    /// in place of the one stored in the account.
+    #[cfg(feature = "test-support")]
+    pub fn override_main_purse(
+        &mut self,
+        account_hash: AccountHash,
+        main_purse: URef,
+    ) -> &mut Self {
+        self.engine_state
+            .main_purse_overrides()
+            .insert(account_hash, main_purse);
+        self
+    }
+
     pub fn get_exec_responses(&self) -> &Vec<Vec<Rc<ExecutionResult>>> {
         &self.exec_responses
     }
@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex};
+
+use log::{LevelFilter, Metadata, Record};
+
+use engine_core::engine_state::arg_redaction::ArgRedaction;
+use engine_shared::logging::{self, Settings, Style, TerminalLogger};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, runtime_args, RuntimeArgs};
+
+const REVERT_WASM: &str = "revert.wasm";
+const ARG_SECRET: &str = "secret";
+const ARG_PUBLIC: &str = "public";
+const SECRET_VALUE: &str = "correct-horse-battery-staple";
+const PUBLIC_VALUE: u8 = 7;
+
+struct Logger {
+    terminal_logger: TerminalLogger,
+    log_lines: Arc<Mutex<Vec<String>>>,
+}
+
+impl Logger {
+    fn new(buffer: Arc<Mutex<Vec<String>>>, settings: &Settings) -> Self {
+        Logger {
+            terminal_logger: TerminalLogger::new(settings),
+            log_lines: buffer,
+        }
+    }
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.terminal_logger.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if let Some(log_line) = self.terminal_logger.prepare_log_line(record) {
+            self.log_lines.lock().unwrap().push(log_line);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Executes a failing deploy with a secret and a public arg, returning the lines logged meanwhile.
+fn exec_and_capture_logs(
+    log_lines: &Arc<Mutex<Vec<String>>>,
+    arg_redaction: ArgRedaction,
+) -> Vec<String> {
+    log_lines.lock().unwrap().clear();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        REVERT_WASM,
+        runtime_args! { ARG_SECRET => SECRET_VALUE, ARG_PUBLIC => PUBLIC_VALUE },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default().with_arg_redaction(arg_redaction);
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    assert!(builder.is_error());
+
+    log_lines.lock().unwrap().clone()
+}
+
+#[ignore]
+#[test]
+fn should_not_log_arg_values_unless_enabled() {
+    let settings = Settings::new(LevelFilter::Trace).with_style(Style::HumanReadable);
+    let log_lines = Arc::new(Mutex::new(vec![]));
+    let logger = Box::new(Logger::new(Arc::clone(&log_lines), &settings));
+    logging::initialize_with_logger(logger, settings).expect("should initialize logger");
+
+    let secret_hex = base16::encode_lower(&SECRET_VALUE.to_bytes().unwrap());
+    let public_value = format!("public: U8 = 0x{:02x}", PUBLIC_VALUE);
+
+    // By default, only arg names and types are logged.
+    let default_lines = exec_and_capture_logs(&log_lines, ArgRedaction::default());
+    assert!(
+        default_lines
+            .iter()
+            .any(|line| line.contains("Session arguments")
+                && line.contains("secret: String")
+                && line.contains("public: U8")),
+        "the names and types of args should be logged"
+    );
+    assert!(
+        default_lines
+            .iter()
+            .any(|line| line.contains("Execution failed") && line.contains(ARG_SECRET)),
+        "the names of the args of a failed execution should be logged"
+    );
+    for line in default_lines.iter() {
+        assert!(!line.contains(SECRET_VALUE), "{}", line);
+        // Values longer than 16 bytes would be shown truncated to their first 16 bytes.
+        assert!(!line.contains(&secret_hex[..32]), "{}", line);
+        assert!(!line.contains(&public_value), "{}", line);
+    }
+
+    // Once enabled, values are logged except for those of redacted args.
+    let arg_redaction = ArgRedaction::new(vec![ARG_SECRET.to_string()]).with_log_values(true);
+    let enabled_lines = exec_and_capture_logs(&log_lines, arg_redaction);
+    assert!(
+        enabled_lines
+            .iter()
+            .any(|line| line.contains("Session arguments")
+                && line.contains("secret: String")
+                && line.contains(&public_value)),
+        "the values of args which aren't redacted should be logged"
+    );
+    for line in enabled_lines.iter() {
+        assert!(!line.contains(SECRET_VALUE), "{}", line);
+        assert!(!line.contains(&secret_hex[..32]), "{}", line);
+    }
+}
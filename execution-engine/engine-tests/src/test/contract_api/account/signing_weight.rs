@@ -0,0 +1,104 @@
+use std::convert::TryFrom;
+
+use assert_matches::assert_matches;
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::{AccountHash, Weight},
+    runtime_args, ApiError, CLValue, RuntimeArgs,
+};
+
+const CONTRACT_AUTHORIZED_KEYS: &str = "authorized_keys.wasm";
+const CONTRACT_CHECK_SIGNING_WEIGHT: &str = "check_signing_weight.wasm";
+/// Associated with the default account with a weight of 100 by `authorized_keys.wasm`.
+const KEY_1: AccountHash = AccountHash::new([123; 32]);
+const KEY_MANAGEMENT_THRESHOLD: u8 = 101;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUTHORIZED_KEYS,
+        runtime_args! {
+            "key_management_threshold" => Weight::new(KEY_MANAGEMENT_THRESHOLD),
+            "deploy_threshold" => Weight::new(0),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn check_signing_weight_request(authorization_keys: &[AccountHash]) -> ExecuteRequestBuilder {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_session_code(CONTRACT_CHECK_SIGNING_WEIGHT, RuntimeArgs::default())
+        .with_deploy_hash([2u8; 32])
+        .with_authorization_keys(authorization_keys)
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy)
+}
+
+fn get_stored_weight(builder: &InMemoryWasmTestBuilder, name: &str) -> Weight {
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let key = account.named_keys()[name];
+    CLValue::try_from(builder.query(None, key, &[]).expect("should have value"))
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be a Weight")
+}
+
+#[ignore]
+#[test]
+fn should_reject_deploy_signed_below_key_management_threshold() {
+    let mut builder = setup();
+
+    let exec_request = check_signing_weight_request(&[DEFAULT_ACCOUNT_ADDR]).build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::PermissionDenied))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_accept_deploy_signed_at_key_management_threshold() {
+    let mut builder = setup();
+
+    let exec_request = check_signing_weight_request(&[DEFAULT_ACCOUNT_ADDR, KEY_1]).build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(
+        get_stored_weight(&builder, "deployment_threshold"),
+        Weight::new(1)
+    );
+    assert_eq!(
+        get_stored_weight(&builder, "key_management_threshold"),
+        Weight::new(KEY_MANAGEMENT_THRESHOLD)
+    );
+    assert_eq!(
+        get_stored_weight(&builder, "total_weight"),
+        Weight::new(KEY_MANAGEMENT_THRESHOLD)
+    );
+}
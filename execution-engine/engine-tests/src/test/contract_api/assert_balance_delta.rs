@@ -0,0 +1,64 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs, U512};
+
+const CONTRACT_ASSERT_BALANCE_DELTA: &str = "assert_balance_delta.wasm";
+const ARG_AMOUNT: &str = "amount";
+const ARG_EXPECTED_DELTA: &str = "expected_delta";
+const UNEXPECTED_BALANCE_DELTA: u16 = 100;
+
+fn run_assert_balance_delta(amount: u64, expected_delta: u64) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ASSERT_BALANCE_DELTA,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(amount),
+            ARG_EXPECTED_DELTA => U512::from(expected_delta),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_pass_with_expected_positive_delta() {
+    run_assert_balance_delta(100, 100).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_pass_with_expected_zero_delta() {
+    run_assert_balance_delta(0, 0).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_unexpected_delta() {
+    let builder = run_assert_balance_delta(100, 50);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::User(
+            UNEXPECTED_BALANCE_DELTA
+        )))
+    );
+}
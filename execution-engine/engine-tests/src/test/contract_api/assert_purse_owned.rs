@@ -0,0 +1,52 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs};
+
+const CONTRACT_ASSERT_PURSE_OWNED: &str = "assert_purse_owned.wasm";
+const ARG_STORE_PURSE: &str = "store_purse";
+const NOT_OWNED: u16 = 100;
+
+fn run_assert_purse_owned(store_purse: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ASSERT_PURSE_OWNED,
+        runtime_args! { ARG_STORE_PURSE => store_purse },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_continue_with_owned_purse() {
+    run_assert_purse_owned(true).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_purse_not_owned() {
+    let builder = run_assert_purse_owned(false);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::User(NOT_OWNED)))
+    );
+}
@@ -0,0 +1,239 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    contracts::{ContractVersion, ContractVersionKey, CONTRACT_INITIAL_VERSION},
+    runtime_args, ContractHash, ContractPackageHash, RuntimeArgs,
+};
+
+const CONTRACT_CALL_VERSIONED_CONTRACT: &str = "call_versioned_contract.wasm";
+const CONTRACT_DISABLE_CONTRACT_VERSION: &str = "disable_contract_version.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_HASH_KEY_NAME: &str = "do_nothing_hash";
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const ARG_AMOUNT: &str = "amount";
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const MISSING_ENTRY_POINT_NAME: &str = "missing";
+const MISSING_VERSION: ContractVersion = CONTRACT_INITIAL_VERSION + 4;
+const PROTOCOL_VERSION_MAJOR: u32 = 1;
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractPackageHash, ContractHash) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let contract_package_hash = account.named_keys()[DO_NOTHING_PACKAGE_HASH_KEY_NAME]
+        .into_hash()
+        .expect("should have package hash");
+    let contract_hash = account.named_keys()[DO_NOTHING_HASH_KEY_NAME]
+        .into_hash()
+        .expect("should have contract hash");
+
+    (builder, contract_package_hash, contract_hash)
+}
+
+fn call_versioned_contract(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    version: Option<ContractVersion>,
+    entry_point: &str,
+) -> Error {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALL_VERSIONED_CONTRACT,
+        runtime_args! {
+            "contract_package" => contract_package_hash,
+            "version" => version,
+            "entry_point" => entry_point,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    execution_result
+        .as_error()
+        .cloned()
+        .expect("should have error")
+}
+
+fn exec_versioned_deploy(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    version: Option<ContractVersion>,
+    entry_point: &str,
+) -> Error {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_stored_versioned_contract_by_hash(
+            contract_package_hash,
+            version,
+            entry_point,
+            RuntimeArgs::default(),
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([42; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    utils::get_precondition_failure(response).clone()
+}
+
+fn disable_contract_version(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    contract_hash: ContractHash,
+) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DISABLE_CONTRACT_VERSION,
+        runtime_args! {
+            "contract_package" => contract_package_hash,
+            "contract_hash" => contract_hash,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_missing_contract_version() {
+    let (mut builder, contract_package_hash, _) = setup();
+
+    let error = call_versioned_contract(
+        &mut builder,
+        contract_package_hash,
+        Some(MISSING_VERSION),
+        ENTRY_FUNCTION_NAME,
+    );
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::NoSuchVersion(contract_version_key))
+            if contract_version_key
+                == ContractVersionKey::new(PROTOCOL_VERSION_MAJOR, MISSING_VERSION)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_disabled_contract_version() {
+    let (mut builder, contract_package_hash, contract_hash) = setup();
+    disable_contract_version(&mut builder, contract_package_hash, contract_hash);
+
+    let error = call_versioned_contract(
+        &mut builder,
+        contract_package_hash,
+        Some(CONTRACT_INITIAL_VERSION),
+        ENTRY_FUNCTION_NAME,
+    );
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::VersionDisabled(contract_version_key))
+            if contract_version_key
+                == ContractVersionKey::new(PROTOCOL_VERSION_MAJOR, CONTRACT_INITIAL_VERSION)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_call_missing_entry_point() {
+    let (mut builder, contract_package_hash, _) = setup();
+
+    let error = call_versioned_contract(
+        &mut builder,
+        contract_package_hash,
+        Some(CONTRACT_INITIAL_VERSION),
+        MISSING_ENTRY_POINT_NAME,
+    );
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::NoSuchEntryPoint(entry_point))
+            if entry_point == MISSING_ENTRY_POINT_NAME
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_deploy_missing_contract_version() {
+    let (mut builder, contract_package_hash, _) = setup();
+
+    let error = exec_versioned_deploy(
+        &mut builder,
+        contract_package_hash,
+        Some(MISSING_VERSION),
+        ENTRY_FUNCTION_NAME,
+    );
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::NoSuchVersion(contract_version_key))
+            if contract_version_key
+                == ContractVersionKey::new(PROTOCOL_VERSION_MAJOR, MISSING_VERSION)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_deploy_disabled_contract_version() {
+    let (mut builder, contract_package_hash, contract_hash) = setup();
+    disable_contract_version(&mut builder, contract_package_hash, contract_hash);
+
+    let error = exec_versioned_deploy(
+        &mut builder,
+        contract_package_hash,
+        Some(CONTRACT_INITIAL_VERSION),
+        ENTRY_FUNCTION_NAME,
+    );
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::VersionDisabled(contract_version_key))
+            if contract_version_key
+                == ContractVersionKey::new(PROTOCOL_VERSION_MAJOR, CONTRACT_INITIAL_VERSION)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_deploy_missing_entry_point() {
+    let (mut builder, contract_package_hash, _) = setup();
+
+    let error = exec_versioned_deploy(
+        &mut builder,
+        contract_package_hash,
+        Some(CONTRACT_INITIAL_VERSION),
+        MISSING_ENTRY_POINT_NAME,
+    );
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::NoSuchEntryPoint(entry_point))
+            if entry_point == MISSING_ENTRY_POINT_NAME
+    );
+}
@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, CLValue, RuntimeArgs, U512};
+
+const CONTRACT_CHECKED_SUB_OR_REVERT: &str = "checked_sub_or_revert.wasm";
+const ARG_BALANCE: &str = "balance";
+const ARG_AMOUNT: &str = "amount";
+const REMAINING_BALANCE: &str = "remaining_balance";
+const INSUFFICIENT_BALANCE: u16 = 0;
+
+fn run_checked_sub_or_revert(balance: u64, amount: u64) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CHECKED_SUB_OR_REVERT,
+        runtime_args! {
+            ARG_BALANCE => U512::from(balance),
+            ARG_AMOUNT => U512::from(amount),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_subtract_amount_not_exceeding_balance() {
+    let mut builder = run_checked_sub_or_revert(100, 100);
+    builder.expect_success();
+
+    let remaining_balance_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .named_keys()[REMAINING_BALANCE]
+        .normalize();
+    let remaining_balance: U512 = CLValue::try_from(
+        builder
+            .query(None, remaining_balance_key, &[])
+            .expect("should have remaining balance"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be U512");
+
+    assert_eq!(remaining_balance, U512::zero());
+}
+
+#[ignore]
+#[test]
+fn should_revert_on_underflow() {
+    let builder = run_checked_sub_or_revert(100, 101);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::User(
+            INSUFFICIENT_BALANCE
+        )))
+    );
+}
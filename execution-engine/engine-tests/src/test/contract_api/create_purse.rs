@@ -3,17 +3,20 @@ use lazy_static::lazy_static;
 use engine_shared::transform::Transform;
 use engine_test_support::{
     internal::{
-        ExecuteRequestBuilder, WasmTestBuilder, DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+        DeployItemBuilder, ExecuteRequestBuilder, WasmTestBuilder, ARG_AMOUNT, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U512};
+use types::{account::AccountHash, runtime_args, Key, RuntimeArgs, URef, U512};
 
 const CONTRACT_CREATE_PURSE_01: &str = "create_purse_01.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
 const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
 const TEST_PURSE_NAME: &str = "test_purse";
 const ARG_PURSE_NAME: &str = "purse_name";
+const DEPLOY_HASH_1: [u8; 32] = [1u8; 32];
+const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
 
 lazy_static! {
     static ref ACCOUNT_1_INITIAL_BALANCE: U512 = *DEFAULT_PAYMENT;
@@ -161,3 +164,44 @@ fn should_create_usable_purse() {
         "when created directly a purse has 0 balance"
     );
 }
+
+fn create_purse_with_deploy_hash(deploy_hash: [u8; 32]) -> URef {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_CREATE_PURSE_01,
+            runtime_args! { ARG_PURSE_NAME => TEST_PURSE_NAME },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy).build();
+
+    let mut builder = WasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(TEST_PURSE_NAME)
+        .expect("should have known key")
+        .into_uref()
+        .expect("should have uref")
+}
+
+#[ignore]
+#[test]
+fn should_create_purse_at_same_address_for_same_deploy_hash() {
+    let first_purse = create_purse_with_deploy_hash(DEPLOY_HASH_1);
+    let second_purse = create_purse_with_deploy_hash(DEPLOY_HASH_1);
+    assert_eq!(first_purse, second_purse);
+
+    let other_purse = create_purse_with_deploy_hash(DEPLOY_HASH_2);
+    assert_ne!(first_purse.addr(), other_purse.addr());
+}
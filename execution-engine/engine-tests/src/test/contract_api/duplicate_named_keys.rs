@@ -0,0 +1,70 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_shared::stored_value::StoredValue;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, Key, RuntimeArgs};
+
+const CONTRACT_DUPLICATE_NAMED_KEYS: &str = "duplicate_named_keys.wasm";
+const ARG_CONFLICT: &str = "conflict";
+const SHARED_NAME: &str = "shared";
+const NEW_NAME: &str = "new";
+const CONTRACT_HASH_KEY_NAME: &str = "contract_hash";
+
+fn run_duplicate_named_keys(conflict: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DUPLICATE_NAMED_KEYS,
+        runtime_args! { ARG_CONFLICT => conflict },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_merge_named_keys_without_conflicts() {
+    let mut builder = run_duplicate_named_keys(false);
+    builder.expect_success();
+
+    let contract_hash_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[CONTRACT_HASH_KEY_NAME];
+    let contract = match builder
+        .query(None, contract_hash_key, &[])
+        .expect("should have contract")
+    {
+        StoredValue::Contract(contract) => contract,
+        other => panic!("expected contract, found {:?}", other),
+    };
+    assert_eq!(contract.named_keys()[SHARED_NAME], Key::Hash([1; 32]));
+    assert_eq!(contract.named_keys()[NEW_NAME], Key::Hash([3; 32]));
+}
+
+#[ignore]
+#[test]
+fn should_reject_conflicting_named_keys() {
+    let builder = run_duplicate_named_keys(true);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::DuplicateNamedKey(name)) if name == SHARED_NAME
+    );
+}
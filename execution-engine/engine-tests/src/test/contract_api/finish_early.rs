@@ -0,0 +1,80 @@
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_FINISH_EARLY: &str = "finish_early.wasm";
+const CONTRACT_FINISH_EARLY_SUBCALL: &str = "finish_early_subcall.wasm";
+const ARG_FINISH_EARLY: &str = "finish_early";
+const BEFORE: &str = "before";
+const AFTER: &str = "after";
+const AFTER_CALL: &str = "after_call";
+
+fn run_finish_early(finish_early: bool) -> (InMemoryWasmTestBuilder, Gas) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_FINISH_EARLY,
+        runtime_args! { ARG_FINISH_EARLY => finish_early },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    let cost = builder.last_exec_gas_cost();
+    (builder, cost)
+}
+
+#[ignore]
+#[test]
+fn should_finish_early_keeping_effects() {
+    let (builder, _cost) = run_finish_early(true);
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    assert!(default_account.named_keys().contains_key(BEFORE));
+    assert!(!default_account.named_keys().contains_key(AFTER));
+}
+
+#[ignore]
+#[test]
+fn should_charge_less_gas_when_finishing_early() {
+    let (builder, full_cost) = run_finish_early(false);
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    assert!(default_account.named_keys().contains_key(AFTER));
+
+    let (_builder, early_cost) = run_finish_early(true);
+    assert!(early_cost < full_cost);
+}
+
+#[ignore]
+#[test]
+fn should_finish_early_only_the_called_contract() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_FINISH_EARLY_SUBCALL,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    // The caller carries on after the callee finishes early.
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    assert!(default_account.named_keys().contains_key(AFTER_CALL));
+}
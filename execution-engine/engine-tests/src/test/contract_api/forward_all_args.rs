@@ -0,0 +1,109 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr, runtime_args, CLValue, ContractHash, RuntimeArgs, U512};
+
+const CONTRACT_FORWARD_ARGS_INSTALL: &str = "forward_args_install.wasm";
+const CONTRACT_FORWARD_ARGS_PROXY: &str = "forward_args_proxy.wasm";
+const CONTRACT_FORWARD_ARGS_EMPTY: &str = "forward_args_empty.wasm";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const CONTRACT_HASH_KEY: &str = "contract_hash";
+const RECEIVED_ARGS_KEY: &str = "received_args";
+
+#[ignore]
+#[test]
+fn should_forward_all_args_to_callee_intact() {
+    let install_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_FORWARD_ARGS_INSTALL,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(install_request)
+        .expect_success()
+        .commit();
+
+    let contract_hash: ContractHash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CONTRACT_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .expect("should have contract hash");
+
+    let sent_args = runtime_args! {
+        ARG_CONTRACT_HASH => contract_hash,
+        "amount" => U512::from(7),
+        "memo" => "forwarded".to_string(),
+        "flags" => vec![true, false],
+        "target" => Some(DEFAULT_ACCOUNT_ADDR),
+    };
+    let proxy_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_FORWARD_ARGS_PROXY,
+        sent_args.clone(),
+    )
+    .build();
+    builder.exec(proxy_request).expect_success().commit();
+
+    let received_args_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[RECEIVED_ARGS_KEY]
+        .normalize();
+    let received_args_bytes: Vec<u8> = CLValue::try_from(
+        builder
+            .query(None, received_args_key, &[])
+            .expect("should have received args"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be Vec<u8>");
+    let received_args: RuntimeArgs =
+        bytesrepr::deserialize(received_args_bytes).expect("should deserialize args");
+
+    assert_eq!(received_args, sent_args);
+}
+
+#[ignore]
+#[test]
+fn should_forward_no_args_and_leave_host_buffer_empty() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_FORWARD_ARGS_EMPTY,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let received_args_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[RECEIVED_ARGS_KEY]
+        .normalize();
+    let received_args_bytes: Vec<u8> = CLValue::try_from(
+        builder
+            .query(None, received_args_key, &[])
+            .expect("should have received args"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be Vec<u8>");
+    let received_args: RuntimeArgs =
+        bytesrepr::deserialize(received_args_bytes).expect("should deserialize args");
+
+    assert!(received_args.is_empty());
+}
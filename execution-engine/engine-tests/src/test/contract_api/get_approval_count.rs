@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, runtime_args, CLValue, RuntimeArgs};
+
+const CONTRACT_ADD_UPDATE_ASSOCIATED_KEY: &str = "add_update_associated_key.wasm";
+const CONTRACT_GET_APPROVAL_COUNT: &str = "get_approval_count.wasm";
+const KEY_1_ADDR: AccountHash = AccountHash::new([201; 32]);
+const KEY_2_ADDR: AccountHash = AccountHash::new([202; 32]);
+const ARG_ACCOUNT: &str = "account";
+const APPROVAL_COUNT_KEY: &str = "approval_count";
+
+fn get_approval_count(builder: &InMemoryWasmTestBuilder) -> u32 {
+    let approval_count_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get default account")
+        .named_keys()[APPROVAL_COUNT_KEY]
+        .normalize();
+    CLValue::try_from(
+        builder
+            .query(None, approval_count_key, &[])
+            .expect("should have approval count"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be u32")
+}
+
+#[ignore]
+#[test]
+fn should_get_approval_count_of_single_key_deploy() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_APPROVAL_COUNT,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(get_approval_count(&builder), 1);
+}
+
+#[ignore]
+#[test]
+fn should_get_approval_count_of_multisig_deploy() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_UPDATE_ASSOCIATED_KEY,
+        runtime_args! { ARG_ACCOUNT => KEY_1_ADDR },
+    )
+    .build();
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_UPDATE_ASSOCIATED_KEY,
+        runtime_args! { ARG_ACCOUNT => KEY_2_ADDR },
+    )
+    .build();
+    let exec_request_3 = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(CONTRACT_GET_APPROVAL_COUNT, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT, })
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR, KEY_1_ADDR, KEY_2_ADDR])
+            .with_deploy_hash([42; 32])
+            .build();
+
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit()
+        .exec(exec_request_3)
+        .expect_success()
+        .commit();
+
+    assert_eq!(get_approval_count(&builder), 3);
+}
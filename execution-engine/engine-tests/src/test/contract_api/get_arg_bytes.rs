@@ -0,0 +1,93 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, runtime_args, CLValue, RuntimeArgs, U512};
+
+const CONTRACT_GET_ARG_BYTES: &str = "get_arg_bytes.wasm";
+const CONTRACT_GET_ARG_OVERSIZED_BUFFER: &str = "get_arg_oversized_buffer.wasm";
+const OVERSIZED_BUFFER_SIZE: usize = 64;
+const ARG0_VALUE: &str = "Hello, world!";
+const ARG1_VALUE: u64 = 42;
+
+fn get_stored_arg_bytes(builder: &InMemoryWasmTestBuilder, index: u32) -> Option<Vec<u8>> {
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let key = account.named_keys()[&format!("arg_{}", index)];
+    CLValue::try_from(builder.query(None, key, &[]).expect("should have value"))
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be Option<Vec<u8>>")
+}
+
+#[ignore]
+#[test]
+fn should_read_present_and_absent_raw_args() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_ARG_BYTES,
+        runtime_args! {
+            "value0" => ARG0_VALUE,
+            "value1" => U512::from(ARG1_VALUE),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_stored_arg_bytes(&builder, 0),
+        Some(ARG0_VALUE.to_bytes().unwrap())
+    );
+    assert_eq!(
+        get_stored_arg_bytes(&builder, 1),
+        Some(U512::from(ARG1_VALUE).to_bytes().unwrap())
+    );
+    assert_eq!(get_stored_arg_bytes(&builder, 2), None);
+}
+
+#[ignore]
+#[test]
+fn should_read_args_into_oversized_buffer() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_ARG_OVERSIZED_BUFFER,
+        runtime_args! { "value" => ARG0_VALUE },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let arg_bytes = ARG0_VALUE.to_bytes().unwrap();
+    for name in &["by_index", "by_name"] {
+        let buffer: Vec<u8> = CLValue::try_from(
+            builder
+                .query(None, account.named_keys()[*name], &[])
+                .expect("should have value"),
+        )
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be Vec<u8>");
+
+        // Only the arg's bytes are written, leaving the rest of the buffer untouched.
+        assert_eq!(buffer.len(), OVERSIZED_BUFFER_SIZE);
+        assert_eq!(&buffer[..arg_bytes.len()], arg_bytes.as_slice());
+        assert!(buffer[arg_bytes.len()..].iter().all(|byte| *byte == 0));
+    }
+}
@@ -0,0 +1,59 @@
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, runtime_args, RuntimeArgs};
+
+const CONTRACT_GET_BASE_ACCOUNT_SUBCALL: &str = "get_base_account_subcall.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+
+#[ignore]
+#[test]
+fn should_get_base_account_through_call_chain() {
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(
+            ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_GET_BASE_ACCOUNT_SUBCALL,
+                runtime_args! {"account" => DEFAULT_ACCOUNT_ADDR},
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_get_base_account_through_call_chain_other_account() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(
+            ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+                runtime_args! {"target" => ACCOUNT_1_ADDR, "amount" => *DEFAULT_PAYMENT},
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(
+            ExecuteRequestBuilder::standard(
+                ACCOUNT_1_ADDR,
+                CONTRACT_GET_BASE_ACCOUNT_SUBCALL,
+                runtime_args! {"account" => ACCOUNT_1_ADDR},
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+}
@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, CLValue, RuntimeArgs};
+
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const CONTRACT_DO_NOTHING_STORED_UPGRADER: &str = "do_nothing_stored_upgrader.wasm";
+const CONTRACT_GET_CONTRACT_PACKAGE: &str = "get_contract_package.wasm";
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const UPGRADED_CONTRACT_HASH_KEY_NAME: &str = "end of upgrade";
+const ARG_PACKAGE_HASH: &str = "package_hash";
+const VERSION_COUNT: &str = "version_count";
+const DISABLED_VERSION_COUNT: &str = "disabled_version_count";
+const CURRENT_CONTRACT_HASH: &str = "current_contract_hash";
+
+fn query_u32(builder: &InMemoryWasmTestBuilder, name: &str) -> u32 {
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let key = account.named_keys()[name];
+    CLValue::try_from(builder.query(None, key, &[]).expect("should have value"))
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be u32")
+}
+
+#[ignore]
+#[test]
+fn should_get_contract_package_with_multiple_versions() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for contract in &[
+        CONTRACT_DO_NOTHING_STORED,
+        CONTRACT_DO_NOTHING_STORED_UPGRADER,
+    ] {
+        let exec_request =
+            ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, contract, RuntimeArgs::default())
+                .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let package_hash = account.named_keys()[DO_NOTHING_PACKAGE_HASH_KEY_NAME]
+        .into_hash()
+        .expect("should be a hash");
+    let upgraded_contract_hash = account.named_keys()[UPGRADED_CONTRACT_HASH_KEY_NAME];
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CONTRACT_PACKAGE,
+        runtime_args! { ARG_PACKAGE_HASH => package_hash },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(query_u32(&builder, VERSION_COUNT), 2);
+    assert_eq!(query_u32(&builder, DISABLED_VERSION_COUNT), 0);
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.named_keys()[CURRENT_CONTRACT_HASH],
+        upgraded_contract_hash
+    );
+}
@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::ExecuteRequest;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, CLValue, RuntimeArgs};
+
+const CONTRACT_GET_SEED: &str = "get_seed.wasm";
+const SEED_KEY: &str = "seed";
+const ARG_AMOUNT: &str = "amount";
+
+fn get_seed_request(deploy_hash: [u8; 32]) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash(deploy_hash)
+        .with_session_code(CONTRACT_GET_SEED, RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT, })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn run_get_seed(deploy_hash: [u8; 32]) -> [u8; 32] {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(get_seed_request(deploy_hash))
+        .expect_success()
+        .commit();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let seed_key = default_account.named_keys()[SEED_KEY].normalize();
+    CLValue::try_from(
+        builder
+            .query(None, seed_key, &[])
+            .expect("should have seed"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be [u8; 32]")
+}
+
+#[ignore]
+#[test]
+fn should_get_the_same_seed_for_the_same_deploy() {
+    let first_seed = run_get_seed([1; 32]);
+    let second_seed = run_get_seed([1; 32]);
+    assert_eq!(first_seed, second_seed);
+}
+
+#[ignore]
+#[test]
+fn should_get_different_seeds_for_different_deploys() {
+    let first_seed = run_get_seed([1; 32]);
+    let second_seed = run_get_seed([2; 32]);
+    assert_ne!(first_seed, second_seed);
+}
@@ -0,0 +1,111 @@
+use engine_core::engine_state::{execution_effect::HostCallRecord, EngineConfig};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    contracts::{ContractVersion, CONTRACT_INITIAL_VERSION},
+    runtime_args, ContractPackageHash, RuntimeArgs,
+};
+
+const CONTRACT_CALL_VERSIONED_CONTRACT: &str = "call_versioned_contract.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const CALL_VERSIONED_CONTRACT_HOST_FUNCTION: &str = "call_versioned_contract";
+const MISSING_VERSION: ContractVersion = CONTRACT_INITIAL_VERSION + 4;
+
+fn setup(enable_host_call_tracing: bool) -> (InMemoryWasmTestBuilder, ContractPackageHash) {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_enable_host_call_tracing(enable_host_call_tracing);
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let contract_package_hash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[DO_NOTHING_PACKAGE_HASH_KEY_NAME]
+        .into_hash()
+        .expect("should have package hash");
+
+    (builder, contract_package_hash)
+}
+
+/// Calls the stored contract and returns the recorded host calls to `call_versioned_contract`.
+fn traced_versioned_calls(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_package_hash: ContractPackageHash,
+    version: Option<ContractVersion>,
+) -> Vec<HostCallRecord> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_CALL_VERSIONED_CONTRACT,
+        runtime_args! {
+            "contract_package" => contract_package_hash,
+            "version" => version,
+            "entry_point" => ENTRY_FUNCTION_NAME,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    utils::get_success_result(response)
+        .effect()
+        .host_calls
+        .iter()
+        .filter(|host_call| host_call.name == CALL_VERSIONED_CONTRACT_HOST_FUNCTION)
+        .cloned()
+        .collect()
+}
+
+#[ignore]
+#[test]
+fn should_trace_call_versioned_contract() {
+    let (mut builder, contract_package_hash) = setup(true);
+
+    let host_calls = traced_versioned_calls(&mut builder, contract_package_hash, None);
+    assert_eq!(host_calls.len(), 1, "{:?}", host_calls);
+    assert_eq!(host_calls[0].result, "Some(I32(0))");
+}
+
+#[ignore]
+#[test]
+fn should_trace_failed_call_versioned_contract() {
+    let (mut builder, contract_package_hash) = setup(true);
+
+    let host_calls =
+        traced_versioned_calls(&mut builder, contract_package_hash, Some(MISSING_VERSION));
+    assert_eq!(host_calls.len(), 1, "{:?}", host_calls);
+    assert!(
+        host_calls[0].result.contains("NoSuchVersion"),
+        "{:?}",
+        host_calls[0]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_trace_host_calls_by_default() {
+    let (mut builder, contract_package_hash) = setup(false);
+
+    let host_calls = traced_versioned_calls(&mut builder, contract_package_hash, None);
+    assert!(host_calls.is_empty(), "{:?}", host_calls);
+}
@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, RuntimeArgs};
+
+const CONTRACT_LIST_PURSES: &str = "list_purses.wasm";
+const PURSE_A: &str = "purse:a";
+const PURSE_B: &str = "purse:b";
+const NOT_A_PURSE: &str = "not_a_purse";
+const PURSE_NAMES: &str = "purse_names";
+
+#[ignore]
+#[test]
+fn should_list_only_purses() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_PURSES,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    assert!(default_account.named_keys().contains_key(NOT_A_PURSE));
+
+    let purse_names_key = default_account.named_keys()[PURSE_NAMES].normalize();
+    let purse_names: Vec<String> = CLValue::try_from(
+        builder
+            .query(None, purse_names_key, &[])
+            .expect("should have purse names"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be Vec<String>");
+
+    assert_eq!(purse_names, vec![PURSE_A.to_string(), PURSE_B.to_string()]);
+}
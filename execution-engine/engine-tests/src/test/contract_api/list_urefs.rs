@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, RuntimeArgs, URef};
+
+const CONTRACT_LIST_UREFS: &str = "list_urefs.wasm";
+const CREATED_UREF_KEY: &str = "created_uref";
+const ACCESSIBLE_UREFS_KEY: &str = "accessible_urefs";
+
+fn run_list_urefs(builder: &mut InMemoryWasmTestBuilder) -> (URef, Vec<URef>) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_UREFS,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let named_keys = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get default account")
+        .named_keys()
+        .clone();
+    let created_uref = *named_keys[CREATED_UREF_KEY]
+        .as_uref()
+        .expect("should be a uref");
+    let accessible_urefs = CLValue::try_from(
+        builder
+            .query(None, named_keys[ACCESSIBLE_UREFS_KEY].normalize(), &[])
+            .expect("should have accessible urefs"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be Vec<URef>");
+    (created_uref, accessible_urefs)
+}
+
+#[ignore]
+#[test]
+fn should_list_created_uref_and_main_purse() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let (created_uref, accessible_urefs) = run_list_urefs(&mut builder);
+
+    let main_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get default account")
+        .main_purse();
+    assert!(accessible_urefs.contains(&created_uref));
+    assert!(accessible_urefs.contains(&main_purse));
+}
+
+#[ignore]
+#[test]
+fn should_list_uref_injected_through_named_keys() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let (first_uref, _) = run_list_urefs(&mut builder);
+    let (second_uref, accessible_urefs) = run_list_urefs(&mut builder);
+
+    assert_ne!(first_uref, second_uref);
+    assert!(accessible_urefs.contains(&first_uref));
+    assert!(accessible_urefs.contains(&second_uref));
+}
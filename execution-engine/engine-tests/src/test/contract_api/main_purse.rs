@@ -10,9 +10,12 @@ use types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
 
 const CONTRACT_MAIN_PURSE: &str = "main_purse.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const CONTRACT_TRANSFER_MAIN_PURSE_TO_NEW_PURSE: &str = "transfer_main_purse_to_new_purse.wasm";
 const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const CUSTOM_MAIN_PURSE_NAME: &str = "custom_main_purse";
 const ARG_TARGET: &str = "target";
 const ARG_AMOUNT: &str = "amount";
+const ARG_DESTINATION: &str = "destination";
 
 #[ignore]
 #[test]
@@ -70,3 +73,54 @@ fn should_run_main_purse_contract_account_1() {
 
     builder.exec(exec_request_2).expect_success().commit();
 }
+
+#[ignore]
+#[test]
+fn should_run_main_purse_contract_with_overridden_main_purse() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_MAIN_PURSE_TO_NEW_PURSE,
+        runtime_args! {
+            ARG_DESTINATION => CUSTOM_MAIN_PURSE_NAME,
+            ARG_AMOUNT => *DEFAULT_PAYMENT * 2,
+        },
+    )
+    .build();
+
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get account");
+    let custom_main_purse = default_account.named_keys()[CUSTOM_MAIN_PURSE_NAME]
+        .into_uref()
+        .expect("should be a uref");
+    let stored_main_purse_balance = builder.get_purse_balance(default_account.main_purse());
+    let custom_main_purse_balance = builder.get_purse_balance(custom_main_purse);
+
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_MAIN_PURSE,
+        runtime_args! { "purse" => custom_main_purse },
+    )
+    .build();
+
+    builder
+        .override_main_purse(DEFAULT_ACCOUNT_ADDR, custom_main_purse)
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    // The deploy was paid for from the overriding purse, leaving the stored one untouched.
+    assert_eq!(
+        builder.get_purse_balance(default_account.main_purse()),
+        stored_main_purse_balance
+    );
+    assert!(builder.get_purse_balance(custom_main_purse) < custom_main_purse_balance);
+}
@@ -0,0 +1,63 @@
+use assert_matches::assert_matches;
+
+use engine_core::{
+    engine_state::{EngineConfig, Error},
+    execution,
+};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_GENERATE_EFFECTS: &str = "generate_effects.wasm";
+const ARG_COUNT: &str = "count";
+const MAX_EFFECT_ENTRIES: usize = 100;
+
+fn builder_with_max_effect_entries() -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_max_effect_entries(Some(MAX_EFFECT_ENTRIES));
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    builder
+}
+
+fn generate_effects(builder: &mut InMemoryWasmTestBuilder, count: u32) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GENERATE_EFFECTS,
+        runtime_args! { ARG_COUNT => count },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+#[ignore]
+#[test]
+fn should_allow_effects_within_limit() {
+    let mut builder = builder_with_max_effect_entries();
+    generate_effects(&mut builder, 10);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_fail_with_too_many_effects() {
+    let mut builder = builder_with_max_effect_entries();
+    generate_effects(&mut builder, MAX_EFFECT_ENTRIES as u32 + 1);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::TooManyEffects { max, actual })
+            if *max == MAX_EFFECT_ENTRIES && *actual > MAX_EFFECT_ENTRIES
+    );
+}
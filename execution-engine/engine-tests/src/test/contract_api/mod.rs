@@ -1,16 +1,52 @@
 mod account;
+mod assert_balance_delta;
+mod assert_purse_owned;
+mod call_versioned_contract;
+mod checked_sub_or_revert;
 mod create_purse;
+mod duplicate_named_keys;
+mod finish_early;
+mod forward_all_args;
+mod get_approval_count;
 mod get_arg;
+mod get_arg_bytes;
+mod get_base_account;
 mod get_blocktime;
 mod get_caller;
+mod get_contract_package;
 mod get_phase;
+mod get_purse_balance;
+mod get_seed;
+mod host_call_trace;
 mod list_named_keys;
+mod list_purses;
+mod list_urefs;
 mod main_purse;
+mod max_effect_entries;
 mod mint_purse;
+mod read_contract_value;
+mod read_keys;
+mod reentrancy_lock;
+mod remaining_time;
+mod require_gas_reserve;
 mod revert;
+mod revert_if_balance_below;
+mod revert_on_err;
+mod revert_with_message;
+mod set_balance_cap;
+mod sub_purse_name;
 mod subcall;
+mod swap_purses;
 mod transfer;
+mod transfer_and_put_key;
+mod transfer_effects_ordering;
 mod transfer_purse_to_account;
+mod transfer_purse_to_account_with_policy;
 mod transfer_purse_to_purse;
+mod transfer_purse_to_purse_and_write;
+mod transfer_purse_to_purse_once;
+mod transfer_sentinel_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod tuple_return;
+mod upgrade_contract;
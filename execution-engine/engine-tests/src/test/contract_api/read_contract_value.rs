@@ -0,0 +1,125 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, runtime_args, CLValue, ContractHash, RuntimeArgs, U512};
+
+const CONTRACT_READ_CONTRACT_VALUE_INSTALL: &str = "read_contract_value_install.wasm";
+const CONTRACT_READ_CONTRACT_VALUE: &str = "read_contract_value.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ACCOUNT_1_INITIAL_BALANCE: u64 = 1_000_000_000;
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_NAME: &str = "name";
+const CONTRACT_HASH_KEY: &str = "contract_hash";
+const RESULT_KEY: &str = "result";
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let install_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_CONTRACT_VALUE_INSTALL,
+        RuntimeArgs::default(),
+    )
+    .build();
+    let fund_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! {
+            "target" => ACCOUNT_1_ADDR,
+            "amount" => U512::from(ACCOUNT_1_INITIAL_BALANCE),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(install_request)
+        .expect_success()
+        .commit()
+        .exec(fund_request)
+        .expect_success()
+        .commit();
+
+    let contract_hash: ContractHash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CONTRACT_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .expect("should have contract hash");
+
+    (builder, contract_hash)
+}
+
+fn exec_read_contract_value(
+    builder: &mut InMemoryWasmTestBuilder,
+    account_addr: AccountHash,
+    contract_hash: ContractHash,
+    name: &str,
+) {
+    let read_request = ExecuteRequestBuilder::standard(
+        account_addr,
+        CONTRACT_READ_CONTRACT_VALUE,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_NAME => name.to_string(),
+        },
+    )
+    .build();
+    builder.exec(read_request).commit();
+}
+
+fn read_contract_value(name: &str) -> Option<u64> {
+    let (mut builder, contract_hash) = setup();
+    exec_read_contract_value(&mut builder, DEFAULT_ACCOUNT_ADDR, contract_hash, name);
+    builder.expect_success();
+
+    let result_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[RESULT_KEY]
+        .normalize();
+    CLValue::try_from(
+        builder
+            .query(None, result_key, &[])
+            .expect("should have result"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be Option<u64>")
+}
+
+#[ignore]
+#[test]
+fn should_read_known_contract_value() {
+    assert_eq!(read_contract_value("value"), Some(42));
+}
+
+#[ignore]
+#[test]
+fn should_read_missing_contract_value() {
+    assert_eq!(read_contract_value("missing"), None);
+}
+
+#[ignore]
+#[test]
+fn should_not_read_contract_value_without_access_rights() {
+    let (mut builder, contract_hash) = setup();
+    // Account 1 doesn't hold the URef under which the contract's value is stored.
+    exec_read_contract_value(&mut builder, ACCOUNT_1_ADDR, contract_hash, "value");
+
+    let response = builder
+        .get_exec_response(2)
+        .expect("should have a response");
+    let error_message = utils::get_error_message(response);
+    assert!(
+        error_message.contains("ForgedReference"),
+        "{}",
+        error_message
+    );
+}
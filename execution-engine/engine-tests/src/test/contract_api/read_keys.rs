@@ -0,0 +1,46 @@
+use std::convert::TryFrom;
+
+use engine_shared::stored_value::StoredValue;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key, RuntimeArgs, U512};
+
+const CONTRACT_READ_KEYS: &str = "read_keys.wasm";
+const READ_VALUES: &str = "read_values";
+
+#[ignore]
+#[test]
+fn should_read_present_and_absent_keys() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_READ_KEYS,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let read_values_key: Key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[READ_VALUES];
+    let stored_value: StoredValue = builder
+        .query(None, read_values_key.normalize(), &[])
+        .expect("should have read values");
+    let read_values: Vec<Option<U512>> = CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be Vec<Option<U512>>");
+
+    assert_eq!(
+        read_values,
+        vec![Some(U512::from(10)), None, Some(U512::from(20))]
+    );
+}
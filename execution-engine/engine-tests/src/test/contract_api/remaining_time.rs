@@ -0,0 +1,25 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_REMAINING_TIME: &str = "remaining_time.wasm";
+const ARG_ITERATIONS: &str = "iterations";
+
+#[ignore]
+#[test]
+fn should_decrease_remaining_time_across_host_calls() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REMAINING_TIME,
+        runtime_args! { ARG_ITERATIONS => 10u32 },
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
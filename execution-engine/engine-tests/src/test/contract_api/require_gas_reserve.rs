@@ -0,0 +1,54 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs};
+
+const CONTRACT_REQUIRE_GAS_RESERVE: &str = "require_gas_reserve.wasm";
+const ARG_MIN: &str = "min";
+const INSUFFICIENT_GAS_RESERVE: u16 = 100;
+
+fn run_require_gas_reserve(min: u64) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REQUIRE_GAS_RESERVE,
+        runtime_args! { ARG_MIN => min },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_pass_with_sufficient_gas_reserve() {
+    run_require_gas_reserve(1_000).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_insufficient_gas_reserve() {
+    let builder = run_require_gas_reserve(u64::max_value());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::User(
+            INSUFFICIENT_GAS_RESERVE
+        )))
+    );
+}
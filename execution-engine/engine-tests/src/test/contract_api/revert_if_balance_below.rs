@@ -0,0 +1,54 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs, U512};
+
+const CONTRACT_REVERT_IF_BALANCE_BELOW: &str = "revert_if_balance_below.wasm";
+const ARG_MIN: &str = "min";
+const INSUFFICIENT_BALANCE: u16 = 100;
+
+fn run_revert_if_balance_below(min: U512) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REVERT_IF_BALANCE_BELOW,
+        runtime_args! { ARG_MIN => min },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_continue_with_balance_above_min() {
+    run_revert_if_balance_below(U512::one()).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_balance_below_min() {
+    let builder = run_revert_if_balance_below(U512::max_value());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::User(
+            INSUFFICIENT_BALANCE
+        )))
+    );
+}
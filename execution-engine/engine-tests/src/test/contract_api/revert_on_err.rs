@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, CLValue, RuntimeArgs, U512};
+
+const CONTRACT_REVERT_ON_ERR: &str = "revert_on_err.wasm";
+const ARG_FAIL: &str = "fail";
+const RESULT_KEY: &str = "result";
+const FAILED: u16 = 100;
+
+fn run_revert_on_err(fail: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REVERT_ON_ERR,
+        runtime_args! { ARG_FAIL => fail },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_return_ok_value() {
+    let mut builder = run_revert_on_err(false);
+    builder.expect_success();
+
+    let result_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[RESULT_KEY];
+    let stored_value = builder
+        .query(None, result_key.normalize(), &[])
+        .expect("should have result");
+    let result: U512 = CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be U512");
+    assert_eq!(result, U512::from(42));
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_error_code() {
+    let builder = run_revert_on_err(true);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::User(FAILED)))
+    );
+}
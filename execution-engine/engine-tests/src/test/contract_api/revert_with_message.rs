@@ -0,0 +1,45 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, RuntimeArgs};
+
+const CONTRACT_REVERT_WITH_MESSAGE: &str = "revert_with_message.wasm";
+const REVERT_CODE: u16 = 100;
+const REVERT_MESSAGE: &str = "payment purse balance mismatch";
+
+#[ignore]
+#[test]
+fn should_record_revert_message_in_execution_result() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REVERT_WITH_MESSAGE,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::RevertWithMessage {
+            error: ApiError::User(REVERT_CODE),
+            message,
+        }) if message == REVERT_MESSAGE
+    );
+    assert!(error.to_string().contains(REVERT_MESSAGE));
+}
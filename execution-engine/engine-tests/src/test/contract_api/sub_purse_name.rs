@@ -0,0 +1,47 @@
+use contract::contract_api::system;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, runtime_args, RuntimeArgs};
+
+const CONTRACT_SUB_PURSE_NAME: &str = "sub_purse_name.wasm";
+const ARG_PREFIX: &str = "prefix";
+const ARG_ACCOUNTS: &str = "accounts";
+const PREFIX: &str = "escrow";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ACCOUNT_2_ADDR: AccountHash = AccountHash::new([2u8; 32]);
+
+#[ignore]
+#[test]
+fn should_store_sub_purses_under_derived_names() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SUB_PURSE_NAME,
+        runtime_args! {
+            ARG_PREFIX => PREFIX.to_string(),
+            ARG_ACCOUNTS => vec![ACCOUNT_1_ADDR, ACCOUNT_2_ADDR],
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let purse_1 = account.named_keys()[&system::sub_purse_name(PREFIX, &ACCOUNT_1_ADDR)]
+        .into_uref()
+        .expect("should be uref");
+    let purse_2 = account.named_keys()[&system::sub_purse_name(PREFIX, &ACCOUNT_2_ADDR)]
+        .into_uref()
+        .expect("should be uref");
+    assert_ne!(purse_1.addr(), purse_2.addr());
+    assert!(builder.get_purse_balance(purse_1).is_zero());
+    assert!(builder.get_purse_balance(purse_2).is_zero());
+}
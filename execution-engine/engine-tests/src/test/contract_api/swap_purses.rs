@@ -0,0 +1,119 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::ExecuteRequest;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    runtime_args, ApiError, CLValue, PurseTransferResult, PurseTransferred, RuntimeArgs, U512,
+};
+
+const CONTRACT_SWAP_PURSES: &str = "swap_purses.wasm";
+const PURSE_A: &str = "purse:a";
+const PURSE_B: &str = "purse:b";
+const SWAP_RESULT: &str = "swap_result";
+const FUNDS_A: u64 = 100;
+const FUNDS_B: u64 = 50;
+const ARG_FUNDS_A: &str = "funds_a";
+const ARG_FUNDS_B: &str = "funds_b";
+const ARG_AMOUNT_A: &str = "amount_a";
+const ARG_AMOUNT_B: &str = "amount_b";
+
+fn swap_request(amount_a: u64, amount_b: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SWAP_PURSES,
+        runtime_args! {
+            ARG_FUNDS_A => U512::from(FUNDS_A),
+            ARG_FUNDS_B => U512::from(FUNDS_B),
+            ARG_AMOUNT_A => U512::from(amount_a),
+            ARG_AMOUNT_B => U512::from(amount_b),
+        },
+    )
+    .build()
+}
+
+fn run_swap(amount_a: u64, amount_b: u64) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(swap_request(amount_a, amount_b))
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_swap_result(builder: &InMemoryWasmTestBuilder) -> String {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let swap_result_key = default_account.named_keys()[SWAP_RESULT].normalize();
+    CLValue::try_from(
+        builder
+            .query(None, swap_result_key, &[])
+            .expect("should have swap result"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be String")
+}
+
+fn get_balances(builder: &InMemoryWasmTestBuilder) -> (U512, U512) {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let balance_of = |name: &str| {
+        let purse = default_account.named_keys()[name]
+            .into_uref()
+            .expect("should be a uref");
+        builder.get_purse_balance(purse)
+    };
+    (balance_of(PURSE_A), balance_of(PURSE_B))
+}
+
+#[ignore]
+#[test]
+fn should_swap_purses() {
+    let builder = run_swap(30, 20);
+
+    assert_eq!(
+        get_swap_result(&builder),
+        format!("{:?}", PurseTransferResult::Ok(PurseTransferred::Completed))
+    );
+    assert_eq!(
+        get_balances(&builder),
+        (U512::from(FUNDS_A - 30 + 20), U512::from(FUNDS_B + 30 - 20))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_move_funds_if_first_leg_fails() {
+    let builder = run_swap(FUNDS_A + 1, 20);
+
+    assert_eq!(
+        get_swap_result(&builder),
+        format!("{:?}", PurseTransferResult::Err(ApiError::Transfer))
+    );
+    assert_eq!(
+        get_balances(&builder),
+        (U512::from(FUNDS_A), U512::from(FUNDS_B))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_move_funds_if_second_leg_fails() {
+    // Purse `b` can't cover this even after receiving `amount_a`.
+    let builder = run_swap(30, FUNDS_B + 31);
+
+    assert_eq!(
+        get_swap_result(&builder),
+        format!("{:?}", PurseTransferResult::Err(ApiError::Transfer))
+    );
+    assert_eq!(
+        get_balances(&builder),
+        (U512::from(FUNDS_A), U512::from(FUNDS_B))
+    );
+}
@@ -0,0 +1,94 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    bytesrepr::FromBytes, runtime_args, ApiError, CLTyped, CLValue, PurseTransferResult,
+    PurseTransferred, RuntimeArgs, U512,
+};
+
+const CONTRACT_TRANSFER_AND_PUT_KEY: &str = "transfer_and_put_key.wasm";
+const TARGET_PURSE: &str = "purse:target";
+const ESCROW_RECEIPT: &str = "escrow_receipt";
+const TRANSFER_RESULT: &str = "transfer_result";
+const TRANSFER_AMOUNT: u64 = 42;
+const ARG_AMOUNT: &str = "amount";
+
+fn run_transfer_and_put_key(amount: U512) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_AND_PUT_KEY,
+        runtime_args! { ARG_AMOUNT => amount },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_named_value<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, name: &str) -> T {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let key = default_account.named_keys()[name].normalize();
+    CLValue::try_from(builder.query(None, key, &[]).expect("should have value"))
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should have expected type")
+}
+
+fn get_target_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let target_purse = default_account.named_keys()[TARGET_PURSE]
+        .into_uref()
+        .expect("should be a uref");
+    builder.get_purse_balance(target_purse)
+}
+
+fn has_named_key(builder: &InMemoryWasmTestBuilder, name: &str) -> bool {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .named_keys()
+        .contains_key(name)
+}
+
+#[ignore]
+#[test]
+fn should_put_key_with_successful_transfer() {
+    let builder = run_transfer_and_put_key(U512::from(TRANSFER_AMOUNT));
+
+    let expected_result: PurseTransferResult = Ok(PurseTransferred::Completed);
+    assert_eq!(
+        get_named_value::<String>(&builder, TRANSFER_RESULT),
+        format!("{:?}", expected_result)
+    );
+    assert_eq!(
+        get_named_value::<U512>(&builder, ESCROW_RECEIPT),
+        U512::from(TRANSFER_AMOUNT)
+    );
+    assert_eq!(get_target_balance(&builder), U512::from(TRANSFER_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_not_put_key_with_failed_transfer() {
+    let builder = run_transfer_and_put_key(U512::max_value());
+
+    let expected_result: PurseTransferResult = Err(ApiError::Transfer);
+    assert_eq!(
+        get_named_value::<String>(&builder, TRANSFER_RESULT),
+        format!("{:?}", expected_result)
+    );
+    assert!(!has_named_key(&builder, ESCROW_RECEIPT));
+    assert_eq!(get_target_balance(&builder), U512::zero());
+}
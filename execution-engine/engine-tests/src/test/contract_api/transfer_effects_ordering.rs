@@ -0,0 +1,57 @@
+use engine_core::engine_state::ExecuteRequest;
+use engine_shared::transform::Transform;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, Key, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_MAIN_PURSE_TO_TWO_PURSES: &str = "transfer_main_purse_to_two_purses.wasm";
+const ARG_AMOUNT: &str = "amount";
+const ARG_DESTINATION_PURSE_ONE: &str = "destination_purse_one";
+const ARG_DESTINATION_PURSE_TWO: &str = "destination_purse_two";
+const ARG_TRANSFER_AMOUNT_ONE: &str = "transfer_amount_one";
+const ARG_TRANSFER_AMOUNT_TWO: &str = "transfer_amount_two";
+
+fn transfer_request(deploy_hash: [u8; 32]) -> ExecuteRequest {
+    let session_args = runtime_args! {
+        ARG_DESTINATION_PURSE_ONE => "purse:1".to_string(),
+        ARG_TRANSFER_AMOUNT_ONE => U512::from(1000),
+        ARG_DESTINATION_PURSE_TWO => "purse:2".to_string(),
+        ARG_TRANSFER_AMOUNT_TWO => U512::from(2000),
+    };
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash(deploy_hash)
+        .with_session_code(CONTRACT_TRANSFER_MAIN_PURSE_TO_TWO_PURSES, session_args)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT, })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn run_transfers(deploy_hash: [u8; 32]) -> Vec<(Key, Transform)> {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(transfer_request(deploy_hash))
+        .expect_success()
+        .commit();
+
+    builder.get_transforms()[0].clone().into_iter().collect()
+}
+
+#[ignore]
+#[test]
+fn should_order_transfer_effects_deterministically() {
+    let first_effects = run_transfers([1; 32]);
+    assert!(!first_effects.is_empty());
+
+    for _ in 0..5 {
+        assert_eq!(run_transfers([1; 32]), first_effects);
+    }
+}
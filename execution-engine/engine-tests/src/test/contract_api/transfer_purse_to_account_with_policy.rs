@@ -0,0 +1,71 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, runtime_args, ApiError, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT_WITH_POLICY: &str =
+    "transfer_purse_to_account_with_policy.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_CREATE_IF_MISSING: &str = "create_if_missing";
+const TRANSFER_AMOUNT: u64 = 42;
+
+fn run_transfer(create_if_missing: bool) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT_WITH_POLICY,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            ARG_CREATE_IF_MISSING => create_if_missing,
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_create_missing_account_when_allowed() {
+    let mut builder = run_transfer(true);
+    builder.expect_success();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should create account");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        U512::from(TRANSFER_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_transfer_to_missing_account_when_not_allowed() {
+    let builder = run_transfer(false);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::TargetAccountMissing))
+    );
+
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+}
@@ -11,6 +11,7 @@ use engine_test_support::{
 };
 
 const CONTRACT_TRANSFER_PURSE_TO_PURSE: &str = "transfer_purse_to_purse.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_READ_ONLY_PURSE: &str = "transfer_purse_to_read_only_purse.wasm";
 const PURSE_TO_PURSE_AMOUNT: u64 = 42;
 const ARG_SOURCE: &str = "source";
 const ARG_TARGET: &str = "target";
@@ -199,3 +200,117 @@ fn should_run_purse_to_purse_transfer_with_error() {
         U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - *DEFAULT_PAYMENT
     );
 }
+
+#[ignore]
+#[test]
+fn should_not_transfer_to_read_only_target_purse() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_READ_ONLY_PURSE,
+        runtime_args! { ARG_AMOUNT => U512::from(PURSE_TO_PURSE_AMOUNT) },
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+
+    let purse_transfer_result_key =
+        default_account.named_keys()["purse_transfer_result"].normalize();
+    let purse_transfer_result = CLValue::try_from(
+        builder
+            .query(None, purse_transfer_result_key, &[])
+            .expect("should have purse transfer result"),
+    )
+    .expect("should be a CLValue")
+    .into_t::<String>()
+    .expect("should be String");
+    assert_eq!(
+        purse_transfer_result,
+        format!("{:?}", Result::<(), _>::Err(ApiError::TargetNotWritable)),
+    );
+
+    let target_purse = default_account.named_keys()["purse:target"]
+        .into_uref()
+        .expect("should be a uref");
+    assert_eq!(builder.get_purse_balance(target_purse), U512::zero());
+}
+
+#[ignore]
+#[test]
+fn should_record_balances_before_and_after_purse_to_purse_transfer() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_PURSE,
+        runtime_args! {
+            ARG_SOURCE => "purse:main".to_string(),
+            ARG_TARGET => "purse:secondary".to_string(),
+            ARG_AMOUNT => U512::from(PURSE_TO_PURSE_AMOUNT)
+        },
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .finish();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let main_purse = default_account.main_purse();
+    let secondary_purse = default_account.named_keys()["purse:secondary"]
+        .into_uref()
+        .expect("should be a uref");
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have exec response");
+    let transfers = &response[0].effect().transfers;
+
+    // Standard payment moves the payment amount out of the main purse first.
+    let payment_transfer = &transfers[0];
+    assert_eq!(payment_transfer.source.addr(), main_purse.addr());
+    assert_eq!(payment_transfer.amount, *DEFAULT_PAYMENT);
+    assert_eq!(
+        payment_transfer.source_balance_before,
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE)
+    );
+    assert_eq!(
+        payment_transfer.source_balance_after,
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - *DEFAULT_PAYMENT
+    );
+    assert_eq!(
+        payment_transfer.target_balance_after,
+        payment_transfer.target_balance_before + *DEFAULT_PAYMENT
+    );
+
+    let session_transfer = transfers
+        .iter()
+        .find(|transfer| transfer.target.addr() == secondary_purse.addr())
+        .expect("should record session transfer");
+    assert_eq!(session_transfer.source.addr(), main_purse.addr());
+    assert_eq!(session_transfer.amount, U512::from(PURSE_TO_PURSE_AMOUNT));
+    assert_eq!(
+        session_transfer.source_balance_before,
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - *DEFAULT_PAYMENT
+    );
+    assert_eq!(
+        session_transfer.source_balance_after,
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - *DEFAULT_PAYMENT - PURSE_TO_PURSE_AMOUNT
+    );
+    assert_eq!(session_transfer.target_balance_before, U512::zero());
+    assert_eq!(
+        session_transfer.target_balance_after,
+        U512::from(PURSE_TO_PURSE_AMOUNT)
+    );
+}
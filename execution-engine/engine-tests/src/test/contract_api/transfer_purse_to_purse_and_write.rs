@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::FromBytes, runtime_args, ApiError, CLTyped, CLValue, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_PURSE_AND_WRITE: &str = "transfer_purse_to_purse_and_write.wasm";
+const TARGET_PURSE: &str = "purse:target";
+const INVOICE_PAID: &str = "invoice_paid";
+const TRANSFER_RESULT: &str = "transfer_result";
+const TRANSFER_AMOUNT: u64 = 42;
+const ARG_AMOUNT: &str = "amount";
+
+fn run_transfer_and_write(amount: U512) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_PURSE_AND_WRITE,
+        runtime_args! { ARG_AMOUNT => amount },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_named_value<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, name: &str) -> T {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let key = default_account.named_keys()[name].normalize();
+    CLValue::try_from(builder.query(None, key, &[]).expect("should have value"))
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should have expected type")
+}
+
+fn get_target_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let target_purse = default_account.named_keys()[TARGET_PURSE]
+        .into_uref()
+        .expect("should be a uref");
+    builder.get_purse_balance(target_purse)
+}
+
+#[ignore]
+#[test]
+fn should_write_marker_with_successful_transfer() {
+    let builder = run_transfer_and_write(U512::from(TRANSFER_AMOUNT));
+
+    assert_eq!(
+        get_named_value::<String>(&builder, TRANSFER_RESULT),
+        format!("{:?}", Result::<(), ApiError>::Ok(()))
+    );
+    assert!(get_named_value::<bool>(&builder, INVOICE_PAID));
+    assert_eq!(get_target_balance(&builder), U512::from(TRANSFER_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_not_write_marker_with_failed_transfer() {
+    let builder = run_transfer_and_write(U512::max_value());
+
+    assert_eq!(
+        get_named_value::<String>(&builder, TRANSFER_RESULT),
+        format!("{:?}", Result::<(), ApiError>::Err(ApiError::Transfer))
+    );
+    assert!(!get_named_value::<bool>(&builder, INVOICE_PAID));
+    assert_eq!(get_target_balance(&builder), U512::zero());
+}
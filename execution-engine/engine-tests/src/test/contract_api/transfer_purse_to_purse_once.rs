@@ -0,0 +1,142 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::ExecuteRequest;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    runtime_args, ApiError, CLValue, PurseTransferResult, PurseTransferred, RuntimeArgs, U512,
+};
+
+const CONTRACT_TRANSFER_PURSE_TO_PURSE_ONCE: &str = "transfer_purse_to_purse_once.wasm";
+const TARGET_PURSE: &str = "purse:target";
+const TRANSFER_RESULT: &str = "transfer_result";
+const TRANSFER_AMOUNT: u64 = 42;
+const TRANSFER_KEY: [u8; 32] = [7u8; 32];
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const ARG_TRANSFER_KEY: &str = "transfer_key";
+
+fn transfer_once_request(transfer_key: [u8; 32]) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_PURSE_ONCE,
+        runtime_args! {
+            ARG_TARGET => TARGET_PURSE,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+            ARG_TRANSFER_KEY => transfer_key,
+        },
+    )
+    .build()
+}
+
+fn get_transfer_result(builder: &InMemoryWasmTestBuilder) -> String {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let transfer_result_key = default_account.named_keys()[TRANSFER_RESULT].normalize();
+    CLValue::try_from(
+        builder
+            .query(None, transfer_result_key, &[])
+            .expect("should have transfer result"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be String")
+}
+
+fn get_target_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let target_purse = default_account.named_keys()[TARGET_PURSE]
+        .into_uref()
+        .expect("should be a uref");
+    builder.get_purse_balance(target_purse)
+}
+
+#[ignore]
+#[test]
+fn should_transfer_only_once_for_the_same_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(transfer_once_request(TRANSFER_KEY))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_transfer_result(&builder),
+        format!("{:?}", PurseTransferResult::Ok(PurseTransferred::Completed))
+    );
+    assert_eq!(get_target_balance(&builder), U512::from(TRANSFER_AMOUNT));
+
+    builder
+        .exec(transfer_once_request(TRANSFER_KEY))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_transfer_result(&builder),
+        format!("{:?}", PurseTransferResult::Ok(PurseTransferred::Duplicate))
+    );
+    assert_eq!(get_target_balance(&builder), U512::from(TRANSFER_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_transfer_again_for_a_different_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(transfer_once_request(TRANSFER_KEY))
+        .expect_success()
+        .commit()
+        .exec(transfer_once_request([8u8; 32]))
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_transfer_result(&builder),
+        format!("{:?}", PurseTransferResult::Ok(PurseTransferred::Completed))
+    );
+    assert_eq!(
+        get_target_balance(&builder),
+        U512::from(2 * TRANSFER_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_record_key_of_failed_transfer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(
+            ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_TRANSFER_PURSE_TO_PURSE_ONCE,
+                runtime_args! {
+                    ARG_TARGET => TARGET_PURSE,
+                    ARG_AMOUNT => U512::max_value(),
+                    ARG_TRANSFER_KEY => TRANSFER_KEY,
+                },
+            )
+            .build(),
+        )
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        get_transfer_result(&builder),
+        format!("{:?}", PurseTransferResult::Err(ApiError::Transfer))
+    );
+
+    builder
+        .exec(transfer_once_request(TRANSFER_KEY))
+        .expect_success()
+        .commit();
+
+    assert_eq!(get_target_balance(&builder), U512::from(TRANSFER_AMOUNT));
+}
@@ -0,0 +1,56 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, RuntimeArgs, U512};
+
+const CONTRACT_TRANSFER_SENTINEL_PURSE: &str = "transfer_sentinel_purse.wasm";
+const ARG_SENTINEL_AS_SOURCE: &str = "sentinel_as_source";
+const ARG_AMOUNT: &str = "amount";
+const TRANSFER_AMOUNT: u64 = 42;
+
+fn assert_sentinel_transfer_rejected(sentinel_as_source: bool) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_SENTINEL_PURSE,
+        runtime_args! {
+            ARG_SENTINEL_AS_SOURCE => sentinel_as_source,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::Transfer))
+    );
+    assert!(execution_result.effect().transfers.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_reject_transfer_from_sentinel_purse() {
+    assert_sentinel_transfer_rejected(true);
+}
+
+#[ignore]
+#[test]
+fn should_reject_transfer_to_sentinel_purse() {
+    assert_sentinel_transfer_rejected(false);
+}
@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, CLValue, ContractHash, RuntimeArgs, U512};
+
+const CONTRACT_TUPLE_RETURN_INSTALL: &str = "tuple_return_install.wasm";
+const CONTRACT_TUPLE_RETURN_CALLER: &str = "tuple_return_caller.wasm";
+const ARG_AMOUNT: &str = "amount";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const CONTRACT_HASH_KEY: &str = "contract_hash";
+const PURSE_NAME: &str = "purse";
+const RETURNED_BALANCE_KEY: &str = "returned_balance";
+const RETURNED_PURSE_KEY: &str = "returned_purse";
+const PURSE_AMOUNT: u64 = 100;
+
+#[ignore]
+#[test]
+fn should_read_tuple_returned_by_callee() {
+    let install_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TUPLE_RETURN_INSTALL,
+        runtime_args! { ARG_AMOUNT => U512::from(PURSE_AMOUNT) },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(install_request)
+        .expect_success()
+        .commit();
+
+    let contract_hash: ContractHash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CONTRACT_HASH_KEY)
+        .and_then(|key| key.into_hash())
+        .expect("should have contract hash");
+
+    let call_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TUPLE_RETURN_CALLER,
+        runtime_args! { ARG_CONTRACT_HASH => contract_hash },
+    )
+    .build();
+    builder.exec(call_request).expect_success().commit();
+
+    let named_keys = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .clone();
+    let returned_balance: U512 = CLValue::try_from(
+        builder
+            .query(None, named_keys[RETURNED_BALANCE_KEY].normalize(), &[])
+            .expect("should have returned balance"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be U512");
+    assert_eq!(returned_balance, U512::from(PURSE_AMOUNT));
+
+    let contract_purse = builder
+        .get_contract(contract_hash)
+        .expect("should have contract")
+        .named_keys()[PURSE_NAME];
+    assert_eq!(named_keys[RETURNED_PURSE_KEY], contract_purse);
+}
@@ -0,0 +1,117 @@
+use std::convert::TryFrom;
+
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::AccountHash, contracts::ContractVersion, runtime_args, ApiError, CLValue,
+    ContractPackageHash, RuntimeArgs, U512,
+};
+
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const CONTRACT_UPGRADE_CONTRACT: &str = "upgrade_contract.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const PACKAGE_HASH_KEY_NAME: &str = "do_nothing_package_hash";
+const UPGRADED_CONTRACT_VERSION: &str = "upgraded_contract_version";
+const ARG_PACKAGE_HASH: &str = "package_hash";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractPackageHash) {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => *DEFAULT_PAYMENT + U512::from(100),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    let package_hash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .named_keys()[PACKAGE_HASH_KEY_NAME]
+        .into_hash()
+        .expect("should be hash");
+
+    (builder, package_hash)
+}
+
+#[ignore]
+#[test]
+fn should_upgrade_contract_with_authorized_caller() {
+    let (mut builder, package_hash) = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_UPGRADE_CONTRACT,
+        runtime_args! { ARG_PACKAGE_HASH => package_hash },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let version_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .named_keys()[UPGRADED_CONTRACT_VERSION]
+        .normalize();
+    let version: ContractVersion = CLValue::try_from(
+        builder
+            .query(None, version_key, &[])
+            .expect("should have version"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be ContractVersion");
+    assert_eq!(version, 2);
+}
+
+#[ignore]
+#[test]
+fn should_not_upgrade_contract_with_unauthorized_caller() {
+    let (mut builder, package_hash) = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_UPGRADE_CONTRACT,
+        runtime_args! { ARG_PACKAGE_HASH => package_hash },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(2)
+        .expect("there should be a response");
+    let execution_result = utils::get_success_result(response);
+    let error = execution_result.as_error().expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::UpgradeNotAuthorized))
+    );
+}
@@ -0,0 +1,97 @@
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+const ARG_TARGET: &str = "target";
+const MODULE_BYTES: &str = "ModuleBytes";
+const TRANSFER: &str = "Transfer";
+
+#[ignore]
+#[test]
+fn should_track_gas_statistics_per_variant() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    assert!(builder
+        .get_engine_state()
+        .gas_statistics()
+        .snapshot()
+        .is_empty());
+
+    let do_nothing_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(do_nothing_request).expect_success().commit();
+    let do_nothing_cost = builder.last_exec_gas_cost();
+
+    let create_account_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => ACCOUNT_1_ADDR, ARG_AMOUNT => *DEFAULT_PAYMENT },
+    )
+    .build();
+    builder
+        .exec(create_account_request)
+        .expect_success()
+        .commit();
+    let create_account_cost = builder.last_exec_gas_cost();
+    assert_ne!(do_nothing_cost, create_account_cost);
+
+    let module_bytes = builder
+        .get_engine_state()
+        .gas_statistics()
+        .get(MODULE_BYTES)
+        .expect("should have module bytes statistics");
+    assert_eq!(module_bytes.count(), 2);
+    assert_eq!(
+        module_bytes.average().value(),
+        (do_nothing_cost.value() + create_account_cost.value()) / 2
+    );
+    assert_eq!(module_bytes.max(), do_nothing_cost.max(create_account_cost));
+    assert!(builder
+        .get_engine_state()
+        .gas_statistics()
+        .get(TRANSFER)
+        .is_none());
+
+    let transfer_request = {
+        let deploy_item = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_empty_payment_bytes(runtime_args! {})
+            .with_transfer_args(runtime_args! {
+                ARG_TARGET => ACCOUNT_1_ADDR,
+                ARG_AMOUNT => U512::from(1000),
+            })
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+    };
+    builder.exec(transfer_request).expect_success().commit();
+    let transfer_cost = builder.last_exec_gas_cost();
+
+    let transfer = builder
+        .get_engine_state()
+        .gas_statistics()
+        .get(TRANSFER)
+        .expect("should have transfer statistics");
+    assert_eq!(transfer.count(), 1);
+    assert_eq!(transfer.average(), transfer_cost);
+    assert_eq!(transfer.max(), transfer_cost);
+
+    let module_bytes_after = builder
+        .get_engine_state()
+        .gas_statistics()
+        .get(MODULE_BYTES)
+        .expect("should have module bytes statistics");
+    assert_eq!(module_bytes_after, module_bytes);
+}
@@ -1,3 +1,8 @@
+mod execution_result_cache;
+mod failure_phase;
+mod gas_statistics;
 mod non_standard_payment;
 mod preconditions;
+mod replay_protection;
+mod stored_args;
 mod stored_contracts;
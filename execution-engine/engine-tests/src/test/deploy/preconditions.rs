@@ -1,10 +1,11 @@
 use assert_matches::assert_matches;
 
 use engine_core::engine_state::Error;
+use engine_shared::gas::Gas;
 use engine_test_support::{
     internal::{
-        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
-        DEFAULT_RUN_GENESIS_REQUEST,
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, VersionSelector,
+        DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -12,6 +13,9 @@ use types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
 
 const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
 const ARG_AMOUNT: &str = "amount";
+const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_HASH_KEY_NAME: &str = "do_nothing_hash";
+const ENTRY_FUNCTION_NAME: &str = "delegate";
 
 #[ignore]
 #[test]
@@ -119,3 +123,170 @@ fn should_raise_precondition_authorization_failure_invalid_authorized_keys() {
     let precondition_failure = utils::get_precondition_failure(response);
     assert_matches!(precondition_failure, Error::Authorization);
 }
+
+#[ignore]
+#[test]
+fn should_exec_session_with_existing_stored_contract_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_STORED_CONTRACT_NAME,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(exec_request_1).expect_success().commit();
+
+    let contract_hash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_HASH_KEY_NAME)
+        .expect("should have do_nothing_hash")
+        .into_hash()
+        .expect("should be hash");
+
+    let exec_request_2 = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_stored_session_hash(contract_hash, ENTRY_FUNCTION_NAME, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_deploy_hash([2; 32])
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request_2).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_no_such_contract_for_nonexistent_stored_contract_hash() {
+    let nonexistent_contract_hash = [255u8; 32];
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_stored_session_hash(
+                nonexistent_contract_hash,
+                ENTRY_FUNCTION_NAME,
+                RuntimeArgs::default(),
+            )
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_deploy_hash([1; 32])
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    let transfer_result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .finish();
+
+    let response = transfer_result
+        .builder()
+        .get_exec_response(0)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(
+        precondition_failure,
+        Error::NoSuchContract(contract_hash) if *contract_hash == nonexistent_contract_hash
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_no_such_contract_package_for_nonexistent_stored_package_hash() {
+    let nonexistent_contract_package_hash = [255u8; 32];
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_stored_versioned_contract_by_hash(
+                nonexistent_contract_package_hash,
+                VersionSelector::Active,
+                ENTRY_FUNCTION_NAME,
+                RuntimeArgs::default(),
+            )
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_deploy_hash([1; 32])
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    let transfer_result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .finish();
+
+    let response = transfer_result
+        .builder()
+        .get_exec_response(0)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(
+        precondition_failure,
+        Error::NoSuchContractPackage(contract_package_hash)
+            if *contract_package_hash == nonexistent_contract_package_hash
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_uncharged_precondition_failure_for_empty_entry_point() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_STORED_CONTRACT_NAME,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    builder.exec(exec_request_1).expect_success().commit();
+
+    let contract_hash = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_HASH_KEY_NAME)
+        .expect("should have do_nothing_hash")
+        .into_hash()
+        .expect("should be hash");
+
+    let exec_request_2 = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_stored_session_hash(contract_hash, "", RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_deploy_hash([2; 32])
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    let response = builder
+        .exec(exec_request_2)
+        .get_exec_response(1)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(precondition_failure, Error::InconsistentDeployItem(_));
+
+    // The deploy is rejected before payment is executed, so nothing is charged.
+    let result = response.first().expect("should have a result");
+    assert_eq!(result.cost(), Gas::default());
+    assert!(result.effect().transforms.is_empty());
+}
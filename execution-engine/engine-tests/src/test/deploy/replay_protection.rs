@@ -0,0 +1,96 @@
+use assert_matches::assert_matches;
+
+use engine_core::engine_state::{deploy_item::DeployItem, replay_protection, EngineConfig, Error};
+use engine_shared::newtypes::Blake2bHash;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, RuntimeArgs};
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const ARG_AMOUNT: &str = "amount";
+const DEPLOY_HASH: [u8; 32] = [42; 32];
+
+fn setup(enable_replay_protection: bool) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(cfg!(feature = "enable-bonding"))
+        .with_enable_replay_protection(enable_replay_protection);
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+    builder
+}
+
+fn do_nothing_deploy() -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(DEPLOY_HASH)
+        .build()
+}
+
+fn exec_do_nothing(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(do_nothing_deploy())
+        .build();
+    builder.exec(exec_request).commit();
+}
+
+#[ignore]
+#[test]
+fn should_reject_deploy_already_executed() {
+    let mut builder = setup(true);
+
+    exec_do_nothing(&mut builder);
+    builder.expect_success();
+    assert!(builder
+        .query(None, replay_protection::receipt_key(&DEPLOY_HASH), &[])
+        .is_ok());
+
+    exec_do_nothing(&mut builder);
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response");
+    let error = utils::get_precondition_failure(response);
+    assert_matches!(
+        error,
+        Error::AlreadyExecuted(deploy_hash) if *deploy_hash == Blake2bHash::from(DEPLOY_HASH)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_reject_deploy_repeated_in_same_request() {
+    let mut builder = setup(true);
+
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(do_nothing_deploy())
+        .push_deploy(do_nothing_deploy())
+        .build();
+    builder.exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    assert!(response[0].is_success());
+    let error = utils::get_precondition_failure(&response[1..]);
+    assert_matches!(error, Error::AlreadyExecuted(_));
+}
+
+#[ignore]
+#[test]
+fn should_rerun_deploy_without_replay_protection() {
+    let mut builder = setup(false);
+
+    exec_do_nothing(&mut builder);
+    builder.expect_success();
+
+    exec_do_nothing(&mut builder);
+    builder.expect_success();
+}
@@ -0,0 +1,208 @@
+use assert_matches::assert_matches;
+
+use engine_core::{engine_state::Error, execution};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, AccessRights, Key, RuntimeArgs, URef,
+    U512,
+};
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_STORE_ARGS: &str = "store_args.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT_STORED: &str = "transfer_purse_to_account_stored.wasm";
+const TRANSFER_PURSE_TO_ACCOUNT_HASH_KEY_NAME: &str = "transfer_purse_to_account_hash";
+const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+const PAY_ENTRY_POINT_NAME: &str = "pay";
+const STORED_ARGS_KEY_NAME: &str = "transfer_args";
+const ARG_NAME: &str = "name";
+const ARG_ARGS: &str = "args";
+const ARG_TARGET: &str = "target";
+const ARG_AMOUNT: &str = "amount";
+const TRANSFER_AMOUNT: u64 = 42;
+
+fn setup() -> (InMemoryWasmTestBuilder, Key) {
+    let transfer_args = runtime_args! {
+        ARG_TARGET => ACCOUNT_1_ADDR,
+        ARG_AMOUNT => U512::from(TRANSFER_AMOUNT),
+    }
+    .into_bytes()
+    .expect("should serialize args");
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT_STORED,
+        RuntimeArgs::default(),
+    )
+    .build();
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_STORE_ARGS,
+        runtime_args! {
+            ARG_NAME => STORED_ARGS_KEY_NAME,
+            ARG_ARGS => transfer_args,
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    let args_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[STORED_ARGS_KEY_NAME];
+
+    (builder, args_key)
+}
+
+fn call_transfer_with_stored_args(builder: &mut InMemoryWasmTestBuilder, args_key: Key) {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_stored_session_named_key(
+            TRANSFER_PURSE_TO_ACCOUNT_HASH_KEY_NAME,
+            TRANSFER_ENTRY_POINT_NAME,
+            RuntimeArgs::default(),
+        )
+        .with_stored_session_args(args_key)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([3; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+    builder.exec(exec_request).commit();
+}
+
+fn exec_expecting_precondition_failure(
+    builder: &mut InMemoryWasmTestBuilder,
+    deploy_item_builder: DeployItemBuilder,
+) -> Error {
+    let deploy = deploy_item_builder
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([3; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("there should be a response");
+    utils::get_precondition_failure(response).clone()
+}
+
+#[ignore]
+#[test]
+fn should_call_stored_contract_with_args_loaded_from_key() {
+    let (mut builder, args_key) = setup();
+
+    call_transfer_with_stored_args(&mut builder, args_key);
+    builder.expect_success();
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have created account 1");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        U512::from(TRANSFER_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_missing_args_key() {
+    let (mut builder, _) = setup();
+    let missing_args_key = Key::Hash([255; 32]);
+
+    call_transfer_with_stored_args(&mut builder, missing_args_key);
+
+    let response = builder
+        .get_exec_response(2)
+        .expect("there should be a response");
+    let precondition_failure = utils::get_precondition_failure(response);
+    assert_matches!(
+        precondition_failure,
+        Error::Exec(execution::Error::KeyNotFound(key)) if *key == missing_args_key
+    );
+    assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_inaccessible_args_key() {
+    let (mut builder, args_key) = setup();
+    let args_uref = args_key.into_uref().expect("should be a uref");
+    let forged_args_keys = vec![
+        Key::URef(URef::new([255; 32], AccessRights::READ)),
+        Key::Account(ACCOUNT_1_ADDR),
+    ];
+
+    for forged_args_key in forged_args_keys {
+        let deploy_item_builder = DeployItemBuilder::new()
+            .with_stored_session_named_key(
+                TRANSFER_PURSE_TO_ACCOUNT_HASH_KEY_NAME,
+                TRANSFER_ENTRY_POINT_NAME,
+                RuntimeArgs::default(),
+            )
+            .with_stored_session_args(forged_args_key)
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT });
+
+        let precondition_failure =
+            exec_expecting_precondition_failure(&mut builder, deploy_item_builder);
+        assert_matches!(precondition_failure, Error::InvalidStoredArgs(_));
+    }
+
+    // The args key as held in the account's named keys is accessible, even with fewer rights.
+    call_transfer_with_stored_args(
+        &mut builder,
+        Key::URef(args_uref.with_access_rights(AccessRights::READ)),
+    );
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_stored_args_in_session_module_bytes() {
+    let (mut builder, args_key) = setup();
+
+    let deploy_item_builder = DeployItemBuilder::new()
+        .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+        .with_stored_session_args(args_key)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT });
+
+    let precondition_failure =
+        exec_expecting_precondition_failure(&mut builder, deploy_item_builder);
+    assert_matches!(precondition_failure, Error::InvalidStoredArgs(_));
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_stored_args_in_payment() {
+    let (mut builder, args_key) = setup();
+
+    let deploy_item_builder = DeployItemBuilder::new()
+        .with_session_code(CONTRACT_DO_NOTHING, RuntimeArgs::default())
+        .with_stored_payment_named_key(
+            TRANSFER_PURSE_TO_ACCOUNT_HASH_KEY_NAME,
+            PAY_ENTRY_POINT_NAME,
+            RuntimeArgs::default(),
+        )
+        .with_stored_payment_args(args_key);
+
+    let precondition_failure =
+        exec_expecting_precondition_failure(&mut builder, deploy_item_builder);
+    assert_matches!(precondition_failure, Error::InvalidStoredArgs(_));
+}
@@ -39,7 +39,7 @@ const TRANSFER_PURSE_TO_ACCOUNT_STORED_HASH_KEY_NAME: &str = "transfer_purse_to_
 // Currently Error enum that holds this variant is private and can't be used otherwise to compare
 // message
 const EXPECTED_ERROR_MESSAGE: &str = "IncompatibleProtocolMajorVersion { expected: 2, actual: 1 }";
-const EXPECTED_VERSION_ERROR_MESSAGE: &str = "InvalidContractVersion(ContractVersionKey(2, 1))";
+const EXPECTED_VERSION_ERROR_MESSAGE: &str = "NoSuchVersion(ContractVersionKey(2, 1))";
 
 const ARG_TARGET: &str = "target";
 const ARG_AMOUNT: &str = "amount";
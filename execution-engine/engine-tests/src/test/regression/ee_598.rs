@@ -9,7 +9,10 @@ use engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::{account::AccountHash, runtime_args, ApiError, RuntimeArgs, U512};
+use types::{
+    account::AccountHash, runtime_args, system_contract_errors::pos::Error as PosError, ApiError,
+    RuntimeArgs, U512,
+};
 
 const ARG_AMOUNT: &str = "amount";
 const ARG_ENTRY_POINT: &str = "entry_point";
@@ -85,9 +88,11 @@ fn should_fail_unboding_more_than_it_was_staked_ee_598_regression() {
             error_message
         );
     } else {
-        // Error::UnbondTooLarge => 7,
         assert!(
-            error_message.contains(&format!("{:?}", ApiError::ProofOfStake(7))),
+            error_message.contains(&format!(
+                "{:?}",
+                ApiError::from(PosError::UnbondExceedsStake)
+            )),
             error_message
         );
     }
@@ -0,0 +1,179 @@
+use engine_core::engine_state::genesis::{GenesisAccount, POS_REWARDS_PURSE};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::AccountHash, runtime_args, system_contract_errors::pos, ApiError, RuntimeArgs, U512,
+};
+
+const CONTRACT_POS_DISTRIBUTE: &str = "pos_distribute.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ARG_AMOUNT: &str = "amount";
+const ARG_AMOUNTS: &str = "amounts";
+const ARG_TARGET: &str = "target";
+
+const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
+const VALIDATOR_1_ADDR: AccountHash = AccountHash::new([42; 32]);
+const VALIDATOR_1_STAKE: u64 = 50_000;
+const VALIDATOR_2_ADDR: AccountHash = AccountHash::new([43; 32]);
+const VALIDATOR_2_STAKE: u64 = 70_000;
+
+fn initialize() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for &(account_hash, stake) in &[
+            (VALIDATOR_1_ADDR, VALIDATOR_1_STAKE),
+            (VALIDATOR_2_ADDR, VALIDATOR_2_STAKE),
+        ] {
+            tmp.push(GenesisAccount::new(
+                account_hash,
+                Motes::new(stake.into()) * Motes::new(2.into()),
+                Motes::new(stake.into()),
+            ));
+        }
+        tmp
+    };
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    // Funding the system account also pays for the deploy, leaving a balance in the rewards purse.
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        runtime_args! { ARG_TARGET => SYSTEM_ADDR, ARG_AMOUNT => *DEFAULT_PAYMENT },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&run_genesis_request)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_pos_rewards_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse = builder.get_pos_contract().named_keys()[POS_REWARDS_PURSE]
+        .into_uref()
+        .expect("should have PoS rewards purse");
+    builder.get_purse_balance(purse)
+}
+
+fn get_main_purse_balance(builder: &InMemoryWasmTestBuilder, account_hash: AccountHash) -> U512 {
+    let account = builder
+        .get_account(account_hash)
+        .expect("should have account");
+    builder.get_purse_balance(account.main_purse())
+}
+
+fn distribute_request(
+    caller: AccountHash,
+    amounts: Vec<(AccountHash, U512)>,
+) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::standard(
+        caller,
+        CONTRACT_POS_DISTRIBUTE,
+        runtime_args! { ARG_AMOUNTS => amounts },
+    )
+}
+
+#[ignore]
+#[test]
+fn should_distribute_rewards_to_validators() {
+    let mut builder = initialize();
+
+    let rewards = get_pos_rewards_purse_balance(&builder);
+    assert!(!rewards.is_zero(), "rewards purse should be funded");
+    let validator_1_reward = rewards / 3;
+    let validator_2_reward = rewards / 2;
+
+    let validator_1_pre_balance = get_main_purse_balance(&builder, VALIDATOR_1_ADDR);
+    let validator_2_pre_balance = get_main_purse_balance(&builder, VALIDATOR_2_ADDR);
+
+    let exec_request = distribute_request(
+        SYSTEM_ADDR,
+        vec![
+            (VALIDATOR_1_ADDR, validator_1_reward),
+            (VALIDATOR_2_ADDR, validator_2_reward),
+        ],
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(
+        get_main_purse_balance(&builder, VALIDATOR_1_ADDR),
+        validator_1_pre_balance + validator_1_reward
+    );
+    assert_eq!(
+        get_main_purse_balance(&builder, VALIDATOR_2_ADDR),
+        validator_2_pre_balance + validator_2_reward
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_distribute_more_than_available_rewards() {
+    let mut builder = initialize();
+
+    let rewards = get_pos_rewards_purse_balance(&builder);
+    let validator_1_pre_balance = get_main_purse_balance(&builder, VALIDATOR_1_ADDR);
+    let validator_2_pre_balance = get_main_purse_balance(&builder, VALIDATOR_2_ADDR);
+
+    let exec_request = distribute_request(
+        SYSTEM_ADDR,
+        vec![(VALIDATOR_1_ADDR, rewards), (VALIDATOR_2_ADDR, U512::one())],
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+    assert!(
+        error_message.contains(&format!(
+            "{:?}",
+            ApiError::ProofOfStake(pos::Error::RewardsOverAllocated as u8)
+        )),
+        "error is {:?}",
+        error_message
+    );
+
+    assert_eq!(
+        get_main_purse_balance(&builder, VALIDATOR_1_ADDR),
+        validator_1_pre_balance
+    );
+    assert_eq!(
+        get_main_purse_balance(&builder, VALIDATOR_2_ADDR),
+        validator_2_pre_balance
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_distribute_from_non_system_account() {
+    let mut builder = initialize();
+
+    let exec_request =
+        distribute_request(DEFAULT_ACCOUNT_ADDR, vec![(VALIDATOR_1_ADDR, U512::one())]).build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+    assert!(
+        error_message.contains(&format!(
+            "{:?}",
+            ApiError::ProofOfStake(pos::Error::SystemFunctionCalledByUserAccount as u8)
+        )),
+        "error is {:?}",
+        error_message
+    );
+}
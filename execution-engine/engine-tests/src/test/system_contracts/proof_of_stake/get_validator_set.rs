@@ -0,0 +1,71 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::AccountHash, CLValue, RuntimeArgs, U512};
+
+const CONTRACT_POS_GET_VALIDATOR_SET: &str = "pos_get_validator_set.wasm";
+const VALIDATOR_SET: &str = "validator_set";
+const VALIDATOR_1_ADDR: AccountHash = AccountHash::new([42; 32]);
+const VALIDATOR_1_STAKE: u64 = 50_000;
+const VALIDATOR_2_ADDR: AccountHash = AccountHash::new([43; 32]);
+const VALIDATOR_2_STAKE: u64 = 70_000;
+
+#[ignore]
+#[test]
+fn should_get_validator_set() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for &(account_hash, stake) in &[
+            (VALIDATOR_1_ADDR, VALIDATOR_1_STAKE),
+            (VALIDATOR_2_ADDR, VALIDATOR_2_STAKE),
+        ] {
+            tmp.push(GenesisAccount::new(
+                account_hash,
+                Motes::new(stake.into()) * Motes::new(2.into()),
+                Motes::new(stake.into()),
+            ));
+        }
+        tmp
+    };
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_GET_VALIDATOR_SET,
+        RuntimeArgs::default(),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&run_genesis_request)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let validator_set_key = default_account.named_keys()[VALIDATOR_SET].normalize();
+    let validator_set: Vec<(AccountHash, U512)> = CLValue::try_from(
+        builder
+            .query(None, validator_set_key, &[])
+            .expect("should have validator set"),
+    )
+    .expect("should be a CLValue")
+    .into_t()
+    .expect("should be a validator set");
+
+    assert_eq!(
+        validator_set,
+        vec![
+            (VALIDATOR_1_ADDR, U512::from(VALIDATOR_1_STAKE)),
+            (VALIDATOR_2_ADDR, U512::from(VALIDATOR_2_STAKE)),
+        ]
+    );
+}
@@ -1,5 +1,7 @@
 mod bonding;
 mod commit_validators;
+mod distribute;
 mod finalize_payment;
 mod get_payment_purse;
+mod get_validator_set;
 mod refund_purse;
@@ -1,6 +1,9 @@
 use lazy_static::lazy_static;
 
-use engine_core::{engine_state::Error as CoreError, execution::Error as ExecError};
+use engine_core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
 use engine_test_support::{
     internal::{
         DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
@@ -9,7 +12,8 @@ use engine_test_support::{
     DEFAULT_ACCOUNT_ADDR,
 };
 use types::{
-    account::AccountHash, runtime_args, AccessRights, ApiError, Key, RuntimeArgs, URef, U512,
+    account::AccountHash, runtime_args, system_contract_errors::mint, AccessRights, ApiError,
+    CLTyped, CLValue, Key, RuntimeArgs, URef, U512,
 };
 
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
@@ -208,6 +212,18 @@ fn should_not_transfer_wasmless_other_purse_to_self_purse() {
     invalid_transfer_wasmless(InvalidWasmlessTransfer::OtherPurseToSelfPurse);
 }
 
+#[ignore]
+#[test]
+fn should_not_transfer_wasmless_to_non_account_key() {
+    invalid_transfer_wasmless(InvalidWasmlessTransfer::TargetNotAccountKey);
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_wasmless_malformed_target() {
+    invalid_transfer_wasmless(InvalidWasmlessTransfer::TargetMalformed);
+}
+
 enum InvalidWasmlessTransfer {
     TransferToSelfByAddr,
     TransferToSelfByKey,
@@ -222,6 +238,8 @@ enum InvalidWasmlessTransfer {
     SourceURefNonexistent,
     TargetURefNonexistent,
     OtherPurseToSelfPurse,
+    TargetNotAccountKey,
+    TargetMalformed,
 }
 
 fn invalid_transfer_wasmless(invalid_wasmless_transfer: InvalidWasmlessTransfer) {
@@ -359,6 +377,30 @@ fn invalid_transfer_wasmless(invalid_wasmless_transfer: InvalidWasmlessTransfer)
                 CoreError::Exec(ExecError::ForgedReference(account_2_purse)),
             )
         }
+        InvalidWasmlessTransfer::TargetNotAccountKey => {
+            // passes a key which doesn't refer to an account as target
+            (
+                ACCOUNT_1_ADDR,
+                runtime_args! { ARG_TARGET => Key::Hash([1; 32]), ARG_AMOUNT => transfer_amount },
+                CoreError::InvalidTransferTarget("malformed target of type Key".to_string()),
+            )
+        }
+        InvalidWasmlessTransfer::TargetMalformed => {
+            // passes a target claiming to be an account hash which is too short to be one
+            let mut runtime_args = runtime_args! { ARG_AMOUNT => transfer_amount };
+            runtime_args.insert_cl_value(
+                ARG_TARGET,
+                CLValue::from_components(AccountHash::cl_type(), vec![1; 20]),
+            );
+            (
+                ACCOUNT_1_ADDR,
+                runtime_args,
+                CoreError::InvalidTransferTarget(format!(
+                    "malformed target of type {:?}",
+                    AccountHash::cl_type()
+                )),
+            )
+        }
     };
 
     let no_wasm_transfer_request = {
@@ -493,3 +535,129 @@ fn init_wasmless_transform_builder(create_account_2: bool) -> InMemoryWasmTestBu
         .expect_success()
         .to_owned()
 }
+
+const ACCOUNT_3_ADDR: AccountHash = AccountHash::new([3u8; 32]);
+const ARG_TARGETS: &str = "targets";
+
+fn wasmless_transfer_batch_request(targets: Vec<(AccountHash, U512)>) -> ExecuteRequest {
+    let deploy_item = DeployItemBuilder::new()
+        .with_address(ACCOUNT_1_ADDR)
+        .with_empty_payment_bytes(runtime_args! {})
+        .with_transfer_args(runtime_args! { ARG_TARGETS => targets })
+        .with_authorization_keys(&[ACCOUNT_1_ADDR])
+        .build();
+    ExecuteRequestBuilder::from_deploy_item(deploy_item).build()
+}
+
+#[ignore]
+#[test]
+fn should_transfer_wasmless_batch_to_existing_and_new_accounts() {
+    let create_account_2: bool = true;
+    let mut builder = init_wasmless_transform_builder(create_account_2);
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1")
+        .main_purse();
+    let account_2_purse = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should get account 2")
+        .main_purse();
+    assert_eq!(
+        builder.get_account(ACCOUNT_3_ADDR),
+        None,
+        "account 3 should not exist"
+    );
+
+    let account_1_starting_balance = builder.get_purse_balance(account_1_purse);
+    let account_2_starting_balance = builder.get_purse_balance(account_2_purse);
+
+    // Account 3 appears twice, so must only be created once.
+    let targets = vec![
+        (ACCOUNT_2_ADDR, U512::from(1000)),
+        (ACCOUNT_3_ADDR, U512::from(2000)),
+        (ACCOUNT_3_ADDR, U512::from(500)),
+    ];
+    builder
+        .exec(wasmless_transfer_batch_request(targets))
+        .expect_success()
+        .commit();
+
+    let account_3_purse = builder
+        .get_account(ACCOUNT_3_ADDR)
+        .expect("account 3 should exist")
+        .main_purse();
+
+    assert_eq!(
+        account_1_starting_balance - U512::from(3500),
+        builder.get_purse_balance(account_1_purse),
+        "account 1 ending balance incorrect"
+    );
+    assert_eq!(
+        account_2_starting_balance + U512::from(1000),
+        builder.get_purse_balance(account_2_purse),
+        "account 2 ending balance incorrect"
+    );
+    assert_eq!(
+        U512::from(2500),
+        builder.get_purse_balance(account_3_purse),
+        "account 3 ending balance incorrect"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_wasmless_batch_exceeding_balance() {
+    let create_account_2: bool = true;
+    let mut builder = init_wasmless_transform_builder(create_account_2);
+
+    let account_1_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should get account 1")
+        .main_purse();
+    let account_2_purse = builder
+        .get_account(ACCOUNT_2_ADDR)
+        .expect("should get account 2")
+        .main_purse();
+
+    let account_1_starting_balance = builder.get_purse_balance(account_1_purse);
+    let account_2_starting_balance = builder.get_purse_balance(account_2_purse);
+
+    // Each transfer is affordable on its own, but not both together.
+    let targets = vec![
+        (ACCOUNT_2_ADDR, account_1_starting_balance),
+        (ACCOUNT_3_ADDR, U512::one()),
+    ];
+    builder
+        .exec(wasmless_transfer_batch_request(targets))
+        .commit();
+
+    let result = builder
+        .get_exec_responses()
+        .last()
+        .expect("Expected to be called after run()")
+        .get(0)
+        .expect("Unable to get first deploy result");
+    assert!(result.is_failure(), "was expected to fail");
+    let expected_error = CoreError::Exec(ExecError::Revert(mint::Error::InsufficientFunds.into()));
+    assert_eq!(
+        format!("{}", expected_error),
+        format!("{}", result.as_error().expect("should have error"))
+    );
+
+    assert_eq!(
+        account_1_starting_balance,
+        builder.get_purse_balance(account_1_purse),
+        "account 1 balance should be unchanged"
+    );
+    assert_eq!(
+        account_2_starting_balance,
+        builder.get_purse_balance(account_2_purse),
+        "account 2 balance should be unchanged"
+    );
+    assert_eq!(
+        builder.get_account(ACCOUNT_3_ADDR),
+        None,
+        "account 3 should not be created"
+    );
+}
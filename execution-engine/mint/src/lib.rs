@@ -11,6 +11,13 @@ pub use crate::{runtime_provider::RuntimeProvider, storage_provider::StorageProv
 
 const SYSTEM_ACCOUNT: AccountHash = AccountHash::new([0; 32]);
 
+/// Name of the mint's named key recording that at least one balance cap has been set.  Named keys
+/// are loaded with the mint, so checking it lets transfers skip the cap lookup while no caps exist.
+///
+/// Caps themselves are stored as local keys under the address of the purse's balance URef, since
+/// local keys must be exactly 32 bytes long.
+const BALANCE_CAPS_SET_KEY: &str = "balance_caps_set";
+
 pub trait Mint: RuntimeProvider + StorageProvider {
     fn mint(&mut self, initial_balance: U512) -> Result<URef, Error> {
         let caller = self.get_caller();
@@ -44,6 +51,12 @@ pub trait Mint: RuntimeProvider + StorageProvider {
     }
 
     fn transfer(&mut self, source: URef, target: URef, amount: U512) -> Result<(), Error> {
+        if source.is_sentinel() {
+            return Err(Error::SourceNotFound);
+        }
+        if target.is_sentinel() {
+            return Err(Error::DestNotFound);
+        }
         if !source.is_writeable() || !target.is_addable() {
             return Err(Error::InvalidAccessRights);
         }
@@ -62,8 +75,36 @@ pub trait Mint: RuntimeProvider + StorageProvider {
             Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
             None => return Err(Error::DestNotFound),
         };
+        let maybe_cap: Option<U512> = if self.has_key(BALANCE_CAPS_SET_KEY) {
+            self.read_local(&target_balance.addr())?
+        } else {
+            None
+        };
+        if let Some(cap) = maybe_cap {
+            let target_value: U512 = self.read(target_balance)?.ok_or(Error::DestNotFound)?;
+            match target_value.checked_add(amount) {
+                Some(new_target_value) if new_target_value <= cap => {}
+                _ => return Err(Error::BalanceCapExceeded),
+            }
+        }
         self.write(source_balance, source_value - amount)?;
         self.add(target_balance, amount)?;
         Ok(())
     }
+
+    fn set_balance_cap(&mut self, purse: URef, cap: U512) -> Result<(), Error> {
+        if !purse.is_writeable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance_uref: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::PurseNotFound),
+        };
+        self.write_local(balance_uref.addr(), cap);
+        if !self.has_key(BALANCE_CAPS_SET_KEY) {
+            let flag_key: Key = self.new_uref(()).into();
+            self.put_key(BALANCE_CAPS_SET_KEY, flag_key);
+        }
+        Ok(())
+    }
 }
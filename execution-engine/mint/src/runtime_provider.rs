@@ -4,4 +4,6 @@ pub trait RuntimeProvider {
     fn get_caller(&self) -> AccountHash;
 
     fn put_key(&mut self, name: &str, key: Key);
+
+    fn has_key(&self, name: &str) -> bool;
 }
@@ -9,6 +9,7 @@ mod runtime_provider;
 mod stakes;
 mod stakes_provider;
 
+use alloc::vec::Vec;
 use core::marker::Sized;
 
 use types::{
@@ -83,6 +84,20 @@ pub trait ProofOfStake:
     fn finalize_payment(&mut self, amount_spent: U512, account: AccountHash) -> Result<()> {
         internal::finalize_payment(self, amount_spent, account)
     }
+
+    /// Returns the current validators along with their stakes.
+    fn get_validator_set(&self) -> Result<Vec<(AccountHash, U512)>> {
+        let stakes = self.read()?;
+        Ok(stakes
+            .iter()
+            .map(|(validator, stake)| (*validator, *stake))
+            .collect())
+    }
+
+    /// Pays each bonded validator its given share of the rewards purse.
+    fn distribute(&mut self, amounts: Vec<(AccountHash, U512)>) -> Result<()> {
+        internal::distribute(self, amounts)
+    }
 }
 
 mod internal {
@@ -310,6 +325,43 @@ mod internal {
         Ok(())
     }
 
+    /// Transfers the given amounts from the rewards purse to each validator's account.  This can
+    /// only be called by the system account, and fails without transferring anything if any of
+    /// the recipients isn't bonded or the amounts add up to more than the rewards purse holds.
+    pub fn distribute<P: MintProvider + RuntimeProvider + StakesProvider>(
+        provider: &mut P,
+        amounts: Vec<(AccountHash, U512)>,
+    ) -> Result<()> {
+        let caller = provider.get_caller();
+        if caller != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+
+        let stakes = provider.read()?;
+        let mut total = U512::zero();
+        for (validator, amount) in &amounts {
+            if stakes.0.get(validator).is_none() {
+                return Err(Error::NotBonded);
+            }
+            total = total
+                .checked_add(*amount)
+                .ok_or(Error::RewardsOverAllocated)?;
+        }
+
+        let rewards_purse = get_rewards_purse(provider)?;
+        let available = provider.balance(rewards_purse).unwrap_or_default();
+        if total > available {
+            return Err(Error::RewardsOverAllocated);
+        }
+
+        for (validator, amount) in amounts {
+            provider
+                .transfer_purse_to_account(rewards_purse, validator, amount)
+                .map_err(|_| Error::FailedTransferFromRewardsPurse)?;
+        }
+        Ok(())
+    }
+
     pub fn refund_to_account<M: MintProvider>(
         mint_provider: &mut M,
         payment_purse: URef,
@@ -79,6 +79,9 @@ impl Stakes {
         if let Some(amount) = maybe_amount {
             // The minimum stake value to not violate the maximum spread.
             let stake = self.0.get_mut(validator).ok_or(Error::NotBonded)?;
+            if amount > *stake {
+                return Err(Error::UnbondExceedsStake);
+            }
             if *stake > amount {
                 if *stake - amount < min {
                     return Err(Error::SpreadTooHigh);
@@ -97,12 +100,6 @@ impl Stakes {
         // If the the amount is greater or equal to the stake, remove the validator.
         let stake = self.0.remove(validator).ok_or(Error::NotBonded)?;
 
-        if let Some(amount) = maybe_amount {
-            if amount > stake {
-                return Err(Error::UnbondTooLarge);
-            }
-        }
-
         if stake > min.saturating_add(max_decrease) && stake > max_decrease {
             return Err(Error::UnbondTooLarge);
         }
@@ -259,6 +256,16 @@ mod tests {
         assert_eq!(new_stakes(&[(KEY1, 46)]), stakes);
     }
 
+    #[test]
+    fn test_unbond_exceeding_stake() {
+        let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 50)]);
+        assert_eq!(
+            Err(Error::UnbondExceedsStake),
+            stakes.unbond(&AccountHash::new(KEY1), Some(U512::from(51)))
+        );
+        assert_eq!(new_stakes(&[(KEY1, 50), (KEY2, 50)]), stakes);
+    }
+
     #[test]
     fn test_unbond_too_much_rel() {
         let mut stakes = new_stakes(&[(KEY1, 999), (KEY2, 1)]);
@@ -77,6 +77,7 @@ check_python_has_toml
 
 # These are the subdirs of CasperLabs/execution-engine which contain packages for publishing.  They should remain
 # ordered from least-dependent to most.
+publish types-derive
 publish types
 publish contract
 publish engine-wasm-prep
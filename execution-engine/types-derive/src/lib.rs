@@ -0,0 +1,123 @@
+//! Derive macros for the `casperlabs-types` crate.
+//!
+//! These are re-exported by `casperlabs-types`, and should be used via that crate rather than
+//! directly.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Lit, Meta, NestedMeta,
+    Path,
+};
+
+const ATTRIBUTE_NAME: &str = "runtime_args";
+const DEFAULT_CRATE_PATH: &str = "casperlabs_types";
+
+/// Derives `FromRuntimeArgs` for a struct with named fields, decoding each field from the
+/// runtime argument of the same name.
+///
+/// The generated code refers to the `casperlabs_types` crate.  If that crate is imported under a
+/// different name, specify it with e.g. `#[runtime_args(crate = "types")]`.
+#[proc_macro_derive(FromRuntimeArgs, attributes(runtime_args))]
+pub fn derive_from_runtime_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_runtime_args(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_from_runtime_args(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let crate_path = crate_path(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "FromRuntimeArgs can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "FromRuntimeArgs can only be derived for structs",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let field_names: Vec<_> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let arg_names: Vec<_> = field_names
+        .iter()
+        .map(|field_name| field_name.to_string().trim_start_matches("r#").to_string())
+        .collect();
+    let field_types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    Ok(quote! {
+        impl #impl_generics #crate_path::FromRuntimeArgs for #name #type_generics #where_clause {
+            fn named_arg_types() -> #crate_path::__private::Vec<(
+                #crate_path::__private::String,
+                #crate_path::CLType,
+            )> {
+                let mut named_arg_types = #crate_path::__private::Vec::new();
+                #(
+                    named_arg_types.push((
+                        #crate_path::__private::String::from(#arg_names),
+                        <#field_types as #crate_path::CLTyped>::cl_type(),
+                    ));
+                )*
+                named_arg_types
+            }
+
+            fn from_runtime_args(
+                args: &#crate_path::RuntimeArgs,
+            ) -> ::core::result::Result<Self, #crate_path::RuntimeArgsError> {
+                ::core::result::Result::Ok(#name {
+                    #(
+                        #field_names: args.get_named_arg(#arg_names)?,
+                    )*
+                })
+            }
+        }
+    })
+}
+
+/// Parses the path to the `casperlabs_types` crate from a `#[runtime_args(crate = "...")]`
+/// attribute, if present.
+fn crate_path(input: &DeriveInput) -> Result<Path, Error> {
+    for attr in &input.attrs {
+        if !attr.path.is_ident(ATTRIBUTE_NAME) {
+            continue;
+        }
+        let meta_list = match attr.parse_meta()? {
+            Meta::List(meta_list) => meta_list,
+            meta => return Err(Error::new(meta.span(), "expected #[runtime_args(...)]")),
+        };
+        for nested in meta_list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("crate") =>
+                {
+                    return match name_value.lit {
+                        Lit::Str(lit_str) => lit_str.parse(),
+                        lit => Err(Error::new(lit.span(), "expected a string literal")),
+                    };
+                }
+                nested => return Err(Error::new(nested.span(), "unknown runtime_args attribute")),
+            }
+        }
+    }
+    Ok(Path::from(syn::Ident::new(
+        DEFAULT_CRATE_PATH,
+        Span::call_site(),
+    )))
+}
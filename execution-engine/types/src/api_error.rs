@@ -182,6 +182,18 @@ const HEADER_ERROR_MAX: u32 = HEADER_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 34 => HostBufferFull
 /// # );
+/// # show_and_check!(
+/// 36 => UpgradeNotAuthorized
+/// # );
+/// # show_and_check!(
+/// 37 => TargetNotWritable
+/// # );
+/// # show_and_check!(
+/// 38 => TargetAccountMissing
+/// # );
+/// # show_and_check!(
+/// 39 => DeserializationLimitExceeded
+/// # );
 /// // Contract header errors:
 /// use casperlabs_types::contracts::Error as ContractHeaderError;
 /// # show_and_check!(
@@ -225,6 +237,9 @@ const HEADER_ERROR_MAX: u32 = HEADER_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 65_031 => MintError::PurseNotFound
 /// # );
+/// # show_and_check!(
+/// 65_032 => MintError::BalanceCapExceeded
+/// # );
 ///
 /// // Proof of stake errors:
 /// use casperlabs_types::system_contract_errors::pos::Error as PosError;
@@ -309,6 +324,9 @@ const HEADER_ERROR_MAX: u32 = HEADER_ERROR_OFFSET + u8::MAX as u32;
 /// # show_and_check!(
 /// 65_306 => PosError::SetRefundPurseCalledOutsidePayment
 /// # );
+/// # show_and_check!(
+/// 65_307 => PosError::UnbondExceedsStake
+/// # );
 ///
 /// // User-defined errors:
 /// # show_and_check!(
@@ -426,6 +444,14 @@ pub enum ApiError {
     HostBufferFull,
     /// Could not lay out an array in memory
     AllocLayout,
+    /// The caller is not authorized to upgrade the given contract package.
+    UpgradeNotAuthorized,
+    /// The target purse of a transfer lacks add access rights.
+    TargetNotWritable,
+    /// The target account of a transfer does not exist and was not allowed to be created.
+    TargetAccountMissing,
+    /// A length declared in serialized data exceeded the limit allowed while deserializing.
+    DeserializationLimitExceeded,
     /// Contract header errors.
     ContractHeader(u8),
     /// Error specific to Mint contract.
@@ -444,6 +470,9 @@ impl From<bytesrepr::Error> for ApiError {
             bytesrepr::Error::Formatting => ApiError::Formatting,
             bytesrepr::Error::LeftOverBytes => ApiError::LeftOverBytes,
             bytesrepr::Error::OutOfMemory => ApiError::OutOfMemory,
+            bytesrepr::Error::DeserializationLimitExceeded => {
+                ApiError::DeserializationLimitExceeded
+            }
         }
     }
 }
@@ -568,6 +597,10 @@ impl From<ApiError> for u32 {
             ApiError::HostBufferEmpty => 33,
             ApiError::HostBufferFull => 34,
             ApiError::AllocLayout => 35,
+            ApiError::UpgradeNotAuthorized => 36,
+            ApiError::TargetNotWritable => 37,
+            ApiError::TargetAccountMissing => 38,
+            ApiError::DeserializationLimitExceeded => 39,
             ApiError::ContractHeader(value) => HEADER_ERROR_OFFSET + u32::from(value),
             ApiError::Mint(value) => MINT_ERROR_OFFSET + u32::from(value),
             ApiError::ProofOfStake(value) => POS_ERROR_OFFSET + u32::from(value),
@@ -614,6 +647,10 @@ impl From<u32> for ApiError {
             33 => ApiError::HostBufferEmpty,
             34 => ApiError::HostBufferFull,
             35 => ApiError::AllocLayout,
+            36 => ApiError::UpgradeNotAuthorized,
+            37 => ApiError::TargetNotWritable,
+            38 => ApiError::TargetAccountMissing,
+            39 => ApiError::DeserializationLimitExceeded,
             USER_ERROR_MIN..=USER_ERROR_MAX => ApiError::User(value as u16),
             POS_ERROR_MIN..=POS_ERROR_MAX => ApiError::ProofOfStake(value as u8),
             MINT_ERROR_MIN..=MINT_ERROR_MAX => ApiError::Mint(value as u8),
@@ -663,6 +700,12 @@ impl Debug for ApiError {
             ApiError::HostBufferEmpty => write!(f, "ApiError::HostBufferEmpty")?,
             ApiError::HostBufferFull => write!(f, "ApiError::HostBufferFull")?,
             ApiError::AllocLayout => write!(f, "ApiError::AllocLayout")?,
+            ApiError::UpgradeNotAuthorized => write!(f, "ApiError::UpgradeNotAuthorized")?,
+            ApiError::TargetNotWritable => write!(f, "ApiError::TargetNotWritable")?,
+            ApiError::TargetAccountMissing => write!(f, "ApiError::TargetAccountMissing")?,
+            ApiError::DeserializationLimitExceeded => {
+                write!(f, "ApiError::DeserializationLimitExceeded")?
+            }
             ApiError::ContractHeader(value) => write!(f, "ApiError::ContractHeader({})", value)?,
             ApiError::Mint(value) => write!(f, "ApiError::Mint({})", value)?,
             ApiError::ProofOfStake(value) => write!(f, "ApiError::ProofOfStake({})", value)?,
@@ -821,6 +864,10 @@ mod tests {
         round_trip(Err(ApiError::HostBufferEmpty));
         round_trip(Err(ApiError::HostBufferFull));
         round_trip(Err(ApiError::AllocLayout));
+        round_trip(Err(ApiError::UpgradeNotAuthorized));
+        round_trip(Err(ApiError::TargetNotWritable));
+        round_trip(Err(ApiError::TargetAccountMissing));
+        round_trip(Err(ApiError::DeserializationLimitExceeded));
         round_trip(Err(ApiError::ContractHeader(0)));
         round_trip(Err(ApiError::ContractHeader(u8::MAX)));
         round_trip(Err(ApiError::Mint(0)));
@@ -101,6 +101,9 @@ pub enum Error {
     /// Out of memory error.
     #[fail(display = "Serialization error: out of memory")]
     OutOfMemory,
+    /// A length declared in the input exceeded the limit allowed while deserializing.
+    #[fail(display = "Deserialization error: limit exceeded")]
+    DeserializationLimitExceeded,
 }
 
 #[cfg(not(feature = "no-unstable-features"))]
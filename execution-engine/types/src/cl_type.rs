@@ -34,6 +34,9 @@ const CL_TYPE_TAG_TUPLE2: u8 = 19;
 const CL_TYPE_TAG_TUPLE3: u8 = 20;
 const CL_TYPE_TAG_ANY: u8 = 21;
 
+/// The maximum depth to which `CLType`s may be nested when deserializing.
+const CL_TYPE_RECURSION_DEPTH: u8 = 50;
+
 /// CasperLabs types, i.e. types which can be stored and manipulated by smart contracts.
 ///
 /// Provides a description of the underlying data type of a [`CLValue`](crate::CLValue).
@@ -180,83 +183,93 @@ impl CLType {
     }
 }
 
-#[allow(clippy::cognitive_complexity)]
 impl FromBytes for CLType {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (tag, remainder) = u8::from_bytes(bytes)?;
-        match tag {
-            CL_TYPE_TAG_BOOL => Ok((CLType::Bool, remainder)),
-            CL_TYPE_TAG_I32 => Ok((CLType::I32, remainder)),
-            CL_TYPE_TAG_I64 => Ok((CLType::I64, remainder)),
-            CL_TYPE_TAG_U8 => Ok((CLType::U8, remainder)),
-            CL_TYPE_TAG_U32 => Ok((CLType::U32, remainder)),
-            CL_TYPE_TAG_U64 => Ok((CLType::U64, remainder)),
-            CL_TYPE_TAG_U128 => Ok((CLType::U128, remainder)),
-            CL_TYPE_TAG_U256 => Ok((CLType::U256, remainder)),
-            CL_TYPE_TAG_U512 => Ok((CLType::U512, remainder)),
-            CL_TYPE_TAG_UNIT => Ok((CLType::Unit, remainder)),
-            CL_TYPE_TAG_STRING => Ok((CLType::String, remainder)),
-            CL_TYPE_TAG_KEY => Ok((CLType::Key, remainder)),
-            CL_TYPE_TAG_UREF => Ok((CLType::URef, remainder)),
-            CL_TYPE_TAG_OPTION => {
-                let (inner_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::Option(Box::new(inner_type));
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_LIST => {
-                let (inner_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::List(Box::new(inner_type));
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_FIXED_LIST => {
-                let (inner_type, remainder) = CLType::from_bytes(remainder)?;
-                let (len, remainder) = u32::from_bytes(remainder)?;
-                let cl_type = CLType::FixedList(Box::new(inner_type), len);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_RESULT => {
-                let (ok_type, remainder) = CLType::from_bytes(remainder)?;
-                let (err_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::Result {
-                    ok: Box::new(ok_type),
-                    err: Box::new(err_type),
-                };
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_MAP => {
-                let (key_type, remainder) = CLType::from_bytes(remainder)?;
-                let (value_type, remainder) = CLType::from_bytes(remainder)?;
-                let cl_type = CLType::Map {
-                    key: Box::new(key_type),
-                    value: Box::new(value_type),
-                };
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_TUPLE1 => {
-                let (mut inner_types, remainder) = parse_cl_tuple_types(1, remainder)?;
-                let cl_type = CLType::Tuple1([inner_types.pop_front().unwrap()]);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_TUPLE2 => {
-                let (mut inner_types, remainder) = parse_cl_tuple_types(2, remainder)?;
-                let cl_type = CLType::Tuple2([
-                    inner_types.pop_front().unwrap(),
-                    inner_types.pop_front().unwrap(),
-                ]);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_TUPLE3 => {
-                let (mut inner_types, remainder) = parse_cl_tuple_types(3, remainder)?;
-                let cl_type = CLType::Tuple3([
-                    inner_types.pop_front().unwrap(),
-                    inner_types.pop_front().unwrap(),
-                    inner_types.pop_front().unwrap(),
-                ]);
-                Ok((cl_type, remainder))
-            }
-            CL_TYPE_TAG_ANY => Ok((CLType::Any, remainder)),
-            _ => Err(bytesrepr::Error::Formatting),
+        depth_limited_from_bytes(0, bytes)
+    }
+}
+
+/// Parses a `CLType`, failing with `DeserializationLimitExceeded` if it nests more than
+/// [`CL_TYPE_RECURSION_DEPTH`] types deep rather than recursing without a bound.
+#[allow(clippy::cognitive_complexity)]
+fn depth_limited_from_bytes(depth: u8, bytes: &[u8]) -> Result<(CLType, &[u8]), bytesrepr::Error> {
+    if depth >= CL_TYPE_RECURSION_DEPTH {
+        return Err(bytesrepr::Error::DeserializationLimitExceeded);
+    }
+    let depth = depth + 1;
+    let (tag, remainder) = u8::from_bytes(bytes)?;
+    match tag {
+        CL_TYPE_TAG_BOOL => Ok((CLType::Bool, remainder)),
+        CL_TYPE_TAG_I32 => Ok((CLType::I32, remainder)),
+        CL_TYPE_TAG_I64 => Ok((CLType::I64, remainder)),
+        CL_TYPE_TAG_U8 => Ok((CLType::U8, remainder)),
+        CL_TYPE_TAG_U32 => Ok((CLType::U32, remainder)),
+        CL_TYPE_TAG_U64 => Ok((CLType::U64, remainder)),
+        CL_TYPE_TAG_U128 => Ok((CLType::U128, remainder)),
+        CL_TYPE_TAG_U256 => Ok((CLType::U256, remainder)),
+        CL_TYPE_TAG_U512 => Ok((CLType::U512, remainder)),
+        CL_TYPE_TAG_UNIT => Ok((CLType::Unit, remainder)),
+        CL_TYPE_TAG_STRING => Ok((CLType::String, remainder)),
+        CL_TYPE_TAG_KEY => Ok((CLType::Key, remainder)),
+        CL_TYPE_TAG_UREF => Ok((CLType::URef, remainder)),
+        CL_TYPE_TAG_OPTION => {
+            let (inner_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::Option(Box::new(inner_type));
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_LIST => {
+            let (inner_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::List(Box::new(inner_type));
+            Ok((cl_type, remainder))
         }
+        CL_TYPE_TAG_FIXED_LIST => {
+            let (inner_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let (len, remainder) = u32::from_bytes(remainder)?;
+            let cl_type = CLType::FixedList(Box::new(inner_type), len);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_RESULT => {
+            let (ok_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let (err_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::Result {
+                ok: Box::new(ok_type),
+                err: Box::new(err_type),
+            };
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_MAP => {
+            let (key_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let (value_type, remainder) = depth_limited_from_bytes(depth, remainder)?;
+            let cl_type = CLType::Map {
+                key: Box::new(key_type),
+                value: Box::new(value_type),
+            };
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE1 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 1, remainder)?;
+            let cl_type = CLType::Tuple1([inner_types.pop_front().unwrap()]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE2 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 2, remainder)?;
+            let cl_type = CLType::Tuple2([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_TUPLE3 => {
+            let (mut inner_types, remainder) = parse_cl_tuple_types(depth, 3, remainder)?;
+            let cl_type = CLType::Tuple3([
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+                inner_types.pop_front().unwrap(),
+            ]);
+            Ok((cl_type, remainder))
+        }
+        CL_TYPE_TAG_ANY => Ok((CLType::Any, remainder)),
+        _ => Err(bytesrepr::Error::Formatting),
     }
 }
 
@@ -272,12 +285,13 @@ fn serialize_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>>>(
 }
 
 fn parse_cl_tuple_types(
+    depth: u8,
     count: usize,
     mut bytes: &[u8],
 ) -> Result<(VecDeque<Box<CLType>>, &[u8]), bytesrepr::Error> {
     let mut cl_types = VecDeque::with_capacity(count);
     for _ in 0..count {
-        let (cl_type, remainder) = CLType::from_bytes(bytes)?;
+        let (cl_type, remainder) = depth_limited_from_bytes(depth, bytes)?;
         cl_types.push_back(Box::new(cl_type));
         bytes = remainder;
     }
@@ -682,4 +696,33 @@ mod tests {
         let any = Any("Any test".to_string());
         round_trip(&any);
     }
+
+    fn nested_list_type(depth: u8) -> CLType {
+        (1..depth).fold(CLType::U8, |inner, _| CLType::List(Box::new(inner)))
+    }
+
+    #[test]
+    fn should_parse_cl_type_at_max_depth() {
+        let cl_type = nested_list_type(CL_TYPE_RECURSION_DEPTH);
+        let bytes = cl_type.to_bytes().unwrap();
+        assert_eq!(bytesrepr::deserialize(bytes), Ok(cl_type));
+    }
+
+    #[test]
+    fn should_reject_cl_type_nested_too_deeply() {
+        let cl_type = nested_list_type(CL_TYPE_RECURSION_DEPTH + 1);
+        let bytes = cl_type.to_bytes().unwrap();
+        assert_eq!(
+            bytesrepr::deserialize::<CLType>(bytes),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+
+        // A stream of list tags claiming to nest far deeper fails cleanly instead of overflowing
+        // the stack.
+        let bytes = vec![CL_TYPE_TAG_LIST; 1_000_000];
+        assert_eq!(
+            bytesrepr::deserialize::<CLType>(bytes),
+            Err(bytesrepr::Error::DeserializationLimitExceeded)
+        );
+    }
 }
@@ -75,7 +75,8 @@ impl CLValue {
     }
 
     // This is only required in order to implement `TryFrom<state::CLValue> for CLValue` (i.e. the
-    // conversion from the Protobuf `CLValue`) in a separate module to this one.
+    // conversion from the Protobuf `CLValue`) in a separate module to this one, and to allow
+    // `casperlabs_contract` to rebuild a `CLValue` from the raw bytes of a named argument.
     #[doc(hidden)]
     pub fn from_components(cl_type: CLType, bytes: Vec<u8>) -> Self {
         Self { cl_type, bytes }
@@ -4,6 +4,11 @@
 //!
 //! By default, the library is `no_std`, however you can enable full `std` functionality by enabling
 //! the crate's `std` feature.
+//!
+//! # `serde`
+//!
+//! String-based `Serialize` and `Deserialize` impls for [`SemVer`] and [`ExtendedSemVer`] are
+//! provided by enabling the crate's `serde` feature, which doesn't require the `std` feature.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(
@@ -45,7 +50,7 @@ mod transfer_result;
 mod uint;
 mod uref;
 
-pub use crate::uint::{UIntParseError, U128, U256, U512};
+pub use crate::uint::{UIntConversionError, UIntParseError, U128, U256, U512, U512_FIXED_LENGTH};
 pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
 #[doc(inline)]
 pub use api_error::ApiError;
@@ -57,6 +62,7 @@ pub use contracts::{
     EntryPointType, EntryPoints, Group, Parameter,
 };
 //pub use contract_ref::ContractRef;
+pub use casperlabs_types_derive::FromRuntimeArgs;
 pub use contract_wasm::ContractWasm;
 #[doc(inline)]
 pub use key::{
@@ -65,8 +71,16 @@ pub use key::{
 };
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
-pub use runtime_args::{NamedArg, RuntimeArgs};
-pub use semver::{SemVer, SEM_VER_SERIALIZED_LENGTH};
+#[cfg(feature = "std")]
+pub use runtime_args::RedactedRuntimeArgs;
+pub use runtime_args::{ArgsDiff, FromRuntimeArgs, NamedArg, RuntimeArgs, RuntimeArgsError};
+pub use semver::{ExtendedSemVer, SemVer, SemVerPattern, SEM_VER_SERIALIZED_LENGTH};
 pub use system_contract_type::SystemContractType;
-pub use transfer_result::{TransferResult, TransferredTo};
+pub use transfer_result::{PurseTransferResult, PurseTransferred, TransferResult, TransferredTo};
 pub use uref::{URef, UREF_ADDR_LENGTH, UREF_SERIALIZED_LENGTH};
+
+/// Items used by code generated by the derive macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::{string::String, vec::Vec};
+}
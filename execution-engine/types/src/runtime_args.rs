@@ -1,12 +1,71 @@
 //! Home of RuntimeArgs for calling contracts
 
+#[cfg(feature = "std")]
+use alloc::collections::BTreeSet;
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use core::fmt::{self, Display, Formatter};
+use core::iter::FromIterator;
+
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+use failure::Fail;
 
 use crate::{
-    bytesrepr::{self, Error, FromBytes, ToBytes},
-    CLTyped, CLValue,
+    bytesrepr::{self, Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    CLType, CLTypeMismatch, CLTyped, CLValue, CLValueError, BLAKE2B_DIGEST_LENGTH, U512,
 };
 
+#[cfg(feature = "std")]
+const ARG_VALUE_MAX_DISPLAY_LEN: usize = 16;
+#[cfg(feature = "std")]
+const REDACTED_VALUE: &str = "<redacted>";
+
+/// Error relating to reading typed values out of [`RuntimeArgs`].
+#[derive(Fail, PartialEq, Eq, Clone, Debug)]
+pub enum RuntimeArgsError {
+    /// No argument with the given name was found.
+    #[fail(display = "Missing argument: {}", _0)]
+    MissingArgument(String),
+    /// The named argument couldn't be converted into the requested type.
+    #[fail(display = "Invalid argument {}: {}", _0, _1)]
+    InvalidArgument(String, CLValueError),
+}
+
+/// A type which can be decoded from a set of [`RuntimeArgs`], each of its fields being read from
+/// the argument of the same name.
+///
+/// This is usually derived via `#[derive(FromRuntimeArgs)]` for a struct with named fields.
+pub trait FromRuntimeArgs: Sized {
+    /// Returns the names and types of the arguments required to decode `Self`.
+    fn named_arg_types() -> Vec<(String, CLType)>;
+
+    /// Decodes `Self` from `args`, returning an error naming the first argument found to be
+    /// missing or of the wrong type.
+    fn from_runtime_args(args: &RuntimeArgs) -> Result<Self, RuntimeArgsError>;
+}
+
+/// The differences between some [`RuntimeArgs`] and the arguments an entry point requires, as
+/// reported by [`RuntimeArgs::diff`].
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct ArgsDiff {
+    /// Names of required arguments which weren't provided, in the order they are required.
+    pub missing: Vec<String>,
+    /// Names of provided arguments which aren't required, in the order they were provided.
+    pub extra: Vec<String>,
+    /// Arguments provided with a different type than required, as `(name, required, provided)`.
+    pub mistyped: Vec<(String, CLType, CLType)>,
+}
+
+impl ArgsDiff {
+    /// Returns `true` if the provided arguments match the required ones exactly.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.mistyped.is_empty()
+    }
+}
+
 /// Named arguments to a contract
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct NamedArg(String, CLValue);
@@ -63,6 +122,25 @@ impl RuntimeArgs {
         RuntimeArgs::default()
     }
 
+    /// Collects `pairs` into a [`RuntimeArgs`] as for [`FromIterator`], then checks that an
+    /// argument is present for every name in `required`.
+    ///
+    /// Returns [`RuntimeArgsError::MissingArgument`] naming the first required argument not
+    /// provided.
+    pub fn with_required<I>(pairs: I, required: &[&str]) -> Result<RuntimeArgs, RuntimeArgsError>
+    where
+        I: IntoIterator<Item = (String, CLValue)>,
+    {
+        let runtime_args: RuntimeArgs = pairs.into_iter().collect();
+        match required
+            .iter()
+            .find(|name| runtime_args.get(name).is_none())
+        {
+            Some(name) => Err(RuntimeArgsError::MissingArgument((*name).into())),
+            None => Ok(runtime_args),
+        }
+    }
+
     /// Gets an argument by its name.
     pub fn get(&self, name: &str) -> Option<&CLValue> {
         self.0.iter().find_map(|NamedArg(named_name, named_value)| {
@@ -74,6 +152,70 @@ impl RuntimeArgs {
         })
     }
 
+    /// Gets an argument by its position, in the order the arguments were inserted.
+    pub fn get_by_index(&self, index: usize) -> Option<&CLValue> {
+        self.0.get(index).map(NamedArg::cl_value)
+    }
+
+    /// Gets an argument by its name, converting it into a `T`.
+    pub fn get_named_arg<T: CLTyped + FromBytes>(&self, name: &str) -> Result<T, RuntimeArgsError> {
+        let cl_value = self
+            .get(name)
+            .ok_or_else(|| RuntimeArgsError::MissingArgument(name.into()))?;
+        cl_value
+            .clone()
+            .into_t::<T>()
+            .map_err(|error| RuntimeArgsError::InvalidArgument(name.into(), error))
+    }
+
+    /// Checks that the argument with the given name has the `expected` [`CLType`], without decoding
+    /// it.
+    pub fn assert_type(&self, name: &str, expected: CLType) -> Result<(), RuntimeArgsError> {
+        let cl_value = self
+            .get(name)
+            .ok_or_else(|| RuntimeArgsError::MissingArgument(name.into()))?;
+        if *cl_value.cl_type() == expected {
+            return Ok(());
+        }
+        Err(RuntimeArgsError::InvalidArgument(
+            name.into(),
+            CLValueError::Type(CLTypeMismatch {
+                expected,
+                found: cl_value.cl_type().clone(),
+            }),
+        ))
+    }
+
+    /// Gets an argument holding a list of values by its name, converting it into a `Vec<T>`.
+    pub fn get_vec<T: CLTyped + FromBytes>(&self, name: &str) -> Result<Vec<T>, RuntimeArgsError> {
+        let cl_value = self
+            .get(name)
+            .ok_or_else(|| RuntimeArgsError::MissingArgument(name.into()))?;
+        cl_value
+            .clone()
+            .into_t::<Vec<T>>()
+            .map_err(|error| RuntimeArgsError::InvalidArgument(name.into(), error))
+    }
+
+    /// Gets an amount argument by its name, accepting a value of any of the CLTypes `U32`, `U64`
+    /// or `U512` and widening it to a [`U512`].
+    pub fn get_amount(&self, name: &str) -> Result<U512, RuntimeArgsError> {
+        let cl_value = self
+            .get(name)
+            .ok_or_else(|| RuntimeArgsError::MissingArgument(name.into()))?
+            .clone();
+        let amount = match cl_value.cl_type().clone() {
+            CLType::U32 => cl_value.into_t::<u32>().map(U512::from),
+            CLType::U64 => cl_value.into_t::<u64>().map(U512::from),
+            CLType::U512 => cl_value.into_t::<U512>(),
+            other => Err(CLValueError::Type(CLTypeMismatch {
+                expected: CLType::U512,
+                found: other,
+            })),
+        };
+        amount.map_err(|error| RuntimeArgsError::InvalidArgument(name.into(), error))
+    }
+
     /// Get length of the collection.
     pub fn len(&self) -> usize {
         self.0.len()
@@ -106,6 +248,68 @@ impl RuntimeArgs {
     pub fn to_values(&self) -> Vec<&CLValue> {
         self.0.iter().map(|NamedArg(_name, value)| value).collect()
     }
+
+    /// Returns the names of the arguments, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.0.iter().map(NamedArg::name).collect()
+    }
+
+    /// Returns the number of bytes `self` will occupy once serialized, computed by summing the
+    /// serialized lengths of the individual arguments rather than by serializing them.
+    pub fn estimated_serialized_size(&self) -> usize {
+        U32_SERIALIZED_LENGTH + self.0.iter().map(ToBytes::serialized_length).sum::<usize>()
+    }
+
+    /// Compares `self` against the `required` argument names and types, reporting which are
+    /// missing, which are provided but not required, and which are provided with the wrong type.
+    pub fn diff(&self, required: &[(String, CLType)]) -> ArgsDiff {
+        let mut diff = ArgsDiff::default();
+        for (name, required_type) in required {
+            match self.get(name) {
+                None => diff.missing.push(name.clone()),
+                Some(cl_value) if cl_value.cl_type() != required_type => diff.mistyped.push((
+                    name.clone(),
+                    required_type.clone(),
+                    cl_value.cl_type().clone(),
+                )),
+                Some(_) => (),
+            }
+        }
+        diff.extra = self
+            .0
+            .iter()
+            .map(NamedArg::name)
+            .filter(|name| {
+                required
+                    .iter()
+                    .all(|(required_name, _)| required_name != name)
+            })
+            .map(String::from)
+            .collect();
+        diff
+    }
+
+    /// Returns a Blake2b hash of the arguments which doesn't depend on their order.
+    ///
+    /// The hash is computed over the serialized arguments sorted into canonical order, so two
+    /// collections holding the same arguments in a different order have the same hash.
+    pub fn content_hash(&self) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+        let mut serialized_args: Vec<Vec<u8>> = self
+            .0
+            .iter()
+            .map(|arg| arg.to_bytes().expect("should serialize named arg"))
+            .collect();
+        serialized_args.sort();
+
+        let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).unwrap();
+        hasher.input((serialized_args.len() as u32).to_le_bytes());
+        for serialized_arg in serialized_args {
+            hasher.input(serialized_arg);
+        }
+        let mut hash = [0u8; BLAKE2B_DIGEST_LENGTH];
+        hasher.variable_result(|result| hash.clone_from_slice(result));
+        hash
+    }
 }
 
 impl From<Vec<NamedArg>> for RuntimeArgs {
@@ -120,6 +324,20 @@ impl From<BTreeMap<String, CLValue>> for RuntimeArgs {
     }
 }
 
+/// Collects named arguments, keeping only the last value given for any repeated name.
+impl FromIterator<(String, CLValue)> for RuntimeArgs {
+    fn from_iter<I: IntoIterator<Item = (String, CLValue)>>(iter: I) -> Self {
+        let mut named_args: Vec<NamedArg> = Vec::new();
+        for (name, cl_value) in iter {
+            match named_args.iter_mut().find(|named_arg| named_arg.0 == name) {
+                Some(named_arg) => named_arg.1 = cl_value,
+                None => named_args.push(NamedArg(name, cl_value)),
+            }
+        }
+        RuntimeArgs(named_args)
+    }
+}
+
 impl Into<BTreeMap<String, CLValue>> for RuntimeArgs {
     fn into(self) -> BTreeMap<String, CLValue> {
         let mut map = BTreeMap::new();
@@ -130,6 +348,85 @@ impl Into<BTreeMap<String, CLValue>> for RuntimeArgs {
     }
 }
 
+/// Formats each argument as its name, [`CLType`](crate::CLType) and serialized value, with values
+/// longer than 16 bytes truncated, e.g. `{amount: U512 = 0x012a}`.
+#[cfg(feature = "std")]
+impl Display for RuntimeArgs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_with_redaction(f, |_| false)
+    }
+}
+
+/// Formats [`RuntimeArgs`] as their `Display` impl does, except that the values of the args with
+/// the given names, or of all args, are replaced by `<redacted>`.  Their names and types are still
+/// shown.
+///
+/// Created by [`RuntimeArgs::redacted`] or [`RuntimeArgs::without_values`].
+#[cfg(feature = "std")]
+pub struct RedactedRuntimeArgs<'a> {
+    args: &'a RuntimeArgs,
+    /// The names of the args whose values are redacted, or `None` if every value is.
+    redacted_names: Option<&'a BTreeSet<String>>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> Display for RedactedRuntimeArgs<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.args.fmt_with_redaction(f, |name| {
+            self.redacted_names
+                .map_or(true, |redacted_names| redacted_names.contains(name))
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl RuntimeArgs {
+    /// Returns a view of these args which displays the values of those named in `redacted_names`
+    /// as `<redacted>`, e.g. for logging args which may hold sensitive data.
+    pub fn redacted<'a>(&'a self, redacted_names: &'a BTreeSet<String>) -> RedactedRuntimeArgs<'a> {
+        RedactedRuntimeArgs {
+            args: self,
+            redacted_names: Some(redacted_names),
+        }
+    }
+
+    /// Returns a view of these args which displays only their names and types, showing every value
+    /// as `<redacted>`.
+    pub fn without_values(&self) -> RedactedRuntimeArgs {
+        RedactedRuntimeArgs {
+            args: self,
+            redacted_names: None,
+        }
+    }
+
+    fn fmt_with_redaction<F: Fn(&str) -> bool>(
+        &self,
+        f: &mut Formatter<'_>,
+        is_redacted: F,
+    ) -> fmt::Result {
+        write!(f, "{{")?;
+        for (index, NamedArg(name, cl_value)) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            let bytes = cl_value.inner_bytes();
+            write!(f, "{}: {:?} = ", name, cl_value.cl_type())?;
+            if is_redacted(name) {
+                write!(f, "{}", REDACTED_VALUE)?;
+            } else if bytes.len() > ARG_VALUE_MAX_DISPLAY_LEN {
+                write!(
+                    f,
+                    "0x{}...",
+                    base16::encode_lower(&bytes[..ARG_VALUE_MAX_DISPLAY_LEN])
+                )?;
+            } else {
+                write!(f, "0x{}", base16::encode_lower(bytes))?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
 impl ToBytes for RuntimeArgs {
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         self.0.to_bytes()
@@ -174,6 +471,8 @@ macro_rules! runtime_args {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
     use super::*;
 
     #[test]
@@ -227,6 +526,313 @@ mod tests {
         assert_eq!(tagless, runtime_args_2.to_bytes().unwrap());
     }
 
+    #[test]
+    fn estimated_serialized_size_should_match_serialization() {
+        let empty_args = RuntimeArgs::new();
+        assert_eq!(
+            empty_args.estimated_serialized_size(),
+            empty_args.to_bytes().unwrap().len()
+        );
+
+        let args = runtime_args! {
+            "amount" => U512::from(1_000_000u64),
+            "target" => [1u8; 32],
+            "name" => "Hello, world!",
+            "maybe" => Some(1u64),
+            "list" => vec![1i32, 2, 3],
+        };
+        assert_eq!(
+            args.estimated_serialized_size(),
+            args.to_bytes().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn assert_type_should_accept_matching_types() {
+        let args = runtime_args! {
+            "amount" => U512::from(1),
+            "names" => vec!["a".to_string()],
+        };
+        assert_eq!(args.assert_type("amount", CLType::U512), Ok(()));
+        assert_eq!(
+            args.assert_type("names", CLType::List(Box::new(CLType::String))),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn assert_type_should_reject_mismatching_types() {
+        let args = runtime_args! { "amount" => 1u64 };
+        assert_eq!(
+            args.assert_type("amount", CLType::U512),
+            Err(RuntimeArgsError::InvalidArgument(
+                "amount".to_string(),
+                CLValueError::Type(CLTypeMismatch {
+                    expected: CLType::U512,
+                    found: CLType::U64,
+                })
+            ))
+        );
+        assert_eq!(
+            args.assert_type("target", CLType::ByteArray(32)),
+            Err(RuntimeArgsError::MissingArgument("target".to_string()))
+        );
+    }
+
+    #[test]
+    fn get_vec_should_read_list_of_values() {
+        let amounts = vec![U512::from(1), U512::from(2), U512::max_value()];
+        let args = runtime_args! {
+            "amounts" => amounts.clone(),
+            "name" => "Hello, world!",
+        };
+
+        assert_eq!(args.get_vec::<U512>("amounts"), Ok(amounts));
+        assert_eq!(
+            args.get_vec::<U512>("missing"),
+            Err(RuntimeArgsError::MissingArgument("missing".into()))
+        );
+    }
+
+    #[test]
+    fn get_vec_should_fail_on_type_mismatch() {
+        let args = runtime_args! {
+            "amounts" => vec![1u64, 2, 3],
+            "name" => "Hello, world!",
+        };
+
+        for name in &["amounts", "name"] {
+            assert!(matches!(
+                args.get_vec::<U512>(name),
+                Err(RuntimeArgsError::InvalidArgument(arg_name, CLValueError::Type(_)))
+                    if arg_name == *name
+            ));
+        }
+    }
+
+    #[test]
+    fn get_amount_should_widen_each_supported_width() {
+        let args = runtime_args! {
+            "u32" => u32::max_value(),
+            "u64" => u64::max_value(),
+            "u512" => U512::max_value(),
+        };
+
+        assert_eq!(args.get_amount("u32"), Ok(U512::from(u32::max_value())));
+        assert_eq!(args.get_amount("u64"), Ok(U512::from(u64::max_value())));
+        assert_eq!(args.get_amount("u512"), Ok(U512::max_value()));
+        assert_eq!(
+            args.get_amount("missing"),
+            Err(RuntimeArgsError::MissingArgument("missing".into()))
+        );
+    }
+
+    #[test]
+    fn get_amount_should_fail_on_unsupported_type() {
+        let args = runtime_args! {
+            "i32" => 1i32,
+            "u128" => crate::U128::from(1),
+            "name" => "Hello, world!",
+        };
+
+        for name in &["i32", "u128", "name"] {
+            assert!(matches!(
+                args.get_amount(name),
+                Err(RuntimeArgsError::InvalidArgument(
+                    arg_name,
+                    CLValueError::Type(CLTypeMismatch {
+                        expected: CLType::U512,
+                        ..
+                    })
+                )) if arg_name == *name
+            ));
+        }
+    }
+
+    #[test]
+    fn should_collect_from_iterator() {
+        let cl_value = |value: u64| CLValue::from_t(value).unwrap();
+        let runtime_args: RuntimeArgs = vec![
+            ("a".to_string(), cl_value(1)),
+            ("b".to_string(), cl_value(2)),
+            ("a".to_string(), cl_value(3)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(runtime_args.len(), 2);
+        assert_eq!(runtime_args.get("a"), Some(&cl_value(3)));
+        assert_eq!(runtime_args.get("b"), Some(&cl_value(2)));
+
+        let empty: RuntimeArgs = Vec::<(String, CLValue)>::new().into_iter().collect();
+        assert!(empty.is_empty());
+        assert_eq!(empty, RuntimeArgs::new());
+    }
+
+    #[test]
+    fn should_list_names_in_order() {
+        let mut runtime_args = RuntimeArgs::new();
+        runtime_args.insert("target", 1u8);
+        runtime_args.insert("amount", 2u64);
+
+        assert_eq!(runtime_args.names(), vec!["target", "amount"]);
+        assert!(RuntimeArgs::new().names().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_should_show_arg_names_types_and_truncated_values() {
+        let args = runtime_args! {
+            "amount" => U512::from(42),
+            "target" => [7u8; 32],
+        };
+        let formatted = args.to_string();
+
+        assert!(formatted.contains("amount: U512 = 0x012a"), "{}", formatted);
+        assert!(
+            formatted.contains(&format!(
+                "target: FixedList(U8, 32) = 0x{}...",
+                "07".repeat(ARG_VALUE_MAX_DISPLAY_LEN)
+            )),
+            "{}",
+            formatted
+        );
+        assert_eq!(RuntimeArgs::new().to_string(), "{}");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn redacted_display_should_hide_only_redacted_values() {
+        let args = runtime_args! {
+            "amount" => U512::from(42),
+            "memo" => 7u8,
+        };
+        let redacted_names = vec!["amount".to_string(), "absent".to_string()]
+            .into_iter()
+            .collect();
+        let formatted = args.redacted(&redacted_names).to_string();
+
+        assert_eq!(formatted, "{amount: U512 = <redacted>, memo: U8 = 0x07}");
+        assert!(!formatted.contains("012a"), "{}", formatted);
+        assert_eq!(
+            args.redacted(&BTreeSet::new()).to_string(),
+            args.to_string()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn display_without_values_should_hide_every_value() {
+        let args = runtime_args! {
+            "amount" => U512::from(42),
+            "memo" => 7u8,
+        };
+        assert_eq!(
+            args.without_values().to_string(),
+            "{amount: U512 = <redacted>, memo: U8 = <redacted>}"
+        );
+    }
+
+    #[test]
+    fn content_hash_should_not_depend_on_order() {
+        let args_1 = runtime_args! {
+            "amount" => U512::from(42),
+            "name" => "Hello, world!",
+            "flag" => true,
+        };
+        let args_2 = runtime_args! {
+            "flag" => true,
+            "amount" => U512::from(42),
+            "name" => "Hello, world!",
+        };
+        assert_ne!(args_1, args_2);
+        assert_eq!(args_1.content_hash(), args_2.content_hash());
+    }
+
+    #[test]
+    fn content_hash_should_differ_for_different_args() {
+        let args = runtime_args! {
+            "amount" => U512::from(42),
+            "name" => "Hello, world!",
+        };
+        let different_value = runtime_args! {
+            "amount" => U512::from(43),
+            "name" => "Hello, world!",
+        };
+        let different_type = runtime_args! {
+            "amount" => 42u64,
+            "name" => "Hello, world!",
+        };
+        let different_name = runtime_args! {
+            "value" => U512::from(42),
+            "name" => "Hello, world!",
+        };
+        let missing_arg = runtime_args! {
+            "amount" => U512::from(42),
+        };
+
+        for other in &[different_value, different_type, different_name, missing_arg] {
+            assert_ne!(args.content_hash(), other.content_hash());
+        }
+        assert_ne!(RuntimeArgs::new().content_hash(), args.content_hash());
+    }
+
+    fn transfer_schema() -> Vec<(String, CLType)> {
+        vec![
+            ("target".to_string(), <[u8; 32]>::cl_type()),
+            ("amount".to_string(), CLType::U512),
+        ]
+    }
+
+    #[test]
+    fn diff_should_be_empty_for_matching_args() {
+        let args = runtime_args! {
+            "amount" => U512::from(42),
+            "target" => [7u8; 32],
+        };
+        let diff = args.diff(&transfer_schema());
+        assert!(diff.is_empty(), "{:?}", diff);
+    }
+
+    #[test]
+    fn diff_should_report_missing_args() {
+        let args = runtime_args! {
+            "target" => [7u8; 32],
+        };
+        let diff = args.diff(&transfer_schema());
+        assert_eq!(diff.missing, vec!["amount".to_string()]);
+        assert!(diff.extra.is_empty());
+        assert!(diff.mistyped.is_empty());
+    }
+
+    #[test]
+    fn diff_should_report_extra_args() {
+        let args = runtime_args! {
+            "target" => [7u8; 32],
+            "memo" => "Hello, world!",
+            "amount" => U512::from(42),
+        };
+        let diff = args.diff(&transfer_schema());
+        assert!(diff.missing.is_empty());
+        assert_eq!(diff.extra, vec!["memo".to_string()]);
+        assert!(diff.mistyped.is_empty());
+    }
+
+    #[test]
+    fn diff_should_report_mistyped_args() {
+        let args = runtime_args! {
+            "target" => [7u8; 32],
+            "amount" => 42u64,
+        };
+        let diff = args.diff(&transfer_schema());
+        assert!(diff.missing.is_empty());
+        assert!(diff.extra.is_empty());
+        assert_eq!(
+            diff.mistyped,
+            vec![("amount".to_string(), CLType::U512, CLType::U64)]
+        );
+    }
+
     #[test]
     fn named_serialization_roundtrip() {
         let args = runtime_args! {
@@ -234,4 +840,92 @@ mod tests {
         };
         bytesrepr::test_serialization_roundtrip(&args);
     }
+
+    #[derive(crate::FromRuntimeArgs, PartialEq, Eq, Debug)]
+    #[runtime_args(crate = "crate")]
+    struct TransferArgs {
+        target: [u8; 32],
+        amount: U512,
+    }
+
+    #[test]
+    fn should_derive_named_arg_types() {
+        assert_eq!(TransferArgs::named_arg_types(), transfer_schema());
+    }
+
+    #[test]
+    fn should_decode_derived_struct() {
+        let args = runtime_args! {
+            "target" => [7u8; 32],
+            "amount" => U512::from(42),
+        };
+        assert_eq!(
+            TransferArgs::from_runtime_args(&args),
+            Ok(TransferArgs {
+                target: [7u8; 32],
+                amount: U512::from(42),
+            })
+        );
+    }
+
+    #[test]
+    fn derived_struct_should_report_missing_field() {
+        let args = runtime_args! {
+            "target" => [7u8; 32],
+        };
+        assert_eq!(
+            TransferArgs::from_runtime_args(&args),
+            Err(RuntimeArgsError::MissingArgument("amount".to_string()))
+        );
+    }
+
+    #[test]
+    fn derived_struct_should_report_mistyped_field() {
+        let args = runtime_args! {
+            "target" => [7u8; 32],
+            "amount" => 42u64,
+        };
+        assert_eq!(
+            TransferArgs::from_runtime_args(&args),
+            Err(RuntimeArgsError::InvalidArgument(
+                "amount".to_string(),
+                CLValueError::Type(CLTypeMismatch {
+                    expected: CLType::U512,
+                    found: CLType::U64,
+                })
+            ))
+        );
+    }
+
+    #[test]
+    fn should_construct_with_required_args_present() {
+        let amount = CLValue::from_t(U512::from(10)).unwrap();
+        let target = CLValue::from_t("target").unwrap();
+        let runtime_args = RuntimeArgs::with_required(
+            vec![
+                ("amount".to_string(), amount.clone()),
+                ("target".to_string(), target.clone()),
+            ],
+            &["target", "amount"],
+        )
+        .expect("should have required args");
+        assert_eq!(runtime_args.len(), 2);
+        assert_eq!(runtime_args.get("amount"), Some(&amount));
+        assert_eq!(runtime_args.get("target"), Some(&target));
+
+        let runtime_args = RuntimeArgs::with_required(vec![], &[]).expect("should require nothing");
+        assert!(runtime_args.is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_construct_with_required_arg_missing() {
+        let amount = CLValue::from_t(U512::from(10)).unwrap();
+        assert_eq!(
+            RuntimeArgs::with_required(
+                vec![("amount".to_string(), amount)],
+                &["amount", "target", "id"],
+            ),
+            Err(RuntimeArgsError::MissingArgument("target".to_string()))
+        );
+    }
 }
@@ -1,13 +1,25 @@
-use alloc::vec::Vec;
-use core::{convert::TryFrom, fmt, num::ParseIntError};
+use alloc::{string::String, vec::Vec};
+use core::{cmp::Ordering, convert::TryFrom, fmt, num::ParseIntError};
 
 use failure::Fail;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::bytesrepr::{self, Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
 
 /// Length of SemVer when serialized
 pub const SEM_VER_SERIALIZED_LENGTH: usize = 3 * U32_SERIALIZED_LENGTH;
 
+const PRE_RELEASE_SEPARATOR: char = '-';
+const BUILD_METADATA_SEPARATOR: char = '+';
+const IDENTIFIER_SEPARATOR: char = '.';
+const WILDCARD: &str = "*";
+
+/// Width in bits of each of the major, minor and patch fields of a packed SemVer.
+const PACKED_COMPONENT_BITS: u32 = 21;
+/// Largest major, minor or patch version which can be packed into a `u64`.
+const PACKED_COMPONENT_MAX: u32 = (1 << PACKED_COMPONENT_BITS) - 1;
+
 /// A struct for semantic versioning.
 #[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SemVer {
@@ -35,6 +47,73 @@ impl SemVer {
             patch,
         }
     }
+
+    /// Compares the precedence of `self` and `other` as defined by Semantic Versioning 2.0.0.
+    ///
+    /// Major, minor and patch versions are compared numerically in that order.
+    pub fn cmp_precedence(&self, other: &SemVer) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+    }
+
+    /// Packs the version into a `u64`, with the patch version in bits 0-20, minor in bits 21-41
+    /// and major in bits 42-62.  Bit 63 is always zero.
+    ///
+    /// Packed versions compare in the same order as the versions themselves.  Returns `None` if any
+    /// component exceeds 2^21 - 1.
+    pub fn to_packed_u64(&self) -> Option<u64> {
+        if self.major > PACKED_COMPONENT_MAX
+            || self.minor > PACKED_COMPONENT_MAX
+            || self.patch > PACKED_COMPONENT_MAX
+        {
+            return None;
+        }
+        Some(
+            u64::from(self.major) << (2 * PACKED_COMPONENT_BITS)
+                | u64::from(self.minor) << PACKED_COMPONENT_BITS
+                | u64::from(self.patch),
+        )
+    }
+
+    /// Unpacks a version packed by [`SemVer::to_packed_u64`].
+    ///
+    /// Returns `None` if bit 63 of `packed` is set, as no version packs to such a value.
+    pub fn from_packed_u64(packed: u64) -> Option<SemVer> {
+        if packed >> (3 * PACKED_COMPONENT_BITS) != 0 {
+            return None;
+        }
+        let component = |shift: u32| (packed >> shift) as u32 & PACKED_COMPONENT_MAX;
+        Some(SemVer::new(
+            component(2 * PACKED_COMPONENT_BITS),
+            component(PACKED_COMPONENT_BITS),
+            component(0),
+        ))
+    }
+
+    /// Returns `true` if `self` can be upgraded to `target`.
+    ///
+    /// Upgrades only move forwards: `target` must be of the same or a higher major version, and
+    /// must have strictly higher precedence than `self`.
+    pub fn can_upgrade_to(&self, target: &SemVer) -> bool {
+        target.major >= self.major && target.cmp_precedence(self) == Ordering::Greater
+    }
+
+    /// Returns the versions in `available` which are compatible with `target`, in their original
+    /// order.
+    ///
+    /// A version is compatible if it has the same major version as `target` and doesn't have lower
+    /// precedence.
+    pub fn compatible_subset(available: &[SemVer], target: &SemVer) -> Vec<SemVer> {
+        available
+            .iter()
+            .filter(|version| {
+                version.major == target.major && version.cmp_precedence(target) != Ordering::Less
+            })
+            .cloned()
+            .collect()
+    }
 }
 
 impl ToBytes for SemVer {
@@ -66,10 +145,31 @@ impl fmt::Display for SemVer {
     }
 }
 
+/// Serializes as the string produced by the `Display` impl, e.g. `"1.2.3"`.
+#[cfg(feature = "serde")]
+impl Serialize for SemVer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from a string in the form accepted by `SemVer::try_from(&str)`.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for SemVer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        SemVer::try_from(value.as_str()).map_err(de::Error::custom)
+    }
+}
+
 #[derive(Fail, Debug, Clone, PartialEq, Eq)]
 pub enum ParseSemVerError {
     #[fail(display = "Invalid version format")]
     InvalidVersionFormat,
+    #[fail(display = "Invalid pre-release version")]
+    InvalidPreRelease,
+    #[fail(display = "Invalid build metadata")]
+    InvalidBuildMetadata,
     #[fail(display = "{}", _0)]
     ParseIntError(ParseIntError),
 }
@@ -83,7 +183,7 @@ impl From<ParseIntError> for ParseSemVerError {
 impl TryFrom<&str> for SemVer {
     type Error = ParseSemVerError;
     fn try_from(value: &str) -> Result<SemVer, Self::Error> {
-        let tokens: Vec<&str> = value.split('.').collect();
+        let tokens: Vec<&str> = value.split(IDENTIFIER_SEPARATOR).collect();
         if tokens.len() != 3 {
             return Err(ParseSemVerError::InvalidVersionFormat);
         }
@@ -96,9 +196,299 @@ impl TryFrom<&str> for SemVer {
     }
 }
 
+/// A [`SemVer`] with an optional pre-release version and build metadata, e.g.
+/// `1.0.0-rc.1+build.5`.
+///
+/// Versions are ordered by their precedence as defined by the SemVer 2.0.0 specification, so a
+/// pre-release version has lower precedence than the associated release version.  Build metadata
+/// doesn't affect precedence, and is only taken into account to break ties between versions which
+/// otherwise have the same precedence.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct ExtendedSemVer {
+    /// Major, minor and patch versions.
+    pub version: SemVer,
+    /// Optional pre-release version, e.g. `rc.1`.
+    pub pre_release: Option<String>,
+    /// Optional build metadata, e.g. `build.5`.
+    pub build: Option<String>,
+}
+
+impl ExtendedSemVer {
+    /// Constructs a new `ExtendedSemVer` for the release `version`.
+    pub const fn new(version: SemVer) -> ExtendedSemVer {
+        ExtendedSemVer {
+            version,
+            pre_release: None,
+            build: None,
+        }
+    }
+
+    /// Returns a copy of `self` with the given pre-release version.
+    pub fn with_pre_release(self, pre_release: String) -> ExtendedSemVer {
+        ExtendedSemVer {
+            pre_release: Some(pre_release),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with the given build metadata.
+    pub fn with_build(self, build: String) -> ExtendedSemVer {
+        ExtendedSemVer {
+            build: Some(build),
+            ..self
+        }
+    }
+
+    /// Returns `true` if `self` is a pre-release version.
+    pub fn is_pre_release(&self) -> bool {
+        self.pre_release.is_some()
+    }
+
+    /// Compares the precedence of `self` and `other` as defined by Semantic Versioning 2.0.0.
+    ///
+    /// The versions are compared by [`SemVer::cmp_precedence`], then pre-release versions are
+    /// compared.  Unlike `Ord`, build metadata is ignored, so versions differing only in build
+    /// metadata have equal precedence.
+    pub fn cmp_precedence(&self, other: &ExtendedSemVer) -> Ordering {
+        self.version
+            .cmp_precedence(&other.version)
+            .then_with(|| compare_pre_releases(&self.pre_release, &other.pre_release))
+    }
+}
+
+impl From<SemVer> for ExtendedSemVer {
+    fn from(version: SemVer) -> ExtendedSemVer {
+        ExtendedSemVer::new(version)
+    }
+}
+
+fn is_numeric(identifier: &str) -> bool {
+    identifier.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Compares two pre-release identifiers.  Numeric identifiers are compared numerically and have
+/// lower precedence than alphanumeric ones, which are compared lexically in ASCII sort order.
+fn compare_identifiers(lhs: &str, rhs: &str) -> Ordering {
+    match (is_numeric(lhs), is_numeric(rhs)) {
+        // Numeric identifiers have no leading zeros, so a longer one is always greater.
+        (true, true) => lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs)),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => lhs.cmp(rhs),
+    }
+}
+
+fn compare_pre_releases(lhs: &Option<String>, rhs: &Option<String>) -> Ordering {
+    match (lhs, rhs) {
+        (None, None) => Ordering::Equal,
+        // A release has higher precedence than any of its pre-releases.
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(lhs), Some(rhs)) => {
+            let mut lhs_identifiers = lhs.split(IDENTIFIER_SEPARATOR);
+            let mut rhs_identifiers = rhs.split(IDENTIFIER_SEPARATOR);
+            loop {
+                match (lhs_identifiers.next(), rhs_identifiers.next()) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(lhs), Some(rhs)) => match compare_identifiers(lhs, rhs) {
+                        Ordering::Equal => continue,
+                        ordering => return ordering,
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl Ord for ExtendedSemVer {
+    fn cmp(&self, other: &ExtendedSemVer) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for ExtendedSemVer {
+    fn partial_cmp(&self, other: &ExtendedSemVer) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ToBytes for ExtendedSemVer {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.version.to_bytes()?);
+        ret.append(&mut self.pre_release.to_bytes()?);
+        ret.append(&mut self.build.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        SEM_VER_SERIALIZED_LENGTH
+            + self.pre_release.serialized_length()
+            + self.build.serialized_length()
+    }
+}
+
+impl FromBytes for ExtendedSemVer {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (version, rem): (SemVer, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (pre_release, rem): (Option<String>, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (build, rem): (Option<String>, &[u8]) = FromBytes::from_bytes(rem)?;
+        let extended_sem_ver = ExtendedSemVer {
+            version,
+            pre_release,
+            build,
+        };
+        Ok((extended_sem_ver, rem))
+    }
+}
+
+impl fmt::Display for ExtendedSemVer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.version)?;
+        if let Some(pre_release) = &self.pre_release {
+            write!(f, "{}{}", PRE_RELEASE_SEPARATOR, pre_release)?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "{}{}", BUILD_METADATA_SEPARATOR, build)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes as the string produced by the `Display` impl, e.g. `"1.0.0-rc.1"`.
+#[cfg(feature = "serde")]
+impl Serialize for ExtendedSemVer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes from a string in the form accepted by `ExtendedSemVer::try_from(&str)`.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ExtendedSemVer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        ExtendedSemVer::try_from(value.as_str()).map_err(de::Error::custom)
+    }
+}
+
+/// Checks that `value` is a non-empty series of dot-separated, non-empty identifiers comprising
+/// only ASCII alphanumerics and hyphens.  If `forbid_leading_zeros` is set, numeric identifiers
+/// must not include leading zeros.
+fn is_valid_identifiers(value: &str, forbid_leading_zeros: bool) -> bool {
+    value.split(IDENTIFIER_SEPARATOR).all(|identifier| {
+        !identifier.is_empty()
+            && identifier
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+            && !(forbid_leading_zeros
+                && is_numeric(identifier)
+                && identifier.len() > 1
+                && identifier.starts_with('0'))
+    })
+}
+
+impl TryFrom<&str> for ExtendedSemVer {
+    type Error = ParseSemVerError;
+    fn try_from(value: &str) -> Result<ExtendedSemVer, Self::Error> {
+        let (value, build) = match value.find(BUILD_METADATA_SEPARATOR) {
+            Some(index) => {
+                let build = &value[index + 1..];
+                if !is_valid_identifiers(build, false) {
+                    return Err(ParseSemVerError::InvalidBuildMetadata);
+                }
+                (&value[..index], Some(String::from(build)))
+            }
+            None => (value, None),
+        };
+
+        let (value, pre_release) = match value.find(PRE_RELEASE_SEPARATOR) {
+            Some(index) => {
+                let pre_release = &value[index + 1..];
+                if !is_valid_identifiers(pre_release, true) {
+                    return Err(ParseSemVerError::InvalidPreRelease);
+                }
+                (&value[..index], Some(String::from(pre_release)))
+            }
+            None => (value, None),
+        };
+
+        Ok(ExtendedSemVer {
+            version: SemVer::try_from(value)?,
+            pre_release,
+            build,
+        })
+    }
+}
+
+/// A pattern matching a family of [`SemVer`]s: `1.*` matches any version with major version 1,
+/// and `1.2.*` any version with major version 1 and minor version 2.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct SemVerPattern {
+    major: u32,
+    minor: Option<u32>,
+}
+
+impl SemVerPattern {
+    /// Constructs the pattern `major.*`.
+    pub const fn major(major: u32) -> SemVerPattern {
+        SemVerPattern { major, minor: None }
+    }
+
+    /// Constructs the pattern `major.minor.*`.
+    pub const fn minor(major: u32, minor: u32) -> SemVerPattern {
+        SemVerPattern {
+            major,
+            minor: Some(minor),
+        }
+    }
+
+    /// Returns `true` if `version` matches `self`.
+    pub fn matches(&self, version: &SemVer) -> bool {
+        version.major == self.major && self.minor.map_or(true, |minor| version.minor == minor)
+    }
+
+    /// Returns the highest of `versions` matching `self`, if any.
+    pub fn highest_match<'a, I: IntoIterator<Item = &'a SemVer>>(
+        &self,
+        versions: I,
+    ) -> Option<&'a SemVer> {
+        versions
+            .into_iter()
+            .filter(|version| self.matches(version))
+            .max()
+    }
+}
+
+impl fmt::Display for SemVerPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.major, IDENTIFIER_SEPARATOR)?;
+        if let Some(minor) = self.minor {
+            write!(f, "{}{}", minor, IDENTIFIER_SEPARATOR)?;
+        }
+        write!(f, "{}", WILDCARD)
+    }
+}
+
+impl TryFrom<&str> for SemVerPattern {
+    type Error = ParseSemVerError;
+    fn try_from(value: &str) -> Result<SemVerPattern, Self::Error> {
+        let tokens: Vec<&str> = value.split(IDENTIFIER_SEPARATOR).collect();
+        match tokens.as_slice() {
+            [major, WILDCARD] => Ok(SemVerPattern::major(major.parse()?)),
+            [major, minor, WILDCARD] => Ok(SemVerPattern::minor(major.parse()?, minor.parse()?)),
+            _ => Err(ParseSemVerError::InvalidVersionFormat),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
     use core::convert::TryInto;
 
     #[test]
@@ -115,6 +505,58 @@ mod tests {
         assert!(SemVer::new(2, 0, 0) > SemVer::new(1, 99, 99));
     }
 
+    #[test]
+    fn should_compare_precedence() {
+        let cases = [
+            ((1, 2, 3), (1, 2, 3), Ordering::Equal),
+            ((2, 0, 0), (1, 9, 9), Ordering::Greater),
+            ((1, 9, 9), (2, 0, 0), Ordering::Less),
+            ((1, 3, 0), (1, 2, 9), Ordering::Greater),
+            ((1, 2, 9), (1, 3, 0), Ordering::Less),
+            ((1, 2, 4), (1, 2, 3), Ordering::Greater),
+            ((1, 2, 3), (1, 2, 4), Ordering::Less),
+            ((10, 0, 0), (9, 0, 0), Ordering::Greater),
+        ];
+        for &((lhs_major, lhs_minor, lhs_patch), (rhs_major, rhs_minor, rhs_patch), expected) in
+            cases.iter()
+        {
+            let lhs = SemVer::new(lhs_major, lhs_minor, lhs_patch);
+            let rhs = SemVer::new(rhs_major, rhs_minor, rhs_patch);
+            assert_eq!(lhs.cmp_precedence(&rhs), expected, "{} vs {}", lhs, rhs);
+            assert_eq!(lhs.cmp(&rhs), expected, "{} vs {}", lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn should_ignore_build_metadata_in_precedence() {
+        let lhs = ExtendedSemVer::new(SemVer::new(1, 0, 0)).with_build("001".to_string());
+        let rhs = ExtendedSemVer::new(SemVer::new(1, 0, 0)).with_build("002".to_string());
+        assert_eq!(lhs.cmp_precedence(&rhs), Ordering::Equal);
+        assert_eq!(lhs.cmp(&rhs), Ordering::Less);
+
+        let pre_release =
+            ExtendedSemVer::new(SemVer::new(1, 0, 0)).with_pre_release("alpha".to_string());
+        assert_eq!(pre_release.cmp_precedence(&lhs), Ordering::Less);
+    }
+
+    #[test]
+    fn should_allow_forward_upgrades() {
+        let current = SemVer::new(1, 2, 3);
+        assert!(current.can_upgrade_to(&SemVer::new(1, 2, 4)));
+        assert!(current.can_upgrade_to(&SemVer::new(1, 3, 0)));
+        assert!(current.can_upgrade_to(&SemVer::new(2, 0, 0)));
+        assert!(current.can_upgrade_to(&SemVer::new(3, 1, 0)));
+    }
+
+    #[test]
+    fn should_not_allow_backward_or_same_version_upgrades() {
+        let current = SemVer::new(1, 2, 3);
+        assert!(!current.can_upgrade_to(&current));
+        assert!(!current.can_upgrade_to(&SemVer::new(1, 2, 2)));
+        assert!(!current.can_upgrade_to(&SemVer::new(1, 1, 9)));
+        assert!(!current.can_upgrade_to(&SemVer::new(0, 9, 9)));
+    }
+
     #[test]
     fn parse_from_string() {
         let ver1: SemVer = "100.20.3".try_into().expect("should parse");
@@ -129,5 +571,319 @@ mod tests {
         assert!(SemVer::try_from("1.2").is_err());
         assert!(SemVer::try_from("1").is_err());
         assert!(SemVer::try_from("0").is_err());
+        assert!(SemVer::try_from("1.0.0-rc1").is_err());
+        assert!(SemVer::try_from("1.0.0+build").is_err());
+    }
+
+    #[test]
+    fn parse_pre_release_and_build_metadata_from_string() {
+        let ver1: ExtendedSemVer = "1.0.0-rc1".try_into().expect("should parse");
+        assert_eq!(
+            ver1,
+            ExtendedSemVer::new(SemVer::new(1, 0, 0)).with_pre_release("rc1".into())
+        );
+        let ver2: ExtendedSemVer = "1.0.0-alpha.1+build.5".try_into().expect("should parse");
+        assert_eq!(
+            ver2,
+            ExtendedSemVer::new(SemVer::new(1, 0, 0))
+                .with_pre_release("alpha.1".into())
+                .with_build("build.5".into())
+        );
+        let ver3: ExtendedSemVer = "1.0.0+001".try_into().expect("should parse");
+        assert_eq!(
+            ver3,
+            ExtendedSemVer::new(SemVer::new(1, 0, 0)).with_build("001".into())
+        );
+        let ver4: ExtendedSemVer = "1.0.0-x-y-z.--".try_into().expect("should parse");
+        assert_eq!(
+            ver4,
+            ExtendedSemVer::new(SemVer::new(1, 0, 0)).with_pre_release("x-y-z.--".into())
+        );
+
+        assert_eq!(
+            ExtendedSemVer::try_from("1.0.0-"),
+            Err(ParseSemVerError::InvalidPreRelease)
+        );
+        assert_eq!(
+            ExtendedSemVer::try_from("1.0.0-rc..1"),
+            Err(ParseSemVerError::InvalidPreRelease)
+        );
+        assert_eq!(
+            ExtendedSemVer::try_from("1.0.0-01"),
+            Err(ParseSemVerError::InvalidPreRelease)
+        );
+        assert_eq!(
+            ExtendedSemVer::try_from("1.0.0-rc_1"),
+            Err(ParseSemVerError::InvalidPreRelease)
+        );
+        assert_eq!(
+            ExtendedSemVer::try_from("1.0.0+"),
+            Err(ParseSemVerError::InvalidBuildMetadata)
+        );
+        assert_eq!(
+            ExtendedSemVer::try_from("1.0.0+build+5"),
+            Err(ParseSemVerError::InvalidBuildMetadata)
+        );
+        assert!(ExtendedSemVer::try_from("1.0-rc1").is_err());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for version in &[
+            "1.2.3",
+            "1.0.0-rc1",
+            "1.0.0+build.5",
+            "1.0.0-alpha.1+build.5",
+        ] {
+            let sem_ver = ExtendedSemVer::try_from(*version).expect("should parse");
+            assert_eq!(sem_ver.to_string(), *version);
+        }
+    }
+
+    #[test]
+    fn should_order_pre_release_before_release() {
+        let parse = |version: &str| ExtendedSemVer::try_from(version).expect("should parse");
+
+        assert!(parse("1.0.0-rc1") < parse("1.0.0"));
+        assert!(parse("1.0.0-rc1") > parse("0.9.9"));
+        assert!(parse("1.0.0-rc1") < parse("1.0.1-alpha"));
+
+        // Precedence example from the SemVer 2.0.0 specification.
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ];
+        for pair in ordered.windows(2) {
+            assert!(parse(pair[0]) < parse(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn build_metadata_should_not_affect_precedence() {
+        let parse = |version: &str| ExtendedSemVer::try_from(version).expect("should parse");
+
+        assert!(parse("1.0.0+build.9") < parse("1.0.1"));
+        assert!(parse("1.0.0-rc1+build.9") < parse("1.0.0"));
+        assert_ne!(parse("1.0.0+build.1"), parse("1.0.0"));
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&SemVer::new(1, 2, 3));
+        bytesrepr::test_serialization_roundtrip(&ExtendedSemVer::new(SemVer::new(1, 2, 3)));
+        bytesrepr::test_serialization_roundtrip(
+            &ExtendedSemVer::new(SemVer::new(1, 0, 0))
+                .with_pre_release("rc.1".into())
+                .with_build("build.5".into()),
+        );
+    }
+
+    #[test]
+    fn should_serialize_sem_ver_to_fixed_length() {
+        let sem_ver = SemVer::new(1, 2, 3);
+        let bytes = sem_ver.to_bytes().expect("should serialize");
+        assert_eq!(bytes.len(), SEM_VER_SERIALIZED_LENGTH);
+        assert_eq!(sem_ver.serialized_length(), SEM_VER_SERIALIZED_LENGTH);
+        assert_eq!(bytes, vec![1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+
+        let extended = ExtendedSemVer::new(sem_ver)
+            .to_bytes()
+            .expect("should serialize");
+        assert_eq!(&extended[..SEM_VER_SERIALIZED_LENGTH], bytes.as_slice());
+    }
+
+    #[test]
+    fn packed_u64_roundtrip() {
+        let max = PACKED_COMPONENT_MAX;
+        for sem_ver in &[
+            SemVer::new(0, 0, 0),
+            SemVer::new(1, 2, 3),
+            SemVer::new(max, 0, 0),
+            SemVer::new(0, max, 0),
+            SemVer::new(0, 0, max),
+            SemVer::new(max, max, max),
+        ] {
+            let packed = sem_ver.to_packed_u64().expect("should pack");
+            assert_eq!(SemVer::from_packed_u64(packed).as_ref(), Some(sem_ver));
+        }
+        assert_eq!(
+            SemVer::new(1, 2, 3).to_packed_u64(),
+            Some(1 << 42 | 2 << 21 | 3)
+        );
+        assert_eq!(
+            SemVer::new(max, max, max).to_packed_u64(),
+            Some(u64::max_value() >> 1)
+        );
+    }
+
+    #[test]
+    fn packed_u64_should_preserve_order() {
+        let ordered = [
+            SemVer::new(0, 0, 1),
+            SemVer::new(0, 1, 0),
+            SemVer::new(0, 1, PACKED_COMPONENT_MAX),
+            SemVer::new(1, 0, 0),
+            SemVer::new(1, 2, 3),
+            SemVer::new(2, 0, 0),
+        ];
+        for pair in ordered.windows(2) {
+            assert!(pair[0].to_packed_u64() < pair[1].to_packed_u64());
+        }
+    }
+
+    #[test]
+    fn should_not_pack_oversized_components() {
+        let oversized = PACKED_COMPONENT_MAX + 1;
+        assert_eq!(SemVer::new(oversized, 0, 0).to_packed_u64(), None);
+        assert_eq!(SemVer::new(0, oversized, 0).to_packed_u64(), None);
+        assert_eq!(SemVer::new(0, 0, oversized).to_packed_u64(), None);
+        assert_eq!(SemVer::new(u32::max_value(), 0, 0).to_packed_u64(), None);
+    }
+
+    #[test]
+    fn should_not_unpack_with_top_bit_set() {
+        assert_eq!(SemVer::from_packed_u64(1 << 63), None);
+        assert_eq!(SemVer::from_packed_u64(u64::max_value()), None);
+    }
+
+    #[test]
+    fn major_pattern_should_match_any_minor_and_patch() {
+        let pattern = SemVerPattern::major(1);
+        assert!(pattern.matches(&SemVer::new(1, 0, 0)));
+        assert!(pattern.matches(&SemVer::new(1, 2, 3)));
+        assert!(!pattern.matches(&SemVer::new(0, 9, 9)));
+        assert!(!pattern.matches(&SemVer::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn minor_pattern_should_match_any_patch() {
+        let pattern = SemVerPattern::minor(1, 2);
+        assert!(pattern.matches(&SemVer::new(1, 2, 0)));
+        assert!(pattern.matches(&SemVer::new(1, 2, 99)));
+        assert!(!pattern.matches(&SemVer::new(1, 3, 0)));
+        assert!(!pattern.matches(&SemVer::new(2, 2, 0)));
+    }
+
+    #[test]
+    fn pattern_should_select_highest_match() {
+        let versions = [
+            SemVer::new(1, 2, 0),
+            SemVer::new(1, 3, 1),
+            SemVer::new(1, 2, 7),
+            SemVer::new(2, 0, 0),
+        ];
+        assert_eq!(
+            SemVerPattern::major(1).highest_match(&versions),
+            Some(&SemVer::new(1, 3, 1))
+        );
+        assert_eq!(
+            SemVerPattern::minor(1, 2).highest_match(&versions),
+            Some(&SemVer::new(1, 2, 7))
+        );
+        assert_eq!(SemVerPattern::major(3).highest_match(&versions), None);
+    }
+
+    #[test]
+    fn should_parse_and_display_patterns() {
+        for (input, pattern) in &[
+            ("1.*", SemVerPattern::major(1)),
+            ("1.2.*", SemVerPattern::minor(1, 2)),
+        ] {
+            assert_eq!(SemVerPattern::try_from(*input), Ok(*pattern));
+            assert_eq!(pattern.to_string(), *input);
+        }
+    }
+
+    #[test]
+    fn should_not_parse_invalid_patterns() {
+        for input in &[
+            "*", "1", "1.2", "1.2.3", "1.*.*", "1.2.3.*", "*.2.*", "a.*", "",
+        ] {
+            assert!(SemVerPattern::try_from(*input).is_err(), "{}", input);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_roundtrip() {
+        for version in &["1.2.3", "0.0.1", "100.20.3"] {
+            let semver = SemVer::try_from(*version).expect("should parse");
+            let json = serde_json::to_string(&semver).expect("should serialize");
+            assert_eq!(json, format!("\"{}\"", version));
+            let deserialized: SemVer = serde_json::from_str(&json).expect("should deserialize");
+            assert_eq!(deserialized, semver);
+        }
+
+        for version in &["1.2.3", "1.0.0-rc.1", "1.0.0-rc.1+build.5", "0.0.1+build"] {
+            let semver = ExtendedSemVer::try_from(*version).expect("should parse");
+            let json = serde_json::to_string(&semver).expect("should serialize");
+            assert_eq!(json, format!("\"{}\"", version));
+            let deserialized: ExtendedSemVer =
+                serde_json::from_str(&json).expect("should deserialize");
+            assert_eq!(deserialized, semver);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_should_reject_invalid_versions() {
+        for json in &[
+            "\"1.2\"",
+            "\"1.2.3.4\"",
+            "\"1.2.x\"",
+            "\"1.0.0-rc.1\"",
+            "\"1.0.0+build\"",
+            "\"\"",
+            "[1, 2, 3]",
+            "123",
+        ] {
+            assert!(
+                serde_json::from_str::<SemVer>(json).is_err(),
+                "{} should be rejected",
+                json
+            );
+        }
+
+        for json in &["\"1.0.0-01\"", "\"1.0.0+\"", "\"1.2\"", "[1, 2, 3]"] {
+            assert!(
+                serde_json::from_str::<ExtendedSemVer>(json).is_err(),
+                "{} should be rejected",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn should_select_compatible_subset() {
+        let available = vec![
+            SemVer::new(0, 9, 0),
+            SemVer::new(1, 0, 0),
+            SemVer::new(1, 2, 0),
+            SemVer::new(2, 0, 0),
+            SemVer::new(1, 2, 3),
+            SemVer::new(1, 10, 0),
+            SemVer::new(3, 2, 3),
+        ];
+        assert_eq!(
+            SemVer::compatible_subset(&available, &SemVer::new(1, 2, 0)),
+            vec![
+                SemVer::new(1, 2, 0),
+                SemVer::new(1, 2, 3),
+                SemVer::new(1, 10, 0)
+            ]
+        );
+        assert_eq!(
+            SemVer::compatible_subset(&available, &SemVer::new(2, 0, 0)),
+            vec![SemVer::new(2, 0, 0)]
+        );
+        assert!(SemVer::compatible_subset(&available, &SemVer::new(1, 11, 0)).is_empty());
+        assert!(SemVer::compatible_subset(&available, &SemVer::new(4, 0, 0)).is_empty());
+        assert!(SemVer::compatible_subset(&[], &SemVer::new(1, 0, 0)).is_empty());
     }
 }
@@ -38,6 +38,9 @@ pub enum Error {
     /// Purse not found while trying to get balance.
     #[fail(display = "Purse not found")]
     PurseNotFound = 7,
+    /// The transfer would take the balance of the target purse above its cap.
+    #[fail(display = "Balance cap exceeded")]
+    BalanceCapExceeded = 8,
 }
 
 impl From<PurseError> for Error {
@@ -79,6 +82,7 @@ impl TryFrom<u8> for Error {
             d if d == Error::InvalidNonEmptyPurseCreation as u8 => {
                 Ok(Error::InvalidNonEmptyPurseCreation)
             }
+            d if d == Error::BalanceCapExceeded as u8 => Ok(Error::BalanceCapExceeded),
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -105,6 +105,16 @@ pub enum Error {
     /// deploy, but was called by the session code.
     #[fail(display = "Set refund purse was called outside payment")]
     SetRefundPurseCalledOutsidePayment,
+    /// Attempted to unbond an amount which exceeds the validator's current stake.
+    #[fail(display = "Unbond exceeds stake")]
+    UnbondExceedsStake,
+    /// Attempted to distribute more rewards than are held in the rewards purse.
+    #[fail(display = "Rewards over-allocated")]
+    RewardsOverAllocated,
+    /// Internal error: while distributing rewards, failed to transfer from the PoS contract's
+    /// rewards purse to a validator's account.
+    #[fail(display = "Transfer from rewards purse failed")]
+    FailedTransferFromRewardsPurse,
 }
 
 impl CLTyped for Error {
@@ -2,10 +2,16 @@ use core::fmt::Debug;
 
 use crate::ApiError;
 
-/// The result of an attempt to transfer between purses.
+/// The result of an attempt to transfer to an account.
 pub type TransferResult = Result<TransferredTo, ApiError>;
 
-/// The result of a successful transfer between purses.
+/// The result of an attempt to transfer between purses.
+pub type PurseTransferResult = Result<PurseTransferred, ApiError>;
+
+const TRANSFER_FAILED: i32 = 2;
+const TARGET_ACCOUNT_MISSING: i32 = 3;
+
+/// The result of a successful transfer to an account.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(i32)]
 pub enum TransferredTo {
@@ -19,11 +25,13 @@ impl TransferredTo {
     /// Converts an `i32` to a [`TransferResult`], where:
     /// * `0` represents `Ok(TransferredTo::ExistingAccount)`,
     /// * `1` represents `Ok(TransferredTo::NewAccount)`,
+    /// * `3` represents `Err(ApiError::TargetAccountMissing)`,
     /// * all other inputs are mapped to `Err(ApiError::Transfer)`.
     pub fn result_from(value: i32) -> TransferResult {
         match value {
             x if x == TransferredTo::ExistingAccount as i32 => Ok(TransferredTo::ExistingAccount),
             x if x == TransferredTo::NewAccount as i32 => Ok(TransferredTo::NewAccount),
+            TARGET_ACCOUNT_MISSING => Err(ApiError::TargetAccountMissing),
             _ => Err(ApiError::Transfer),
         }
     }
@@ -33,7 +41,17 @@ impl TransferredTo {
     pub fn i32_from(result: TransferResult) -> i32 {
         match result {
             Ok(transferred_to) => transferred_to as i32,
-            Err(_) => 2,
+            Err(ApiError::TargetAccountMissing) => TARGET_ACCOUNT_MISSING,
+            Err(_) => TRANSFER_FAILED,
         }
     }
 }
+
+/// The result of a successful transfer between purses.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PurseTransferred {
+    /// The funds were moved to the target purse.
+    Completed,
+    /// The transfer had already been performed, so no funds were moved.
+    Duplicate,
+}
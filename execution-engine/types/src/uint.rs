@@ -14,6 +14,19 @@ use crate::bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
 mod macro_code {
     use uint::construct_uint;
 
+    pub(crate) use self::wide::U1024;
+
+    // Most of the generated `U1024` methods are unused.
+    #[allow(dead_code)]
+    mod wide {
+        use uint::construct_uint;
+
+        construct_uint! {
+            /// Wide enough to hold the product of any two `U512`s.
+            pub(crate) struct U1024(16);
+        }
+    }
+
     construct_uint! {
         pub struct U512(8);
     }
@@ -25,6 +38,7 @@ mod macro_code {
     }
 }
 
+use self::macro_code::U1024;
 pub use self::macro_code::{U128, U256, U512};
 
 /// Error type for parsing [`U128`], [`U256`], [`U512`] from a string.
@@ -462,6 +476,186 @@ impl AsPrimitive<U512> for U512 {
     }
 }
 
+/// Error type for fallible conversions between [`U512`] and primitive integer types.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UIntConversionError {
+    /// The value to convert was negative.
+    Negative,
+    /// The value to convert doesn't fit in the target type.
+    Overflow,
+}
+
+/// The length in bytes of the fixed-size little-endian representation of a [`U512`].
+pub const U512_FIXED_LENGTH: usize = 64;
+
+impl U512 {
+    /// Returns the value as a fixed-size, little-endian array of [`U512_FIXED_LENGTH`] bytes.
+    ///
+    /// Unlike the `ToBytes` encoding, the resulting length does not depend on the value.
+    pub fn to_bytes_fixed(&self) -> [u8; U512_FIXED_LENGTH] {
+        let mut bytes = [0u8; U512_FIXED_LENGTH];
+        self.to_little_endian(&mut bytes);
+        bytes
+    }
+
+    /// Constructs a value from a fixed-size, little-endian array as produced by
+    /// [`U512::to_bytes_fixed`].
+    pub fn from_bytes_fixed(bytes: [u8; U512_FIXED_LENGTH]) -> U512 {
+        U512::from_little_endian(&bytes)
+    }
+
+    /// Returns the value in a compact encoding: a leading byte giving the number of little-endian
+    /// bytes which follow, with high-order zero bytes omitted.
+    ///
+    /// Zero takes a single byte, and values below 256 take two.  This matches the `ToBytes`
+    /// encoding, but cannot fail.
+    pub fn to_varint_bytes(&self) -> Vec<u8> {
+        let bytes = self.to_bytes_fixed();
+        let length = bytes
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |index| index + 1);
+        let mut result = Vec::with_capacity(U8_SERIALIZED_LENGTH + length);
+        result.push(length as u8);
+        result.extend_from_slice(&bytes[..length]);
+        result
+    }
+
+    /// Decodes a value from the start of `bytes` as encoded by [`U512::to_varint_bytes`],
+    /// returning it along with the remaining bytes.
+    ///
+    /// Fails if the length byte exceeds [`U512_FIXED_LENGTH`] or `bytes` is too short to hold the
+    /// value it announces.
+    pub fn from_varint_bytes(bytes: &[u8]) -> Result<(U512, &[u8]), Error> {
+        U512::from_bytes(bytes)
+    }
+
+    /// Converts `value` to a `U512`, failing if it is negative.
+    ///
+    /// This is a non-panicking alternative to `U512::from(i64)` provided by the `uint` crate, which
+    /// panics on negative input.  That impl also rules out implementing `TryFrom<i64>`.
+    pub fn checked_from_i64(value: i64) -> Result<U512, UIntConversionError> {
+        if value < 0 {
+            return Err(UIntConversionError::Negative);
+        }
+        Ok(U512::from(value as u64))
+    }
+
+    /// Converts `value` to a `U512`, failing if it is negative.
+    pub fn checked_from_i128(value: i128) -> Result<U512, UIntConversionError> {
+        if value < 0 {
+            return Err(UIntConversionError::Negative);
+        }
+        Ok(U512::from_little_endian(&(value as u128).to_le_bytes()))
+    }
+
+    /// Converts `self` to a `u64`, failing if it exceeds `u64::max_value()`.
+    pub fn checked_to_u64(self) -> Result<u64, UIntConversionError> {
+        if self > U512::from(u64::max_value()) {
+            return Err(UIntConversionError::Overflow);
+        }
+        Ok(self.as_u64())
+    }
+
+    /// Returns `self + 1`, or `None` if `self` is `U512::max_value()`.
+    pub fn inc(self) -> Option<U512> {
+        self.checked_add(U512::one())
+    }
+
+    /// Returns `self - 1`, or `None` if `self` is zero.
+    pub fn dec(self) -> Option<U512> {
+        self.checked_sub(U512::one())
+    }
+
+    /// Returns the integer square root of `self`, i.e. the greatest value whose square doesn't
+    /// exceed `self`.
+    pub fn isqrt(self) -> U512 {
+        if self < U512::from(2) {
+            return self;
+        }
+        // Newton's method, starting from a power of two no smaller than the root, decreases
+        // monotonically until it reaches the floor of the root.
+        let mut root = U512::one() << ((self.bits() + 1) / 2);
+        loop {
+            let next = (root + self / root) >> 1;
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    ///
+    /// The GCD of any value and zero is that value, so `U512::zero().gcd(U512::zero())` is zero.
+    pub fn gcd(self, other: U512) -> U512 {
+        let (mut a, mut b) = (self, other);
+        while !b.is_zero() {
+            let remainder = a % b;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    /// Computes `self * mul / div`, rounding down, without the multiplication overflowing.
+    ///
+    /// Returns `None` if `div` is zero or the result doesn't fit in a `U512`.
+    pub fn mul_div(self, mul: U512, div: U512) -> Option<U512> {
+        if div.is_zero() {
+            return None;
+        }
+        narrow(widen(self) * widen(mul) / widen(div))
+    }
+
+    /// Computes `(self + other) % modulus` without the addition overflowing.
+    ///
+    /// Returns `None` if `modulus` is zero.
+    pub fn add_mod(self, other: U512, modulus: U512) -> Option<U512> {
+        if modulus.is_zero() {
+            return None;
+        }
+        narrow((widen(self) + widen(other)) % widen(modulus))
+    }
+
+    /// Computes `(self * other) % modulus` without the multiplication overflowing.
+    ///
+    /// Returns `None` if `modulus` is zero.
+    pub fn mul_mod(self, other: U512, modulus: U512) -> Option<U512> {
+        if modulus.is_zero() {
+            return None;
+        }
+        narrow(widen(self) * widen(other) % widen(modulus))
+    }
+
+    /// Converts `self` to the nearest `f64`, for display purposes only.
+    ///
+    /// The conversion is lossy: values above 2^53 can't generally be represented exactly, so the
+    /// result must not be used in any calculation whose outcome matters.  Every `U512` is well
+    /// within the range of `f64`, so the result is always finite.
+    pub fn to_f64_lossy(&self) -> f64 {
+        const LIMB_FACTOR: f64 = 18_446_744_073_709_551_616.0; // 2^64
+        self.0
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &limb| acc * LIMB_FACTOR + limb as f64)
+    }
+}
+
+fn widen(value: U512) -> U1024 {
+    U1024::from_little_endian(&value.to_bytes_fixed())
+}
+
+/// Converts `value` back to a `U512`, or returns `None` if it doesn't fit.
+fn narrow(value: U1024) -> Option<U512> {
+    if value.bits() > 512 {
+        return None;
+    }
+    let mut bytes = [0u8; 2 * U512_FIXED_LENGTH];
+    value.to_little_endian(&mut bytes);
+    Some(U512::from_little_endian(&bytes[..U512_FIXED_LENGTH]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -775,6 +969,154 @@ mod tests {
         assert_eq!(value, U256::max_value());
     }
 
+    #[test]
+    fn bits_u512() {
+        // `bits` is provided by `construct_uint!`, returning the number of significant bits.
+        assert_eq!(U512::zero().bits(), 0);
+        assert_eq!(U512::one().bits(), 1);
+        assert_eq!(U512::from(2).bits(), 2);
+        assert_eq!(U512::from(255).bits(), 8);
+        assert_eq!(U512::from(256).bits(), 9);
+        assert_eq!(U512::from(u64::max_value()).bits(), 64);
+        assert_eq!((U512::one() << 511).bits(), 512);
+        assert_eq!(U512::max_value().bits(), 512);
+    }
+
+    #[test]
+    fn mul_div_u512() {
+        assert_eq!(
+            U512::from(100).mul_div(U512::from(3), U512::from(4)),
+            Some(U512::from(75))
+        );
+        // Rounds down.
+        assert_eq!(
+            U512::from(10).mul_div(U512::from(1), U512::from(3)),
+            Some(U512::from(3))
+        );
+        assert_eq!(U512::from(10).mul_div(U512::from(1), U512::zero()), None);
+    }
+
+    #[test]
+    fn mul_div_u512_without_intermediate_overflow() {
+        let max = U512::max_value();
+        // `max * max` would overflow, but the result fits.
+        assert_eq!(max.mul_div(max, max), Some(max));
+        assert_eq!(
+            max.mul_div(U512::from(3), U512::from(4)),
+            Some(max / 4 * 3 + (max % 4) * 3 / 4)
+        );
+        let half = U512::one() << 511;
+        assert_eq!(half.mul_div(U512::from(4), U512::from(8)), Some(half / 2));
+        // The result itself doesn't fit.
+        assert_eq!(max.mul_div(U512::from(2), U512::one()), None);
+    }
+
+    #[test]
+    fn to_f64_lossy_u512() {
+        assert_eq!(U512::zero().to_f64_lossy(), 0.0);
+        assert_eq!(U512::from(42).to_f64_lossy(), 42.0);
+        let max_exact = (1u64 << 53) - 1;
+        assert_eq!(U512::from(max_exact).to_f64_lossy(), max_exact as f64);
+        assert_eq!(U512::from(u64::max_value()).to_f64_lossy(), 2f64.powi(64));
+        assert_eq!((U512::one() << 200).to_f64_lossy(), 2f64.powi(200));
+
+        let max = U512::max_value().to_f64_lossy();
+        assert!(max.is_finite());
+        assert_eq!(max, 2f64.powi(512));
+    }
+
+    #[test]
+    fn checked_conversions_u512() {
+        assert_eq!(U512::checked_from_i64(0), Ok(U512::zero()));
+        assert_eq!(
+            U512::checked_from_i64(i64::max_value()),
+            Ok(U512::from(i64::max_value() as u64))
+        );
+        assert_eq!(
+            U512::checked_from_i64(-1),
+            Err(UIntConversionError::Negative)
+        );
+        assert_eq!(
+            U512::checked_from_i64(i64::min_value()),
+            Err(UIntConversionError::Negative)
+        );
+
+        assert_eq!(U512::checked_from_i128(42), Ok(U512::from(42)));
+        assert_eq!(
+            U512::checked_from_i128(i128::max_value()),
+            Ok((U512::one() << 127) - 1)
+        );
+        assert_eq!(
+            U512::checked_from_i128(-1),
+            Err(UIntConversionError::Negative)
+        );
+
+        assert_eq!(U512::from(42).checked_to_u64(), Ok(42));
+        assert_eq!(
+            U512::from(u64::max_value()).checked_to_u64(),
+            Ok(u64::max_value())
+        );
+        assert_eq!(
+            (U512::from(u64::max_value()) + 1).checked_to_u64(),
+            Err(UIntConversionError::Overflow)
+        );
+        assert_eq!(
+            U512::max_value().checked_to_u64(),
+            Err(UIntConversionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn gcd_u512() {
+        let gcd = |a: u64, b: u64| U512::from(a).gcd(U512::from(b));
+
+        assert_eq!(gcd(12, 18), U512::from(6));
+        assert_eq!(gcd(18, 12), U512::from(6));
+        assert_eq!(gcd(7, 7), U512::from(7));
+        assert_eq!(gcd(1, 1_000_000), U512::one());
+
+        // Coprime values.
+        assert_eq!(gcd(35, 64), U512::one());
+        assert_eq!(gcd(17, 31), U512::one());
+
+        // Zero.
+        assert_eq!(gcd(0, 42), U512::from(42));
+        assert_eq!(gcd(42, 0), U512::from(42));
+        assert_eq!(gcd(0, 0), U512::zero());
+
+        let max = U512::max_value();
+        assert_eq!(max.gcd(max), max);
+        assert_eq!(max.gcd(U512::zero()), max);
+        assert_eq!((max - 1).gcd(max), U512::one());
+    }
+
+    #[test]
+    fn fixed_bytes_round_trip_u512() {
+        for value in &[
+            U512::zero(),
+            U512::one(),
+            U512::from(u64::max_value()),
+            U512::max_value(),
+        ] {
+            let bytes = value.to_bytes_fixed();
+            assert_eq!(U512::from_bytes_fixed(bytes), *value);
+        }
+
+        // Arrays longer than 32 elements don't implement `PartialEq`, so compare slices instead.
+        assert_eq!(
+            &U512::zero().to_bytes_fixed()[..],
+            &[0u8; U512_FIXED_LENGTH][..]
+        );
+        assert_eq!(
+            &U512::max_value().to_bytes_fixed()[..],
+            &[u8::max_value(); U512_FIXED_LENGTH][..]
+        );
+
+        let mut expected = [0u8; U512_FIXED_LENGTH];
+        expected[0] = 1;
+        assert_eq!(&U512::one().to_bytes_fixed()[..], &expected[..]);
+    }
+
     #[test]
     fn wrapping_test_u128() {
         let max = U128::max_value();
@@ -785,4 +1127,167 @@ mod tests {
         let value = min.wrapping_sub(&1.into());
         assert_eq!(value, U128::max_value());
     }
+
+    #[test]
+    fn inc_should_fail_at_max() {
+        assert_eq!(U512::zero().inc(), Some(U512::one()));
+        assert_eq!((U512::max_value() - 1).inc(), Some(U512::max_value()));
+        assert_eq!(U512::max_value().inc(), None);
+    }
+
+    #[test]
+    fn dec_should_fail_at_zero() {
+        assert_eq!(U512::max_value().dec(), Some(U512::max_value() - 1));
+        assert_eq!(U512::one().dec(), Some(U512::zero()));
+        assert_eq!(U512::zero().dec(), None);
+    }
+
+    #[test]
+    fn isqrt_of_perfect_squares() {
+        for root in &[0u64, 1, 2, 3, 10, 1_000_000, u64::max_value()] {
+            let root = U512::from(*root);
+            assert_eq!((root * root).isqrt(), root);
+        }
+    }
+
+    #[test]
+    fn isqrt_should_round_down() {
+        assert_eq!(U512::from(2).isqrt(), U512::one());
+        assert_eq!(U512::from(3).isqrt(), U512::one());
+        assert_eq!(U512::from(8).isqrt(), U512::from(2));
+        assert_eq!(U512::from(99).isqrt(), U512::from(9));
+        let root = U512::from(u64::max_value());
+        assert_eq!((root * root + root * U512::from(2)).isqrt(), root);
+
+        let root = U512::max_value().isqrt();
+        assert!(root.checked_mul(root).is_some());
+        assert!((root + 1).checked_mul(root + 1).is_none());
+    }
+
+    #[test]
+    fn varint_bytes_roundtrip() {
+        let values = [
+            U512::zero(),
+            U512::one(),
+            U512::from(255),
+            U512::from(256),
+            U512::from(u64::max_value()),
+            U512::from(u64::max_value()) * U512::from(u64::max_value()),
+            U512::max_value(),
+        ];
+        for value in &values {
+            let bytes = value.to_varint_bytes();
+            assert_eq!(bytes, value.to_bytes().unwrap());
+            assert_eq!(U512::from_varint_bytes(&bytes), Ok((*value, &[][..])));
+        }
+    }
+
+    #[test]
+    fn varint_bytes_should_be_compact_for_small_values() {
+        assert_eq!(U512::zero().to_varint_bytes(), vec![0]);
+        assert_eq!(U512::from(7).to_varint_bytes(), vec![1, 7]);
+        assert_eq!(U512::from(256).to_varint_bytes(), vec![2, 0, 1]);
+        assert_eq!(
+            U512::max_value().to_varint_bytes().len(),
+            1 + U512_FIXED_LENGTH
+        );
+    }
+
+    #[test]
+    fn from_varint_bytes_should_return_remainder() {
+        let mut bytes = U512::from(1000).to_varint_bytes();
+        bytes.extend(U512::from(5).to_varint_bytes());
+        let (first, rem) = U512::from_varint_bytes(&bytes).unwrap();
+        let (second, rem) = U512::from_varint_bytes(rem).unwrap();
+        assert_eq!((first, second), (U512::from(1000), U512::from(5)));
+        assert!(rem.is_empty());
+    }
+
+    #[test]
+    fn from_varint_bytes_should_reject_malformed_input() {
+        assert_eq!(
+            U512::from_varint_bytes(&[U512_FIXED_LENGTH as u8 + 1]),
+            Err(Error::Formatting)
+        );
+        assert_eq!(
+            U512::from_varint_bytes(&[2, 1]),
+            Err(Error::EarlyEndOfStream)
+        );
+        assert_eq!(U512::from_varint_bytes(&[]), Err(Error::EarlyEndOfStream));
+    }
+
+    #[test]
+    fn saturating_sub_should_clamp_to_zero() {
+        let balance = U512::from(10);
+        assert_eq!(balance.saturating_sub(U512::from(3)), U512::from(7));
+        assert_eq!(balance.saturating_sub(U512::from(11)), U512::zero());
+        assert_eq!(U512::zero().saturating_sub(U512::max_value()), U512::zero());
+    }
+
+    #[test]
+    fn add_mod_should_reduce_sum() {
+        let modulus = U512::from(7);
+        assert_eq!(
+            U512::from(3).add_mod(U512::from(2), modulus),
+            Some(U512::from(5))
+        );
+        assert_eq!(
+            U512::from(5).add_mod(U512::from(4), modulus),
+            Some(U512::from(2))
+        );
+        assert_eq!(
+            U512::from(20).add_mod(U512::from(30), modulus),
+            Some(U512::from(1))
+        );
+        assert_eq!(
+            U512::from(7).add_mod(U512::zero(), modulus),
+            Some(U512::from(0))
+        );
+        assert_eq!(U512::from(3).add_mod(U512::from(2), U512::zero()), None);
+    }
+
+    #[test]
+    fn add_mod_should_not_overflow() {
+        let max = U512::max_value();
+        // The sum of two maximal values needs 513 bits.
+        assert_eq!(max.add_mod(max, max), Some(U512::from(0)));
+        assert_eq!(
+            max.add_mod(U512::one(), max - U512::one()),
+            Some(U512::from(2))
+        );
+        assert_eq!(max.add_mod(max, U512::from(10)), Some(U512::from(0)));
+    }
+
+    #[test]
+    fn mul_mod_should_reduce_product() {
+        let modulus = U512::from(13);
+        assert_eq!(
+            U512::from(4).mul_mod(U512::from(3), modulus),
+            Some(U512::from(12))
+        );
+        assert_eq!(
+            U512::from(5).mul_mod(U512::from(6), modulus),
+            Some(U512::from(4))
+        );
+        assert_eq!(
+            U512::zero().mul_mod(U512::from(6), modulus),
+            Some(U512::from(0))
+        );
+        assert_eq!(
+            U512::from(4).mul_mod(U512::from(3), U512::one()),
+            Some(U512::from(0))
+        );
+        assert_eq!(U512::from(4).mul_mod(U512::from(3), U512::zero()), None);
+    }
+
+    #[test]
+    fn mul_mod_should_not_overflow() {
+        let max = U512::max_value();
+        // The product of two maximal values needs 1024 bits.  Since 2^512 = 1 (mod 3) and
+        // 2^512 = 4 (mod 7), `max` is 0 (mod 3) and 3 (mod 7), so its square is 0 and 2 resp.
+        assert_eq!(max.mul_mod(max, U512::from(3)), Some(U512::from(0)));
+        assert_eq!(max.mul_mod(max, U512::from(7)), Some(U512::from(2)));
+        assert_eq!(max.mul_mod(U512::from(2), max), Some(U512::from(0)));
+        assert_eq!(max.mul_mod(max, max - U512::one()), Some(U512::from(1)));
+    }
 }
@@ -21,10 +21,17 @@ pub type URefAddr = [u8; UREF_ADDR_LENGTH];
 /// the [`AccessRights`] of the reference.
 ///
 /// A `URef` can be used to index entities such as [`CLValue`](crate::CLValue)s, or smart contracts.
+///
+/// `URef`s are ordered by their address bytes first and then by their access rights, so collections
+/// of purses can be iterated in a deterministic order.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct URef(URefAddr, AccessRights);
 
 impl URef {
+    /// A placeholder `URef` with an all-zero address and no access rights, used to represent "no
+    /// purse" without wrapping purses in an `Option`.  Transfers from or to it are rejected.
+    pub const SENTINEL: URef = URef([0; UREF_ADDR_LENGTH], AccessRights::NONE);
+
     /// Constructs a [`URef`] from an address and access rights.
     pub fn new(address: URefAddr, access_rights: AccessRights) -> Self {
         URef(address, access_rights)
@@ -35,6 +42,12 @@ impl URef {
         self.0
     }
 
+    /// Returns `true` if this [`URef`] has the address of [`URef::SENTINEL`], regardless of its
+    /// access rights.
+    pub fn is_sentinel(&self) -> bool {
+        self.0 == URef::SENTINEL.0
+    }
+
     /// Returns the access rights of this [`URef`].
     pub fn access_rights(&self) -> AccessRights {
         self.1
@@ -108,6 +121,10 @@ impl Debug for URef {
     }
 }
 
+/// Purses are identified by `URef`s, so this layout is relied on by external tooling and must not
+/// change: the 32 address bytes followed by a single byte holding the [`AccessRights`] bits, for
+/// [`UREF_SERIALIZED_LENGTH`] bytes in total.  As a [`Key::URef`] it is prefixed by one more byte
+/// for the key variant.
 impl bytesrepr::ToBytes for URef {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::unchecked_allocate_buffer(self);
@@ -144,6 +161,106 @@ impl TryFrom<Key> for URef {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bytesrepr::{FromBytes, ToBytes};
+
+    fn purse() -> URef {
+        let mut addr = [0u8; UREF_ADDR_LENGTH];
+        for (index, byte) in addr.iter_mut().enumerate() {
+            *byte = index as u8;
+        }
+        URef::new(addr, AccessRights::READ_ADD_WRITE)
+    }
+
+    fn golden_bytes() -> Vec<u8> {
+        let mut bytes: Vec<u8> = (0..UREF_ADDR_LENGTH as u8).collect();
+        bytes.push(AccessRights::READ_ADD_WRITE.bits());
+        bytes
+    }
+
+    #[test]
+    fn should_serialize_purse_uref_to_fixed_layout() {
+        let bytes = purse().to_bytes().expect("should serialize");
+        assert_eq!(bytes.len(), 33);
+        assert_eq!(bytes.len(), UREF_SERIALIZED_LENGTH);
+        assert_eq!(bytes.len(), purse().serialized_length());
+        assert_eq!(bytes, golden_bytes());
+
+        let key_bytes = Key::from(purse()).to_bytes().expect("should serialize");
+        assert_eq!(key_bytes.len(), 34);
+        assert_eq!(key_bytes[0], 2);
+        assert_eq!(&key_bytes[1..], golden_bytes().as_slice());
+    }
+
+    #[test]
+    fn should_identify_sentinel_purse() {
+        assert!(URef::SENTINEL.is_sentinel());
+        assert_eq!(URef::SENTINEL.addr(), [0; UREF_ADDR_LENGTH]);
+        assert_eq!(URef::SENTINEL.access_rights(), AccessRights::NONE);
+        assert!(URef::SENTINEL.into_read_add_write().is_sentinel());
+        assert!(!purse().is_sentinel());
+        bytesrepr::test_serialization_roundtrip(&URef::SENTINEL);
+    }
+
+    #[test]
+    fn should_round_trip_purse_uref() {
+        for access_rights in &[
+            AccessRights::NONE,
+            AccessRights::READ,
+            AccessRights::ADD,
+            AccessRights::READ_ADD_WRITE,
+        ] {
+            let uref = URef::new([7; UREF_ADDR_LENGTH], *access_rights);
+            bytesrepr::test_serialization_roundtrip(&uref);
+        }
+
+        let (uref, rem) = URef::from_bytes(&golden_bytes()).expect("should deserialize");
+        assert_eq!(uref, purse());
+        assert!(rem.is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_truncated_purse_uref() {
+        let bytes = golden_bytes();
+        assert!(URef::from_bytes(&bytes[..UREF_SERIALIZED_LENGTH - 1]).is_err());
+    }
+
+    #[test]
+    fn should_order_purses_by_address_bytes() {
+        let purse_a = URef::new([1; UREF_ADDR_LENGTH], AccessRights::READ_ADD_WRITE);
+        let purse_b = URef::new([2; UREF_ADDR_LENGTH], AccessRights::READ);
+        let mut addr_c = [2; UREF_ADDR_LENGTH];
+        addr_c[UREF_ADDR_LENGTH - 1] = 3;
+        let purse_c = URef::new(addr_c, AccessRights::NONE);
+        let purse_d = URef::new([255; UREF_ADDR_LENGTH], AccessRights::ADD);
+
+        let expected = vec![purse_a, purse_b, purse_c, purse_d];
+
+        let mut forward = vec![purse_c, purse_a, purse_d, purse_b];
+        forward.sort();
+        assert_eq!(forward, expected);
+
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        reversed.sort();
+        assert_eq!(reversed, expected);
+
+        for pair in expected.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].addr() < pair[1].addr());
+        }
+    }
+
+    #[test]
+    fn should_order_purses_with_same_address_by_access_rights() {
+        let addr = [7; UREF_ADDR_LENGTH];
+        let read = URef::new(addr, AccessRights::READ);
+        let read_add_write = URef::new(addr, AccessRights::READ_ADD_WRITE);
+        assert!(read < read_add_write);
+        assert_eq!(read.cmp(&read), core::cmp::Ordering::Equal);
+
+        let lower_addr = URef::new([6; UREF_ADDR_LENGTH], AccessRights::READ_ADD_WRITE);
+        assert!(lower_addr < read);
+    }
 
     #[test]
     fn uref_as_string() {